@@ -1,40 +1,66 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
-use std::process::id;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    match read_file_to_string(input_path) {
-        Ok(result) => result
-            .chars()
-            .map(|m| match m {
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// Each '(' goes up a floor and each ')' goes down one
+fn parse_input(input: &str) -> Result<Vec<i32>> {
+    let mut steps: Vec<i32> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        for (index, c) in line.chars().enumerate() {
+            steps.push(match c {
                 '(' => 1,
                 ')' => -1,
-                _ => 0,
-            })
-            .sum(),
-        Err(_) => i32::MIN,
+                _ => {
+                    return Err(Error::parse_at(
+                        line_number,
+                        index + 1,
+                        format!("expected '(' or ')', found {:?}", c),
+                    ))
+                }
+            });
+        }
+    }
+
+    if steps.is_empty() {
+        return Err(Error::parse(1, "expected at least one instruction"));
     }
+
+    Ok(steps)
+}
+
+fn solve_part_one(steps: &[i32]) -> i32 {
+    steps.iter().sum()
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(steps: &[i32]) -> Result<i32> {
     let mut floor: i32 = 0;
 
-    match read_file_to_string(input_path) {
-        Ok(result) => {
-            for (idx, step) in result.chars().map(|m| match m {
-                '(' => 1,
-                ')' => -1,
-                _ => 0,
-            }).enumerate() {
-                floor += step;
-                if floor == -1 {
-                    return (idx + 1) as i32
-                }
-            }
+    for (idx, step) in steps.iter().enumerate() {
+        floor += step;
+        if floor == -1 {
+            return Ok((idx + 1) as i32);
+        }
+    }
+
+    Err(Error::parse(1, "Santa never enters the basement"))
+}
+
+#[derive(Args)]
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<i32>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
 
-            i32::MIN
-        },
-        Err(_) => i32::MIN,
+    fn part_two(&self, input: &Vec<i32>) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
     }
 }
@@ -1,9 +1,8 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use regex::Regex;
-use std::path::Path;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-struct GiftBox {
+pub struct GiftBox {
     length: u32,
     width: u32,
     height: u32,
@@ -41,52 +40,61 @@ impl GiftBox {
     }
 }
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut sum: i32 = 0;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let re = Regex::new(r"(?<length>\d+)x(?<width>\d+)x(?<height>\d+)").unwrap();
-                match re.captures(&result) {
-                    Some(capture) => {
-                        sum += GiftBox::new(
-                            capture["length"].to_string().parse::<u32>().unwrap(),
-                            capture["width"].to_string().parse::<u32>().unwrap(),
-                            capture["height"].to_string().parse::<u32>().unwrap(),
-                        )
-                        .wrapping_paper_required() as i32;
-                    }
-                    None => {}
-                }
-            }
-        }
+// Each line is a box's dimensions, "LxWxH"
+fn parse_input(input: &str) -> Result<Vec<GiftBox>> {
+    let mut gift_boxes: Vec<GiftBox> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let length = parser.number::<u32>()?;
+        parser.literal("x")?;
+        let width = parser.number::<u32>()?;
+        parser.literal("x")?;
+        let height = parser.number::<u32>()?;
+        parser.end()?;
+
+        gift_boxes.push(GiftBox::new(length, width, height));
+    }
+
+    if gift_boxes.is_empty() {
+        return Err(Error::parse(
+            1,
+            "expected the dimensions of at least one box",
+        ));
     }
 
-    sum
+    Ok(gift_boxes)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let mut sum: i32 = 0;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let re = Regex::new(r"(?<length>\d+)x(?<width>\d+)x(?<height>\d+)").unwrap();
-                match re.captures(&result) {
-                    Some(capture) => {
-                        sum += GiftBox::new(
-                            capture["length"].to_string().parse::<u32>().unwrap(),
-                            capture["width"].to_string().parse::<u32>().unwrap(),
-                            capture["height"].to_string().parse::<u32>().unwrap(),
-                        )
-                        .ribbon_required() as i32;
-                    }
-                    None => {}
-                }
-            }
-        }
+fn solve_part_one(gift_boxes: &[GiftBox]) -> i32 {
+    gift_boxes
+        .iter()
+        .map(|gift_box| gift_box.wrapping_paper_required() as i32)
+        .sum()
+}
+
+fn solve_part_two(gift_boxes: &[GiftBox]) -> i32 {
+    gift_boxes
+        .iter()
+        .map(|gift_box| gift_box.ribbon_required() as i32)
+        .sum()
+}
+
+#[derive(Args)]
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input = Vec<GiftBox>;
+
+    fn parse(&self, input: &str) -> Result<Vec<GiftBox>> {
+        parse_input(input)
     }
 
-    sum
+    fn part_one(&self, input: &Vec<GiftBox>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<GiftBox>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
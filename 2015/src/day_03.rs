@@ -1,81 +1,83 @@
-use advent_of_code::{read_file_to_string, read_lines, Coordinate};
-use log::{debug, log_enabled, Level};
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut x: i32 = 0;
-    let mut y: i32 = 0;
-    let mut visited_locations: HashSet<Coordinate> = HashSet::new();
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Coordinate, Direction, Error, Result, Solution};
+use clap::Args;
+use std::collections::HashSet;
 
-    visited_locations.insert(Coordinate::new(x, y));
-
-    if let Ok(input) = read_file_to_string(input_path) {
-        for c in input.chars() {
-            match c {
-                '>' => {
-                    y += 1;
-                }
-                '<' => {
-                    y -= 1;
-                }
-                '^' => {
-                    x -= 1;
-                }
-                'v' => {
-                    x += 1;
-                }
-                _ => todo!(),
-            }
+// The moves are arrows, possibly wrapped over several lines
+fn parse_input(input: &str) -> Result<Vec<Direction>> {
+    let mut moves: Vec<Direction> = Vec::new();
 
-            visited_locations.insert(Coordinate::new(x, y));
+    for (line_number, line) in numbered_lines(input) {
+        for (index, c) in line.chars().enumerate() {
+            let direction = match c {
+                '^' | 'v' | '<' | '>' => Direction::from_char(c),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                Error::parse_at(
+                    line_number,
+                    index + 1,
+                    format!("expected one of ^v<>, found {:?}", c),
+                )
+            })?;
+            moves.push(direction);
         }
     }
 
+    Ok(moves)
+}
+
+fn solve_part_one(moves: &[Direction]) -> i32 {
+    let mut santa: Coordinate = Coordinate::new(0, 0);
+    let mut visited_locations: HashSet<Coordinate> = HashSet::new();
+
+    visited_locations.insert(santa);
+
+    for &direction in moves {
+        santa += direction;
+        visited_locations.insert(santa);
+    }
+
     visited_locations.len() as i32
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let mut santa_x: i32 = 0;
-    let mut santa_y: i32 = 0;
-    let mut robo_santa_x: i32 = 0;
-    let mut robo_santa_y: i32 = 0;
+fn solve_part_two(moves: &[Direction]) -> i32 {
+    let mut santa: Coordinate = Coordinate::new(0, 0);
+    let mut robo_santa: Coordinate = Coordinate::new(0, 0);
     let mut visited_locations: HashSet<Coordinate> = HashSet::new();
 
-    visited_locations.insert(Coordinate::new(santa_x, santa_y));
-    visited_locations.insert(Coordinate::new(robo_santa_x, robo_santa_y));
+    visited_locations.insert(santa);
     let mut robo_santa_move: bool = false;
 
-    if let Ok(input) = read_file_to_string(input_path) {
-        for (i, c) in input.chars().enumerate() {
-            match c {
-                '>' => match robo_santa_move {
-                    true => robo_santa_y += 1,
-                    false => santa_y += 1,
-                },
-                '<' => match robo_santa_move {
-                    true => robo_santa_y -= 1,
-                    false => santa_y -= 1,
-                },
-                '^' => match robo_santa_move {
-                    true => robo_santa_x -= 1,
-                    false => santa_x -= 1,
-                },
-                'v' => match robo_santa_move {
-                    true => robo_santa_x += 1,
-                    false => santa_x += 1,
-                },
-                _ => todo!(),
-            }
-
-            match robo_santa_move {
-                true => visited_locations.insert(Coordinate::new(robo_santa_x, robo_santa_y)),
-                false => visited_locations.insert(Coordinate::new(santa_x, santa_y)),
-            };
+    for &direction in moves {
+        let mover = match robo_santa_move {
+            true => &mut robo_santa,
+            false => &mut santa,
+        };
+        *mover += direction;
+        visited_locations.insert(*mover);
 
-            robo_santa_move = !robo_santa_move;
-        }
+        robo_santa_move = !robo_santa_move;
     }
 
     visited_locations.len() as i32
 }
+
+#[derive(Args)]
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Direction>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Direction>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Direction>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
@@ -1,25 +1,25 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use md5;
-use std::fmt::format;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u64 {
-    let input: String = match read_file_to_string(input_path) {
-        Ok(_input) => _input,
-        Err(_) => String::from(""),
-    };
-
-    solve(input.as_str(), 5)
-}
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// The secret key is a single word on one line
+fn parse_input(input: &str) -> Result<String> {
+    let mut lines = numbered_lines(input);
+    let (line_number, line) = lines
+        .next()
+        .ok_or_else(|| Error::parse(1, "expected a secret key"))?;
+    let mut parser = Parser::new(line_number, line);
+    let key = parser.word()?;
+    parser.end()?;
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let input: String = match read_file_to_string(input_path) {
-        Ok(_input) => _input,
-        Err(_) => String::from(""),
-    };
+    if let Some((line_number, line)) = lines.find(|(_, line)| !line.trim().is_empty()) {
+        return Err(Error::parse(
+            line_number,
+            format!("expected only the secret key, found {:?}", line),
+        ));
+    }
 
-    solve(input.as_str(), 6)
+    Ok(key.to_string())
 }
 
 fn solve(input: &str, length: usize) -> u64 {
@@ -35,3 +35,22 @@ fn solve(input: &str, length: usize) -> u64 {
     }
     answer
 }
+
+#[derive(Args)]
+pub struct DayFour;
+
+impl Solution for DayFour {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &String) -> Result<Answer> {
+        Ok(solve(input, 5).into())
+    }
+
+    fn part_two(&self, input: &String) -> Result<Answer> {
+        Ok(solve(input, 6).into())
+    }
+}
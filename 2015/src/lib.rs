@@ -1,57 +1,15 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-// read_lines from https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-pub fn read_file_to_string<P>(filename: P) -> Result<String, std::io::Error>
-where
-    P: AsRef<Path>,
-{
-    std::fs::read_to_string(filename)
-}
-
-#[derive(Clone, Copy, Ord, Eq, PartialEq, Hash, Debug)]
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Coordinate {
-    pub fn new(x: i32, y: i32) -> Coordinate {
-        Coordinate { x, y }
-    }
-
-    pub fn neighboring_cardinal_directions(&self) -> Vec<Coordinate> {
-        return Vec::from([
-            Coordinate::new(self.x + 1, self.y),
-            Coordinate::new(self.x - 1, self.y),
-            Coordinate::new(self.x, self.y + 1),
-            Coordinate::new(self.x, self.y - 1),
-        ]);
-    }
-}
-
-impl PartialOrd for Coordinate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.x == other.x {
-            return Some(self.y.cmp(&other.y));
-        }
-        Some(self.y.cmp(&other.y))
-    }
-}
-
-impl fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "x={}, y={}", self.x, self.y,)
-    }
+use advent_of_code::Registry;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<day_01::DayOne>(2015, 1);
+    registry.register::<day_02::DayTwo>(2015, 2);
+    registry.register::<day_03::DayThree>(2015, 3);
+    registry.register::<day_04::DayFour>(2015, 4);
+    registry.register::<day_05::DayFive>(2015, 5);
 }
@@ -1,47 +1,36 @@
-use advent_of_code::read_lines;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut sum: i32 = 0;
-    let mut max: i32 = 0;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if !result.is_empty() {
-                    sum += result.parse::<i32>().unwrap();
-                } else {
-                    if sum > max {
-                        max = sum;
-                    }
-
-                    sum = 0;
-                }
-            }
-        }
+use advent_of_code::parse::blocks;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// The total calories carried by each elf, in input order
+fn parse_input(input: &str) -> Result<Vec<i32>> {
+    let elves: Vec<i32> = blocks(input)
+        .iter()
+        .map(|block| {
+            block
+                .numbered_lines()
+                .map(|(line_number, line)| {
+                    line.trim()
+                        .parse::<i32>()
+                        .map_err(|err| Error::parse(line_number, format!("{:?}: {}", line, err)))
+                })
+                .sum::<Result<i32>>()
+        })
+        .collect::<Result<Vec<i32>>>()?;
+
+    if elves.is_empty() {
+        return Err(Error::parse(1, "expected the calories of at least one elf"));
     }
 
-    max
+    Ok(elves)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let mut sum: i32 = 0;
-    let mut calorie_totals: Vec<i32> = Vec::new();
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if !result.is_empty() {
-                    let calories = result.parse::<i32>().unwrap();
-                    sum += calories;
-                } else {
-                    calorie_totals.push(sum);
-
-                    sum = 0;
-                }
-            }
-        }
-    }
+fn solve_part_one(elves: &[i32]) -> i32 {
+    elves.iter().copied().max().unwrap_or_default()
+}
+
+fn solve_part_two(elves: &[i32]) -> i32 {
+    let mut calorie_totals: Vec<i32> = elves.to_vec();
 
     calorie_totals.sort();
     calorie_totals.reverse();
@@ -49,3 +38,22 @@ pub fn part_two(input_path: &Path) -> i32 {
 
     calorie_totals.iter().sum::<i32>()
 }
+
+#[derive(Args)]
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Vec<i32>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<i32>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<i32>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
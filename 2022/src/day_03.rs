@@ -1,75 +1,105 @@
-use advent_of_code::read_lines;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use log::debug;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i32 {
+// One rucksack per line, holding items named by letters
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    let mut rucksacks: Vec<String> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if let Some((column, item)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_alphabetic())
+        {
+            return Err(Error::parse_at(
+                line_number,
+                column + 1,
+                format!("expected an item letter, found {:?}", item),
+            ));
+        }
+        if !line.len().is_multiple_of(2) {
+            return Err(Error::parse(
+                line_number,
+                format!(
+                    "expected two equal compartments, found {} items",
+                    line.len()
+                ),
+            ));
+        }
+
+        rucksacks.push(line.to_string());
+    }
+
+    Ok(rucksacks)
+}
+
+fn solve_part_one(rucksacks: &[String]) -> i32 {
     let priorities = generate_priorities();
     let mut total = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        // Consumes the iterator, returns an (Optional) String
-        for line in lines {
-            if let Ok(result) = line {
-                let mut first_compartment: String = result.clone();
-                let compartment_length = first_compartment.trim().len() / 2;
-
-                let second_compartment: String = first_compartment.split_off(compartment_length);
-
-                let first_compartment_set: HashSet<char> =
-                    HashSet::from_iter(first_compartment.chars());
-                let second_compartment_set: HashSet<char> =
-                    HashSet::from_iter(second_compartment.chars());
-
-                let intersection = first_compartment_set.intersection(&second_compartment_set);
-                match intersection.last() {
-                    Some(duplicate) => {
-                        if let Some(value) = priorities.get(&duplicate) {
-                            total += *value;
-                        }
-                    }
-                    None => println!("No duplicate found in the compartments"),
+    for result in rucksacks {
+        let mut first_compartment: String = result.to_string();
+        let compartment_length = first_compartment.trim().len() / 2;
+
+        let second_compartment: String = first_compartment.split_off(compartment_length);
+
+        let first_compartment_set: HashSet<char> = HashSet::from_iter(first_compartment.chars());
+        let second_compartment_set: HashSet<char> = HashSet::from_iter(second_compartment.chars());
+
+        let intersection = first_compartment_set.intersection(&second_compartment_set);
+        match intersection.last() {
+            Some(duplicate) => {
+                if let Some(value) = priorities.get(duplicate) {
+                    total += *value;
                 }
             }
+            None => debug!("No duplicate found in the compartments"),
         }
     }
 
     total
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(rucksacks: &[String]) -> Result<i32> {
     let priorities = generate_priorities();
     let mut total = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        // Consumes the iterator, returns an (Optional) String
-        let mut peekable_lines = lines.peekable();
-        while peekable_lines.peek().is_some() {
-            let rucksack_one: HashSet<char> =
-                HashSet::from_iter(peekable_lines.next().unwrap().unwrap().chars());
-            let rucksack_two: HashSet<char> =
-                HashSet::from_iter(peekable_lines.next().unwrap().unwrap().chars());
-            let rucksack_three: HashSet<char> =
-                HashSet::from_iter(peekable_lines.next().unwrap().unwrap().chars());
-
-            let badge_set_one: HashSet<&char> =
-                HashSet::from_iter(rucksack_one.intersection(&rucksack_two));
-            let badge_set_two: HashSet<&char> =
-                HashSet::from_iter(rucksack_two.intersection(&rucksack_three));
-
-            let badge_set_final = badge_set_one.intersection(&badge_set_two);
-
-            match badge_set_final.last() {
-                Some(badge) => {
-                    if let Some(value) = priorities.get(&badge) {
-                        total += *value;
-                    }
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(Error::parse(
+            rucksacks.len(),
+            format!(
+                "expected the rucksacks in groups of three, found {}",
+                rucksacks.len()
+            ),
+        ));
+    }
+
+    for group in rucksacks.chunks(3) {
+        let rucksack_one: HashSet<char> = HashSet::from_iter(group[0].chars());
+        let rucksack_two: HashSet<char> = HashSet::from_iter(group[1].chars());
+        let rucksack_three: HashSet<char> = HashSet::from_iter(group[2].chars());
+
+        let badge_set_one: HashSet<&char> =
+            HashSet::from_iter(rucksack_one.intersection(&rucksack_two));
+        let badge_set_two: HashSet<&char> =
+            HashSet::from_iter(rucksack_two.intersection(&rucksack_three));
+
+        let badge_set_final = badge_set_one.intersection(&badge_set_two);
+
+        match badge_set_final.last() {
+            Some(badge) => {
+                if let Some(value) = priorities.get(badge) {
+                    total += *value;
                 }
-                None => println!("No duplicate badge found"),
             }
+            None => debug!("No duplicate badge found"),
         }
     }
 
-    total
+    Ok(total)
 }
 
 fn generate_priorities() -> HashMap<char, i32> {
@@ -84,3 +114,22 @@ fn generate_priorities() -> HashMap<char, i32> {
 
     priorities
 }
+
+#[derive(Args)]
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
+}
@@ -1,66 +1,75 @@
-use advent_of_code::read_lines;
-use std::collections::HashSet;
-use std::path::Path;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, RangeSet, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut overlaps: i32 = 0;
+type Assignments = Vec<(RangeSet, RangeSet)>;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let (first_section, second_section) = parse_section_assignments(result);
-                if first_section.is_superset(&second_section)
-                    || first_section.is_subset(&second_section)
-                {
-                    overlaps += 1;
-                }
-            }
-        }
-    }
+fn solve_part_one(assignments: &Assignments) -> i32 {
+    assignments
+        .iter()
+        .filter(|(first_section, second_section)| {
+            first_section.is_superset(second_section) || first_section.is_subset(second_section)
+        })
+        .count() as i32
+}
 
-    overlaps
+fn solve_part_two(assignments: &Assignments) -> i32 {
+    assignments
+        .iter()
+        .filter(|(first_section, second_section)| !first_section.is_disjoint(second_section))
+        .count() as i32
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let mut overlaps: i32 = 0;
+// Each line is a pair of section ranges, "2-4,6-8"
+fn parse_input(input: &str) -> Result<Assignments> {
+    let mut assignments: Assignments = Vec::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let (first_section, second_section) = parse_section_assignments(result);
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let first_section = parse_section(&mut parser)?;
+        parser.literal(",")?;
+        let second_section = parse_section(&mut parser)?;
+        parser.end()?;
+
+        assignments.push((first_section, second_section));
+    }
 
-                let intersections = first_section.intersection(&second_section).count();
-                if intersections > 0 {
-                    overlaps += 1;
-                }
-            }
-        }
+    if assignments.is_empty() {
+        return Err(Error::parse(1, "expected at least one pair of assignments"));
     }
 
-    overlaps
+    Ok(assignments)
 }
 
-fn parse_section_assignments(result: String) -> (HashSet<i32>, HashSet<i32>) {
-    let sections: Vec<&str> = result.split(',').collect();
-    let first_section_vec: Vec<i32> = sections
-        .first()
-        .unwrap()
-        .split('-')
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
-    let second_section_vec: Vec<i32> = sections
-        .last()
-        .unwrap()
-        .split('-')
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect();
+fn parse_section(parser: &mut Parser) -> Result<RangeSet> {
+    let start = parser.number::<i64>()?;
+    parser.literal("-")?;
+    let end = parser.number::<i64>()?;
+    if end < start {
+        return Err(parser.error(format!(
+            "the section {}-{} ends before it starts",
+            start, end
+        )));
+    }
+
+    Ok(RangeSet::from(start..=end))
+}
 
-    let first_section: HashSet<i32> = HashSet::from_iter(
-        *first_section_vec.first().unwrap()..=*first_section_vec.last().unwrap(),
-    );
-    let second_section: HashSet<i32> = HashSet::from_iter(
-        *second_section_vec.first().unwrap()..=*second_section_vec.last().unwrap(),
-    );
+#[derive(Args)]
+pub struct DayFour;
 
-    (first_section, second_section)
+impl Solution for DayFour {
+    type Input = Assignments;
+
+    fn parse(&self, input: &str) -> Result<Assignments> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Assignments) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Assignments) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
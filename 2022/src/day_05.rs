@@ -1,188 +1,198 @@
-use advent_of_code::read_lines;
-use std::collections::BTreeMap;
-use std::collections::VecDeque;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> String {
-    let (stacks, manifests) = stacks_and_manifests_from_input(input_path);
-    let mut crate_mover: CrateMover9000 = CrateMover9000::new(stacks, manifests);
-    crate_mover.execute_manifests();
-
-    crate_mover.top_of_stacks()
+use advent_of_code::parse::{blocks, Block, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// The starting stacks, each from the bottom crate up, and the rearrangement
+// steps in the order the crane carries them out
+pub struct Procedure {
+    stacks: Vec<Vec<char>>,
+    // The line of the stack numbers, for errors about the stacks
+    stacks_line: usize,
+    steps: Vec<Step>,
 }
 
-pub fn part_two(input_path: &Path) -> String {
-    let (stacks, manifests) = stacks_and_manifests_from_input(input_path);
-    let mut crate_mover: CrateMover9001 = CrateMover9001::new(stacks, manifests);
-    crate_mover.execute_manifests();
-
-    crate_mover.top_of_stacks()
+struct Step {
+    line: usize,
+    count: usize,
+    // Indexes into the stacks, so one less than the numbers in the input
+    from: usize,
+    to: usize,
 }
 
-struct CrateMover9000 {
-    crates: BTreeMap<i32, VecDeque<String>>,
-    manifests: Vec<CrateManifest>,
+fn solve_part_one(procedure: &Procedure) -> Result<String> {
+    // The CrateMover 9000 moves one crate at a time, reversing their order
+    rearrange(procedure, |crane_arm| crane_arm.reverse())
 }
 
-impl CrateMover9000 {
-    fn new(
-        crates: BTreeMap<i32, VecDeque<String>>,
-        manifests: Vec<CrateManifest>,
-    ) -> CrateMover9000 {
-        CrateMover9000 { crates, manifests }
-    }
-
-    fn execute_manifests(&mut self) {
-        let mut _manifests = self.manifests.iter().peekable();
-        while _manifests.peek().is_some() {
-            if let Some(manifest) = _manifests.next() {
-                let mut index = 0;
-                let mut crane_arm: VecDeque<String> = VecDeque::new();
-
-                if let Some(from_stack) = self.crates.get_mut(&manifest.from_stack) {
-                    while index < manifest.number_of_crates {
-                        if let Some(item) = from_stack.pop_front() {
-                            crane_arm.push_front(item);
-                        }
-                        index = index + 1;
-                    }
-                }
-
-                if let Some(to_stack) = self.crates.get_mut(&manifest.to_stack) {
-                    while let Some(item) = crane_arm.pop_back() {
-                        to_stack.push_front(item);
-                    }
-                }
-            }
-        }
-    }
+fn solve_part_two(procedure: &Procedure) -> Result<String> {
+    // The CrateMover 9001 lifts all the crates at once, keeping their order
+    rearrange(procedure, |_| {})
+}
 
-    fn top_of_stacks(&self) -> String {
-        let mut result: String = "".to_string();
-        for (_, value) in &self.crates {
-            result.push(value.get(0).unwrap().chars().next().unwrap());
+fn rearrange(procedure: &Procedure, mut lift: impl FnMut(&mut Vec<char>)) -> Result<String> {
+    let mut stacks = procedure.stacks.clone();
+
+    for step in &procedure.steps {
+        let from_stack = &mut stacks[step.from];
+        if from_stack.len() < step.count {
+            return Err(Error::parse(
+                step.line,
+                format!(
+                    "cannot move {} crates from stack {}, which holds {}",
+                    step.count,
+                    step.from + 1,
+                    from_stack.len()
+                ),
+            ));
         }
 
-        result
+        let mut crane_arm = from_stack.split_off(from_stack.len() - step.count);
+        lift(&mut crane_arm);
+        stacks[step.to].extend(crane_arm);
     }
+
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(index, stack)| {
+            stack.last().copied().ok_or_else(|| {
+                Error::parse(
+                    procedure.stacks_line,
+                    format!("stack {} ends up empty", index + 1),
+                )
+            })
+        })
+        .collect()
 }
 
-struct CrateMover9001 {
-    crates: BTreeMap<i32, VecDeque<String>>,
-    manifests: Vec<CrateManifest>,
+// A drawing of the stacks ending in their numbers, a blank line, then steps
+// like "move 1 from 2 to 1"
+fn parse_input(input: &str) -> Result<Procedure> {
+    let blocks = blocks(input);
+    let [drawing, steps] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            format!(
+                "expected a drawing of the stacks and a list of steps, found {} sections",
+                blocks.len()
+            ),
+        ));
+    };
+
+    let (stacks_line, stacks) = parse_drawing(drawing)?;
+    let steps = steps
+        .numbered_lines()
+        .map(|(line_number, line)| parse_step(line_number, line, stacks.len()))
+        .collect::<Result<Vec<Step>>>()?;
+
+    Ok(Procedure {
+        stacks,
+        stacks_line,
+        steps,
+    })
 }
 
-impl CrateMover9001 {
-    fn new(
-        crates: BTreeMap<i32, VecDeque<String>>,
-        manifests: Vec<CrateManifest>,
-    ) -> CrateMover9001 {
-        CrateMover9001 { crates, manifests }
+fn parse_drawing(drawing: &Block) -> Result<(usize, Vec<Vec<char>>)> {
+    let mut lines: Vec<(usize, &str)> = drawing.numbered_lines().collect();
+    let (stacks_line, numbers) = lines.pop().unwrap_or((drawing.line, ""));
+
+    let mut parser = Parser::new(stacks_line, numbers);
+    let mut count = 0;
+    while !parser.rest().trim().is_empty() {
+        let number: usize = parser.number()?;
+        if number != count + 1 {
+            return Err(parser.error(format!(
+                "expected stack {}, found stack {}",
+                count + 1,
+                number
+            )));
+        }
+        count += 1;
+    }
+    if count == 0 {
+        return Err(Error::parse(stacks_line, "expected the stack numbers"));
     }
 
-    fn execute_manifests(&mut self) {
-        let mut _manifests = self.manifests.iter().peekable();
-        while _manifests.peek().is_some() {
-            if let Some(manifest) = _manifests.next() {
-                let mut index = 0;
-                let mut crane_arm: VecDeque<String> = VecDeque::new();
-
-                if let Some(from_stack) = self.crates.get_mut(&manifest.from_stack) {
-                    while index < manifest.number_of_crates {
-                        if let Some(item) = from_stack.pop_front() {
-                            crane_arm.push_back(item);
-                        }
-                        index = index + 1;
-                    }
-                }
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); count];
+    // Crates are drawn from the top down, so stack them from the bottom line
+    for &(line_number, line) in lines.iter().rev() {
+        let cells: Vec<char> = line.chars().collect();
+        if cells.len() > count * 4 {
+            return Err(Error::parse_at(
+                line_number,
+                count * 4,
+                format!("expected at most {} stacks", count),
+            ));
+        }
 
-                if let Some(to_stack) = self.crates.get_mut(&manifest.to_stack) {
-                    while let Some(item) = crane_arm.pop_back() {
-                        to_stack.push_front(item);
-                    }
+        for (index, cell) in cells.chunks(4).enumerate() {
+            match cell {
+                ['[', name, ']'] | ['[', name, ']', ' '] if name.is_ascii_uppercase() => {
+                    stacks[index].push(*name)
+                }
+                _ if cell.iter().all(|&c| c == ' ') => {}
+                _ => {
+                    return Err(Error::parse_at(
+                        line_number,
+                        index * 4 + 1,
+                        format!(
+                            "expected a crate like \"[A]\" or a gap, found {:?}",
+                            cell.iter().collect::<String>()
+                        ),
+                    ))
                 }
             }
         }
     }
 
-    fn top_of_stacks(&self) -> String {
-        let mut result: String = "".to_string();
-        for (_, value) in &self.crates {
-            result.push(value.get(0).unwrap().chars().next().unwrap());
-        }
-
-        result
-    }
+    Ok((stacks_line, stacks))
 }
 
-#[derive(Debug)]
-struct CrateManifest {
-    number_of_crates: i32,
-    from_stack: i32,
-    to_stack: i32,
+fn parse_step(line_number: usize, line: &str, stacks: usize) -> Result<Step> {
+    let mut parser = Parser::new(line_number, line);
+    parser.literal("move")?;
+    let count = parser.number()?;
+    parser.literal("from")?;
+    let from = parse_stack(&mut parser, stacks)?;
+    parser.literal("to")?;
+    let to = parse_stack(&mut parser, stacks)?;
+    parser.end()?;
+
+    Ok(Step {
+        line: line_number,
+        count,
+        from,
+        to,
+    })
 }
 
-impl CrateManifest {
-    fn new(number_of_crates: i32, from_stack: i32, to_stack: i32) -> CrateManifest {
-        CrateManifest {
-            number_of_crates,
-            from_stack,
-            to_stack,
-        }
+fn parse_stack(parser: &mut Parser, stacks: usize) -> Result<usize> {
+    let number: usize = parser.number()?;
+    if !(1..=stacks).contains(&number) {
+        return Err(parser.error(format!(
+            "there is no stack {}, only 1 to {}",
+            number, stacks
+        )));
     }
+
+    Ok(number - 1)
 }
 
-fn stacks_and_manifests_from_input(
-    input_path: &Path,
-) -> (BTreeMap<i32, VecDeque<String>>, Vec<CrateManifest>) {
-    let mut stack_map: BTreeMap<i32, VecDeque<String>> = BTreeMap::new();
-    let mut manifests: Vec<CrateManifest> = Vec::new();
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if result.is_empty() {
-                    continue;
-                } else if result.contains("move") {
-                    let moves = result.split(' ').collect::<Vec<&str>>();
-
-                    let manifest = CrateManifest::new(
-                        moves.get(1).unwrap().parse::<i32>().unwrap(),
-                        moves.get(3).unwrap().parse::<i32>().unwrap(),
-                        moves.get(5).unwrap().parse::<i32>().unwrap(),
-                    );
-
-                    manifests.push(manifest);
-                } else {
-                    let mut i = 1;
-                    let mut j = 1;
-
-                    while i < result.trim_end().len() {
-                        let index = j.try_into().unwrap();
-                        stack_map.entry(index).or_insert(VecDeque::new());
-                        let working_queue = stack_map.get_mut(&index).unwrap();
-
-                        let item: String = result
-                            .trim_end()
-                            .to_string()
-                            .get(i..=i)
-                            .unwrap()
-                            .to_string()
-                            .trim_end()
-                            .to_string();
-                        if !item.is_empty() {
-                            let item_char = item.chars().next().unwrap();
-                            if !item_char.is_numeric() {
-                                working_queue.push_back(item);
-                            }
-                        }
-                        i = i + 4;
-                        j = j + 1;
-                    }
-                }
-            }
-        }
+#[derive(Args)]
+pub struct DayFive;
+
+impl Solution for DayFive {
+    type Input = Procedure;
+
+    fn parse(&self, input: &str) -> Result<Procedure> {
+        parse_input(input)
     }
 
-    (stack_map, manifests)
+    fn part_one(&self, input: &Procedure) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Procedure) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
 }
@@ -1,33 +1,45 @@
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashSet;
 use std::collections::VecDeque;
-use std::fmt;
 
-type Result<T> = std::result::Result<T, MissingStartOfPacketError>;
+// The datastream is a single line of lowercase letters
+fn parse_input(input: &str) -> Result<String> {
+    let mut lines = numbered_lines(input).filter(|(_, line)| !line.trim().is_empty());
+    let (line_number, line) = lines
+        .next()
+        .ok_or_else(|| Error::parse(1, "expected a datastream"))?;
+    let datastream = line.trim();
+    if let Some(index) = datastream.find(|c: char| !c.is_ascii_lowercase()) {
+        return Err(Error::parse_at(
+            line_number,
+            index + 1,
+            format!("expected a lowercase letter in {:?}", datastream),
+        ));
+    }
+    if let Some((line_number, _)) = lines.next() {
+        return Err(Error::parse(line_number, "expected a single datastream"));
+    }
 
-pub fn part_one(input: String) -> Result<i32> {
+    Ok(datastream.to_string())
+}
+
+fn solve_part_one(input: &str) -> Result<i32> {
     let start_of_packet_detector: StartOfPacketDetector = StartOfPacketDetector::new(4);
 
-    return match start_of_packet_detector.run(input) {
+    match start_of_packet_detector.run(input) {
         Ok(start_of_packet) => Ok(start_of_packet.index),
         Err(err) => Err(err),
-    };
+    }
 }
 
-pub fn part_two(input: String) -> Result<i32> {
+fn solve_part_two(input: &str) -> Result<i32> {
     let start_of_packet_detector: StartOfPacketDetector = StartOfPacketDetector::new(14);
 
-    return match start_of_packet_detector.run(input) {
+    match start_of_packet_detector.run(input) {
         Ok(start_of_packet) => Ok(start_of_packet.index),
         Err(err) => Err(err),
-    };
-}
-
-#[derive(Debug, Clone)]
-pub struct MissingStartOfPacketError;
-
-impl fmt::Display for MissingStartOfPacketError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "no start of packet discovered")
     }
 }
 
@@ -46,14 +58,11 @@ impl StartOfPacketDetector {
         }
     }
 
-    fn run(&self, datastream: String) -> Result<StartOfPacket> {
-        let len_comparator: usize = self.start_of_packet_length.try_into().unwrap();
+    fn run(&self, datastream: &str) -> Result<StartOfPacket> {
+        let len_comparator: usize = self.start_of_packet_length as usize;
         let mut signal_buffer: VecDeque<char> = VecDeque::with_capacity(len_comparator);
-        let mut index: i32 = 0;
-
-        for char in datastream.chars() {
+        for (index, char) in (1_i32..).zip(datastream.chars()) {
             signal_buffer.push_back(char);
-            index = index + 1;
 
             if signal_buffer.len() == len_comparator {
                 let signal_set: HashSet<&char> = HashSet::from_iter(signal_buffer.iter());
@@ -64,6 +73,26 @@ impl StartOfPacketDetector {
             }
         }
 
-        Err(MissingStartOfPacketError)
+        // The datastream is a single line, so the whole of line 1 was searched
+        Err(Error::parse(1, "no start of packet discovered"))
+    }
+}
+
+#[derive(Args)]
+pub struct DaySix;
+
+impl Solution for DaySix {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &String) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &String) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
     }
 }
@@ -1,23 +1,23 @@
-use advent_of_code::read_lines;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::{HashMap, VecDeque};
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let input: Input = Input::new(input_path);
-
-    input
+fn solve_part_one(forest: &Forest) -> i32 {
+    forest
         .trees
         .iter()
-        .filter(|t| t.is_visible(input.rows - 1, input.columns - 1))
-        .count()
-        .try_into()
-        .unwrap()
+        .filter(|t| t.is_visible(forest.rows - 1, forest.columns - 1))
+        .count() as i32
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let input: Input = Input::new(input_path);
-
-    input.trees.iter().map(|t| t.scenic_score()).max().unwrap()
+fn solve_part_two(forest: &Forest) -> i32 {
+    forest
+        .trees
+        .iter()
+        .map(|t| t.scenic_score())
+        .max()
+        .unwrap_or_default()
 }
 
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
@@ -59,7 +59,7 @@ impl Tree {
         edge_column: isize,
         neighbors: &TreeNeighbors,
     ) -> bool {
-        self.is_edge_tree(edge_row, edge_column) || neighbors.is_tree_visible(&self)
+        self.is_edge_tree(edge_row, edge_column) || neighbors.is_tree_visible(self)
     }
 }
 
@@ -119,7 +119,7 @@ impl TreeNeighbors {
         let mut viewing_distance: i32 = 0;
 
         for neighbor in neighbors {
-            viewing_distance = viewing_distance + 1;
+            viewing_distance += 1;
             if neighbor.height >= tree.height {
                 break;
             }
@@ -149,36 +149,50 @@ impl TreeWithNeighbors {
     }
 }
 
-struct Input {
+pub struct Forest {
     trees: Vec<TreeWithNeighbors>,
     rows: isize,
     columns: isize,
 }
 
-impl Input {
-    pub fn new(input_path: &Path) -> Input {
+impl Forest {
+    pub fn parse(input: &str) -> Result<Forest> {
         let mut row_index: isize = 0;
         let mut column_index: isize = 0;
         let mut temporary_map: HashMap<TreeCoordinate, Tree> = HashMap::new();
         let mut trees: Vec<TreeWithNeighbors> = Vec::new();
+        let mut first_row_width: Option<isize> = None;
+
+        for (line_number, result) in numbered_lines(input) {
+            column_index = 0;
+
+            for char in result.chars() {
+                let height: u32 = char.to_digit(10).ok_or_else(|| {
+                    Error::parse_at(
+                        line_number,
+                        column_index as usize + 1,
+                        format!("expected a tree height, found {:?}", char),
+                    )
+                })?;
+                let coordinates: TreeCoordinate = TreeCoordinate::new(row_index, column_index);
+                temporary_map.insert(coordinates, Tree::new(height, coordinates));
+                column_index += 1;
+            }
 
-        if let Ok(lines) = read_lines(input_path) {
-            for line in lines {
-                column_index = 0;
-
-                if let Ok(result) = line {
-                    for char in result.chars() {
-                        let height: u32 = char.to_digit(10).unwrap();
-                        let coordinates: TreeCoordinate =
-                            TreeCoordinate::new(row_index.into(), column_index.into());
-                        temporary_map
-                            .insert(coordinates.clone(), Tree::new(height, coordinates.clone()));
-                        column_index = column_index + 1;
-                    }
-                }
-
-                row_index = row_index + 1;
+            // Every row must be as wide as the first so the forest is a grid
+            let width = *first_row_width.get_or_insert(column_index);
+            if column_index == 0 || column_index != width {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected a row of {} trees, found {}", width, column_index),
+                ));
             }
+
+            row_index += 1;
+        }
+
+        if row_index == 0 {
+            return Err(Error::parse(1, "expected at least one row of trees"));
         }
 
         let rows: isize = row_index;
@@ -200,9 +214,9 @@ impl Input {
                         TreeCoordinate::new(inner_row, column);
                     if let Some(neighbor) = temporary_map.get(&neighbor_coordinate) {
                         if inner_row < row {
-                            current_neighbors.add_upper_neighbor(neighbor.clone());
+                            current_neighbors.add_upper_neighbor(*neighbor);
                         } else if inner_row > row {
-                            current_neighbors.add_lower_neighbor(neighbor.clone());
+                            current_neighbors.add_lower_neighbor(*neighbor);
                         }
                     }
                 }
@@ -217,26 +231,42 @@ impl Input {
 
                     if let Some(neighbor) = temporary_map.get(&neighbor_coordinate) {
                         if inner_column < column {
-                            current_neighbors.add_left_neighbor(neighbor.clone());
+                            current_neighbors.add_left_neighbor(*neighbor);
                         } else if inner_column > column {
-                            current_neighbors.add_right_neighbor(neighbor.clone());
+                            current_neighbors.add_right_neighbor(*neighbor);
                         }
                     }
                 }
 
-                trees.push(TreeWithNeighbors::new(
-                    current_tree.clone(),
-                    current_neighbors,
-                ))
+                trees.push(TreeWithNeighbors::new(*current_tree, current_neighbors))
             }
 
             columns = column_index;
         }
 
-        Input {
+        Ok(Forest {
             rows: row_index,
             columns: column_index,
             trees,
-        }
+        })
+    }
+}
+
+#[derive(Args)]
+pub struct DayEight;
+
+impl Solution for DayEight {
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> Result<Forest> {
+        Forest::parse(input)
+    }
+
+    fn part_one(&self, input: &Forest) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Forest) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
     }
 }
@@ -1,23 +1,17 @@
-use advent_of_code::read_lines;
+use advent_of_code::{Answer, Coordinate, Direction, Error, Result, Solution};
+use clap::Args;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::path::Path;
 
-const UP_DIRECTION_STRING: &str = "U";
-const DOWN_DIRECTION_STRING: &str = "D";
-const LEFT_DIRECTION_STRING: &str = "L";
-const RIGHT_DIRECTION_STRING: &str = "R";
-
-pub fn part_one(input_path: &Path) -> i32 {
-    run(input_path, 2)
+fn solve_part_one(moves: &[Move]) -> i32 {
+    run(moves, 2)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    run(input_path, 10)
+fn solve_part_two(moves: &[Move]) -> i32 {
+    run(moves, 10)
 }
 
-fn run(input_path: &Path, length_of_rope: usize) -> i32 {
-    let moves: Vec<Move> = read_input(input_path);
+fn run(moves: &[Move], length_of_rope: usize) -> i32 {
     let mut tail_visited_coordinates: HashSet<Coordinate> = HashSet::new();
     let previous_knot: &mut Knot = &mut Knot::new(-1, Coordinate::new(-1, -1));
 
@@ -29,24 +23,21 @@ fn run(input_path: &Path, length_of_rope: usize) -> i32 {
         );
     }
 
-    for move_to_execute in &moves {
+    for move_to_execute in moves {
         for _ in 0..move_to_execute.steps {
             // Move the head knot first
             knots.entry(0).and_modify(|k| {
-                k.move_in_direction(&move_to_execute.direction, 1);
-                *previous_knot = k.clone();
+                k.coordinate += move_to_execute.direction;
+                *previous_knot = *k;
             });
             for current_knot_index in 1..length_of_rope {
                 knots.entry(current_knot_index).and_modify(|k| {
-                    while !k.touching(&previous_knot) {
-                        let knot_moves: Vec<Move> = k.determine_next_moves(&previous_knot);
-                        for knot_move in knot_moves {
-                            k.move_in_direction(&knot_move.direction, 1);
-                        }
+                    while !k.touching(previous_knot) {
+                        k.coordinate += k.direction_towards(previous_knot);
                     }
-                    *previous_knot = k.clone();
+                    *previous_knot = *k;
                     if current_knot_index == (length_of_rope - 1) {
-                        tail_visited_coordinates.insert(k.coordinate.clone());
+                        tail_visited_coordinates.insert(k.coordinate);
                     }
                 });
             }
@@ -57,47 +48,28 @@ fn run(input_path: &Path, length_of_rope: usize) -> i32 {
 }
 
 #[derive(Debug)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-    Unknown,
-}
-
-#[derive(Debug)]
-struct Move {
+pub struct Move {
     direction: Direction,
     steps: i32,
 }
 
-impl Move {
-    pub fn new(direction: Direction, steps: i32) -> Move {
-        Move { direction, steps }
-    }
-}
+fn line_to_move(line_number: usize, line: &str) -> Result<Move> {
+    let invalid = || {
+        Error::parse(
+            line_number,
+            format!("expected `<U|D|L|R> <steps>`, found {:?}", line),
+        )
+    };
+    let (direction, steps) = line.split_once(' ').ok_or_else(invalid)?;
 
-fn line_to_move(line: &String) -> Move {
-    let mut split_line = line.split(" ").collect::<Vec<_>>();
-    let steps: i32 = split_line
-        .pop()
-        .expect("unable to pop a move from the input line")
-        .parse()
-        .unwrap();
-    let direction = split_line
-        .pop()
-        .expect("unable to pop a move from the input line");
-
-    // Use shadowing here to override the direction string from the input
-    let direction = match direction {
-        UP_DIRECTION_STRING => Direction::Up,
-        DOWN_DIRECTION_STRING => Direction::Down,
-        LEFT_DIRECTION_STRING => Direction::Left,
-        RIGHT_DIRECTION_STRING => Direction::Right,
-        &_ => Direction::Unknown,
+    let mut direction_chars = direction.chars();
+    let direction = match (direction_chars.next(), direction_chars.next()) {
+        (Some(c), None) => Direction::from_char(c).ok_or_else(invalid)?,
+        _ => return Err(invalid()),
     };
+    let steps: i32 = steps.parse().map_err(|_| invalid())?;
 
-    Move::new(direction, steps)
+    Ok(Move { direction, steps })
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -147,69 +119,39 @@ impl Knot {
         neighbor_coordinates.contains(&knot.coordinate)
     }
 
-    pub fn determine_next_moves(&self, knot: &Knot) -> Vec<Move> {
-        let x_difference: i32 = self.coordinate.x - knot.coordinate.x;
-        let y_difference = self.coordinate.y - knot.coordinate.y;
-        let mut moves: Vec<Move> = Vec::new();
-
-        // If the same row, towards the other knot by 1 in the direction
-        if x_difference < 0 {
-            moves.push(Move::new(Direction::Up, 1));
-        } else if x_difference > 0 {
-            moves.push(Move::new(Direction::Down, 1));
-        }
-
-        // If the same column, towards the other knot by 1 in the direction
-        if y_difference < 0 {
-            moves.push(Move::new(Direction::Right, 1));
-        } else if y_difference > 0 {
-            moves.push(Move::new(Direction::Left, 1))
-        }
-
-        moves
+    // The single step, possibly diagonal, that brings this knot closer to the other
+    pub fn direction_towards(&self, knot: &Knot) -> Direction {
+        Direction::from_offset(
+            (knot.coordinate.x - self.coordinate.x).signum(),
+            (knot.coordinate.y - self.coordinate.y).signum(),
+        )
+        .expect("a knot that is not touching is at least one step away")
     }
+}
 
-    pub fn move_in_direction(&mut self, direction: &Direction, steps: i32) {
-        match direction {
-            Direction::Up => {
-                self.coordinate.x = self.coordinate.x + steps;
-            }
-            Direction::Down => {
-                self.coordinate.x = self.coordinate.x - steps;
-            }
-            Direction::Left => {
-                self.coordinate.y = self.coordinate.y - steps;
-            }
-            Direction::Right => {
-                self.coordinate.y = self.coordinate.y + steps;
-            }
-            Direction::Unknown => {}
-        }
-    }
+fn read_input(input: &str) -> Result<Vec<Move>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| line_to_move(index + 1, line))
+        .collect()
 }
 
-fn read_input(input_path: &Path) -> Vec<Move> {
-    let mut moves: Vec<Move> = Vec::new();
+#[derive(Args)]
+pub struct DayNine;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                moves.push(line_to_move(&result));
-            }
-        }
-    }
+impl Solution for DayNine {
+    type Input = Vec<Move>;
 
-    moves
-}
+    fn parse(&self, input: &str) -> Result<Vec<Move>> {
+        read_input(input)
+    }
 
-#[derive(Debug, Eq, Hash, PartialEq, Clone, Copy)]
-struct Coordinate {
-    x: i32,
-    y: i32,
-}
+    fn part_one(&self, input: &Vec<Move>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
 
-impl Coordinate {
-    pub fn new(x: i32, y: i32) -> Coordinate {
-        Coordinate { x, y }
+    fn part_two(&self, input: &Vec<Move>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
     }
 }
@@ -1,58 +1,76 @@
-use advent_of_code::read_lines;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use log::debug;
-use regex::Regex;
 use std::collections::BTreeMap;
 use std::fmt;
-use std::path::Path;
+
+#[derive(Clone, Copy)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+// One "noop" or "addx <value>" per line
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    let mut program: Vec<Instruction> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let instruction = match parser.word()? {
+            "noop" => Instruction::Noop,
+            "addx" => Instruction::Addx(parser.number()?),
+            other => {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected \"noop\" or \"addx\", found {:?}", other),
+                ))
+            }
+        };
+        parser.end()?;
+        program.push(instruction);
+    }
+
+    if program.is_empty() {
+        return Err(Error::parse(1, "expected at least one instruction"));
+    }
+
+    Ok(program)
+}
 
 fn should_calculate_signal_strength(current_cycle: usize) -> bool {
     if current_cycle < 20 {
         return false;
     }
-    current_cycle == 20 || (current_cycle - 20) % 40 == 0
+    current_cycle == 20 || (current_cycle - 20).is_multiple_of(40)
 }
 
-pub fn part_one(input_path: &Path) -> i32 {
+fn solve_part_one(program: &[Instruction]) -> i32 {
     let mut register_value: i32 = 1;
 
-    let noop_regex = Regex::new(r"^noop").unwrap();
-    let addx_regex = Regex::new(r"^addx\s+(?P<increment_by>-?\d+)").unwrap();
     let mut next_execution_cycle: usize = 1;
     let total_cycles: usize = 220;
 
     let mut cycle_executions: BTreeMap<usize, Vec<i32>> = BTreeMap::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for (_, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                match addx_regex.captures(&result) {
-                    Some(capture) => match capture.name("increment_by") {
-                        Some(increment_by) => {
-                            let _increment_by: i32 = increment_by.as_str().parse().unwrap();
-                            next_execution_cycle = next_execution_cycle + 2;
-
-                            cycle_executions
-                                .entry(next_execution_cycle)
-                                .and_modify(|v| {
-                                    v.push(_increment_by);
-                                })
-                                .or_insert(Vec::from([_increment_by]));
-                        }
-                        None => {}
-                    },
-                    None => {}
-                };
-
-                match noop_regex.captures(&result) {
-                    Some(_) => {
-                        cycle_executions
-                            .entry(next_execution_cycle)
-                            .and_modify(|v| v.push(0))
-                            .or_insert(Vec::from([0]));
-                        next_execution_cycle = next_execution_cycle + 1;
-                    }
-                    None => {}
-                };
+    for &instruction in program {
+        match instruction {
+            Instruction::Addx(increment_by) => {
+                next_execution_cycle += 2;
+
+                cycle_executions
+                    .entry(next_execution_cycle)
+                    .and_modify(|v| {
+                        v.push(increment_by);
+                    })
+                    .or_insert(Vec::from([increment_by]));
+            }
+            Instruction::Noop => {
+                cycle_executions
+                    .entry(next_execution_cycle)
+                    .and_modify(|v| v.push(0))
+                    .or_insert(Vec::from([0]));
+                next_execution_cycle += 1;
             }
         }
     }
@@ -67,7 +85,7 @@ pub fn part_one(input_path: &Path) -> i32 {
         if let Some(_cycle_executions) = cycle_executions.remove(&current_cycle) {
             for cycle_execution in _cycle_executions.iter() {
                 debug!("Executing {} in cycle {}", cycle_execution, current_cycle);
-                register_value = register_value + cycle_execution;
+                register_value += cycle_execution;
             }
         }
 
@@ -88,43 +106,26 @@ pub fn part_one(input_path: &Path) -> i32 {
     signal_strengths.iter().sum()
 }
 
-pub fn part_two(input_path: &Path) -> String {
+fn solve_part_two(program: &[Instruction]) -> Vec<String> {
     let mut register_value: i32 = 1;
 
-    let noop_regex = Regex::new(r"^noop").unwrap();
-    let addx_regex = Regex::new(r"^addx\s+(?P<increment_by>-?\d+)").unwrap();
     let mut next_execution_cycle: usize = 0;
 
     let mut cycle_executions: BTreeMap<usize, Vec<i32>> = BTreeMap::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for (_, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                match addx_regex.captures(&result) {
-                    Some(capture) => match capture.name("increment_by") {
-                        Some(increment_by) => {
-                            let _increment_by: i32 = increment_by.as_str().parse().unwrap();
-                            next_execution_cycle = next_execution_cycle + 2;
-
-                            cycle_executions
-                                .entry(next_execution_cycle)
-                                .and_modify(|v| {
-                                    v.push(_increment_by);
-                                })
-                                .or_insert(Vec::from([_increment_by]));
-                        }
-                        None => {}
-                    },
-                    None => {}
-                };
-
-                match noop_regex.captures(&result) {
-                    Some(_) => {
-                        next_execution_cycle = next_execution_cycle + 1;
-                    }
-                    None => {}
-                };
+    for &instruction in program {
+        match instruction {
+            Instruction::Addx(increment_by) => {
+                next_execution_cycle += 2;
+
+                cycle_executions
+                    .entry(next_execution_cycle)
+                    .and_modify(|v| {
+                        v.push(increment_by);
+                    })
+                    .or_insert(Vec::from([increment_by]));
             }
+            Instruction::Noop => next_execution_cycle += 1,
         }
     }
 
@@ -141,17 +142,14 @@ pub fn part_two(input_path: &Path) -> String {
             let currently_executing_cycle = current_cycle - 1;
 
             for cycle_execution in _cycle_executions.iter() {
-                register_value = register_value + cycle_execution;
+                register_value += cycle_execution;
                 debug!(
                     "End of cycle  {}: finish executing addx {} (Register X is now {})",
                     currently_executing_cycle, cycle_execution, register_value
                 );
 
-                match usize::try_from(register_value) {
-                    Ok(value) => {
-                        sprite_position.move_sprite(value);
-                    }
-                    Err(_) => {}
+                if let Ok(value) = usize::try_from(register_value) {
+                    sprite_position.move_sprite(value);
                 }
                 debug!("Sprite position: {}", sprite_position);
             }
@@ -178,14 +176,7 @@ pub fn part_two(input_path: &Path) -> String {
         }
     }
 
-    format!(
-        "\n{}",
-        crt_rows
-            .iter()
-            .map(|r| r.join(""))
-            .collect::<Vec<String>>()
-            .join("\n")
-    )
+    crt_rows.iter().map(|r| r.join("")).collect()
 }
 
 struct SpritePosition {
@@ -223,15 +214,12 @@ impl SpritePosition {
 
     pub fn move_sprite(&mut self, middle_position: usize) {
         self.clear();
-        self.sprites
-            .entry(middle_position - 1)
-            .and_modify(|s| *s = "#".to_string());
-        self.sprites
-            .entry(middle_position)
-            .and_modify(|s| *s = "#".to_string());
-        self.sprites
-            .entry(middle_position + 1)
-            .and_modify(|s| *s = "#".to_string());
+        // A sprite at the left edge hangs off the screen by one pixel
+        for position in middle_position.saturating_sub(1)..=middle_position + 1 {
+            self.sprites
+                .entry(position)
+                .and_modify(|s| *s = "#".to_string());
+        }
     }
 }
 
@@ -248,3 +236,22 @@ impl fmt::Display for SpritePosition {
         )
     }
 }
+
+#[derive(Args)]
+pub struct DayTen;
+
+impl Solution for DayTen {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Instruction>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Instruction>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
@@ -1,32 +1,29 @@
-use advent_of_code::read_file_to_string;
+use advent_of_code::math::lcm_all;
+use advent_of_code::parse::{blocks, key_list, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use log::debug;
-use regex::Regex;
 use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i64 {
-    monkey_business(load_monkeys(input_path), false, 20)
+fn solve_part_one(monkeys: &Monkeys) -> i64 {
+    monkey_business(monkeys.clone(), false, 20)
 }
 
-pub fn part_two(input_path: &Path) -> i64 {
-    monkey_business(load_monkeys(input_path), true, 10000)
+fn solve_part_two(monkeys: &Monkeys) -> i64 {
+    monkey_business(monkeys.clone(), true, 10000)
 }
 
 fn monkey_business(mut monkeys: Monkeys, worried: bool, rounds: usize) -> i64 {
-    let overall_worry_level: i64 = monkeys
-        .values()
-        .map(|m| m.test_operand as i64)
-        .collect::<Vec<i64>>()
-        .iter()
-        .product();
+    // Worry levels only matter modulo every monkey's divisor at once
+    let overall_worry_level: i64 = lcm_all(monkeys.values().map(|m| m.test_operand as i64));
     let monkey_ids = monkeys.clone();
 
     for round in 1..=rounds {
         for monkey_id in monkey_ids.keys() {
             debug!("Monkey {}:", monkey_id);
             monkeys
-                .get_mut(&monkey_id)
+                .get_mut(monkey_id)
                 .map(|m| m.pass_items(worried, Some(overall_worry_level)))
                 .unwrap()
                 .iter()
@@ -47,11 +44,11 @@ fn monkey_business(mut monkeys: Monkeys, worried: bool, rounds: usize) -> i64 {
 
     monkeys
         .iter()
-        .for_each(|(_, m)| println!("Monkey {} inspected items {} times.", m.id, m.inspections));
+        .for_each(|(_, m)| debug!("Monkey {} inspected items {} times.", m.id, m.inspections));
 
     let mut inspections: Vec<i64> = monkeys
-        .iter()
-        .map(|(_, m)| m.inspections as i64)
+        .values()
+        .map(|m| m.inspections as i64)
         .collect::<Vec<i64>>();
     inspections.sort();
     inspections.reverse();
@@ -59,91 +56,98 @@ fn monkey_business(mut monkeys: Monkeys, worried: bool, rounds: usize) -> i64 {
 
     let mut monkey_business_level: i64 = 1;
     for inspection in top_two_inspections {
-        monkey_business_level = monkey_business_level * inspection;
+        monkey_business_level *= inspection;
     }
 
     monkey_business_level
 }
 
-fn load_monkeys(input_path: &Path) -> Monkeys {
-    let monkey_id_regex = Regex::new(r"(?m)^Monkey\s+(?P<id>\d+)").unwrap();
-    let item_ids_regex = Regex::new(r"(?m)^\s+Starting\sitems:\s+(?P<item_ids>.+)").unwrap();
-    let operation_regex =
-        Regex::new(r"(?m)^\s+Operation:\s+new\s+=\s+old\s+(?P<operator>.)\s+(?P<operand>.+)")
-            .unwrap();
-    let test_regex = Regex::new(r"(?m)^\s+Test:\s+divisible\s+by\s+(?P<operand>.+)").unwrap();
-    let true_test_regex = Regex::new(r"(?m)^\s+If\s+true:\s+throw to monkey\s+(?P<id>.+)").unwrap();
-    let false_test_regex =
-        Regex::new(r"(?m)^\s+If\s+false:\s+throw to monkey\s+(?P<id>.+)").unwrap();
-
+fn load_monkeys(input: &str) -> Result<Monkeys> {
     let mut monkeys: Monkeys = Monkeys::new();
+    // Every monkey an item is thrown to, with the line that names it
+    let mut targets: Vec<(usize, i32)> = Vec::new();
+
+    for block in blocks(input) {
+        let lines: Vec<(usize, &str)> = block.numbered_lines().collect();
+        let [header, items, operation, test, if_true, if_false] = lines[..] else {
+            return Err(Error::parse(
+                block.line,
+                "expected six lines describing a monkey",
+            ));
+        };
 
-    if let Ok(lines) = read_file_to_string(input_path) {
-        for (index, _) in monkey_id_regex.captures_iter(&lines).enumerate() {
-            monkeys
-                .entry(index.try_into().unwrap())
-                .or_insert(Monkey::new(index.try_into().unwrap()));
-        }
-
-        for (index, cap) in item_ids_regex.captures_iter(&lines).enumerate() {
-            let items: VecDeque<Item> = cap
-                .name("item_ids")
-                .unwrap()
-                .as_str()
-                .split(", ")
-                .collect::<Vec<&str>>()
-                .iter()
-                .map(|i| Item::new(WorryLevel::new(i.parse::<i64>().unwrap())))
-                .collect::<VecDeque<Item>>();
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.items = items);
+        let mut parser = Parser::new(header.0, header.1);
+        parser.literal("Monkey")?;
+        let mut monkey = Monkey::new(parser.number()?);
+        parser.literal(":")?;
+        parser.end()?;
+        if monkeys.contains_key(&monkey.id) {
+            return Err(Error::parse(
+                header.0,
+                format!("monkey {} is described twice", monkey.id),
+            ));
         }
 
-        for (index, cap) in operation_regex.captures_iter(&lines).enumerate() {
-            let operator = cap.name("operator").unwrap().as_str().to_string();
-            let operand: String = cap.name("operand").unwrap().as_str().to_string();
-
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.operation_operator = operator);
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.operation_operand = operand);
+        let (key, worry_levels) = key_list::<i64>(items.0, items.1)?;
+        if key != "Starting items" {
+            return Err(Error::parse_at(
+                items.0,
+                1,
+                format!("expected starting items, found {:?}", key),
+            ));
         }
-
-        for (index, cap) in test_regex.captures_iter(&lines).enumerate() {
-            let operand: u32 = cap.name("operand").unwrap().as_str().parse().unwrap();
-
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.test_operand = operand);
+        monkey.items = worry_levels
+            .into_iter()
+            .map(|w| Item::new(WorryLevel::new(w)))
+            .collect();
+
+        let mut parser = Parser::new(operation.0, operation.1);
+        parser.literal("Operation:")?;
+        parser.literal("new = old")?;
+        monkey.operation_operator = parser.value(" ")?;
+        monkey.operation_operand = parser.value(" ")?;
+        parser.end()?;
+
+        let mut parser = Parser::new(test.0, test.1);
+        parser.literal("Test: divisible by")?;
+        monkey.test_operand = parser.number()?;
+        parser.end()?;
+        if monkey.test_operand == 0 {
+            return Err(Error::parse(test.0, "the divisor must be positive"));
         }
 
-        for (index, cap) in true_test_regex.captures_iter(&lines).enumerate() {
-            let id: i32 = cap.name("id").unwrap().as_str().parse().unwrap();
+        let mut parser = Parser::new(if_true.0, if_true.1);
+        parser.literal("If true: throw to monkey")?;
+        monkey.test_true_pass_id = parser.number()?;
+        parser.end()?;
 
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.test_true_pass_id = id);
-        }
+        let mut parser = Parser::new(if_false.0, if_false.1);
+        parser.literal("If false: throw to monkey")?;
+        monkey.test_false_pass_id = parser.number()?;
+        parser.end()?;
 
-        for (index, cap) in false_test_regex.captures_iter(&lines).enumerate() {
-            let id: i32 = cap.name("id").unwrap().as_str().parse().unwrap();
+        targets.push((if_true.0, monkey.test_true_pass_id));
+        targets.push((if_false.0, monkey.test_false_pass_id));
+        monkeys.insert(monkey.id, monkey);
+    }
 
-            monkeys
-                .entry(index.try_into().unwrap())
-                .and_modify(|m| m.test_false_pass_id = id);
-        }
+    if monkeys.len() < 2 {
+        return Err(Error::parse(1, "expected at least two monkeys"));
+    }
+    if let Some((line, target)) = targets.iter().find(|(_, t)| !monkeys.contains_key(t)) {
+        return Err(Error::parse(
+            *line,
+            format!("there is no monkey {}", target),
+        ));
     }
 
-    monkeys
+    Ok(monkeys)
 }
 
-type Monkeys = BTreeMap<i32, Monkey>;
+pub type Monkeys = BTreeMap<i32, Monkey>;
 
 #[derive(Debug, Clone)]
-struct WorryLevel {
+pub struct WorryLevel {
     value: i64,
 }
 
@@ -152,7 +156,7 @@ impl WorryLevel {
         WorryLevel { value }
     }
 
-    pub fn is_divisible_by(self, operand: i64) -> bool {
+    pub fn is_divisible_by(&self, operand: i64) -> bool {
         self.value % operand == 0
     }
 
@@ -162,7 +166,7 @@ impl WorryLevel {
             None => self.value,
         };
 
-        self.value = self.value / _operand;
+        self.value /= _operand;
 
         self.value
     }
@@ -173,7 +177,7 @@ impl WorryLevel {
             None => self.value,
         };
 
-        self.value = self.value % _operand;
+        self.value %= _operand;
 
         self.value
     }
@@ -184,7 +188,7 @@ impl WorryLevel {
             None => self.value,
         };
 
-        self.value = self.value + _operand;
+        self.value += _operand;
 
         self.value
     }
@@ -195,7 +199,7 @@ impl WorryLevel {
             None => self.value,
         };
 
-        self.value = self.value * _operand;
+        self.value *= _operand;
 
         self.value
     }
@@ -208,7 +212,7 @@ impl fmt::Display for WorryLevel {
 }
 
 #[derive(Debug, Clone)]
-struct PassedItem {
+pub struct PassedItem {
     to: i32,
     item: Item,
 }
@@ -220,7 +224,7 @@ impl PassedItem {
 }
 
 #[derive(Debug, Clone)]
-struct Item {
+pub struct Item {
     worry_level: WorryLevel,
 }
 
@@ -231,7 +235,7 @@ impl Item {
 }
 
 #[derive(Debug, Clone)]
-struct Monkey {
+pub struct Monkey {
     id: i32,
     inspections: i32,
     items: VecDeque<Item>,
@@ -257,10 +261,7 @@ impl Monkey {
     }
 
     pub fn operation(&self, item: &mut Item) -> i64 {
-        let right_operand = match self.operation_operand.parse::<i64>() {
-            Ok(operand) => Some(operand),
-            Err(_) => None,
-        };
+        let right_operand = self.operation_operand.parse::<i64>().ok();
 
         match self.operation_operator.as_str() {
             "+" => item.worry_level.add(right_operand),
@@ -283,7 +284,7 @@ impl Monkey {
         let mut passed_items: Vec<PassedItem> = Vec::new();
 
         while let Some(mut item) = self.items.pop_front() {
-            self.inspections = self.inspections + 1;
+            self.inspections += 1;
             let mut new_worry_level: i64 = self.operation(&mut item);
             debug!(
                 "  Monkey inspects an item with worry level of {}",
@@ -302,9 +303,7 @@ impl Monkey {
                 );
             }
 
-            match overall_worry_level {
-                _ => item.worry_level.modulo(overall_worry_level),
-            };
+            item.worry_level.modulo(overall_worry_level);
 
             let next_monkey_id: i32 = match item
                 .clone()
@@ -352,3 +351,22 @@ impl fmt::Display for Monkey {
         )
     }
 }
+
+#[derive(Args)]
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    type Input = Monkeys;
+
+    fn parse(&self, input: &str) -> Result<Monkeys> {
+        load_monkeys(input)
+    }
+
+    fn part_one(&self, input: &Monkeys) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Monkeys) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
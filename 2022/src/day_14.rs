@@ -1,20 +1,20 @@
-use advent_of_code::read_lines;
-use clap::builder::Str;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use log::{debug, log_enabled, Level};
 use std::collections::HashMap;
 use std::fmt;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i32 {
-    run(input_path, false, State::FellIntoAbyss)
+fn solve_part_one(paths: &RockPaths) -> i32 {
+    run(paths, false, State::FellIntoAbyss)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    run(input_path, true, State::Finished)
+fn solve_part_two(paths: &RockPaths) -> i32 {
+    run(paths, true, State::Finished)
 }
 
-fn run(input_path: &Path, infinite_board: bool, final_state: State) -> i32 {
-    let mut board: Board = input_to_board(input_path, infinite_board);
+fn run(paths: &RockPaths, infinite_board: bool, final_state: State) -> i32 {
+    let mut board: Board = input_to_board(paths, infinite_board);
     let sand_entry_coordinate: Coordinate = Coordinate::new(500, 0);
 
     let mut state: State = State::Start;
@@ -33,19 +33,21 @@ fn run(input_path: &Path, infinite_board: bool, final_state: State) -> i32 {
             instances_of_sand
         );
 
-        if sand_instance.coordinate == sand_entry_coordinate {
+        // Sand can also fall straight past every rock from where it enters
+        if state != State::FellIntoAbyss && sand_instance.coordinate == sand_entry_coordinate {
             debug!("We've reached the top of the cave with sand");
             state = State::Finished;
         }
         sand_instance.coordinate = sand_entry_coordinate;
 
-        instances_of_sand = instances_of_sand + 1;
+        instances_of_sand += 1;
     }
 
     count_sand(&board)
 }
 
 type Board = HashMap<Coordinate, BoardItem>;
+type RockPaths = Vec<Vec<Coordinate>>;
 
 #[derive(Debug)]
 enum BoardItem {
@@ -151,12 +153,12 @@ impl Sand {
                 _ => {}
             }
 
-            print_board(&board);
+            print_board(board);
         }
 
         self.update_board(board);
 
-        return self.state.clone();
+        self.state.clone()
     }
 
     fn update_board(&mut self, board: &mut Board) {
@@ -166,7 +168,7 @@ impl Sand {
     }
 
     fn blocked(&mut self, next_coordinate: &Coordinate, board: &Board) -> bool {
-        let next_board_item: Option<&BoardItem> = board.get(&next_coordinate);
+        let next_board_item: Option<&BoardItem> = board.get(next_coordinate);
 
         debug!("Sand is attempting to descend to {}", next_coordinate);
 
@@ -247,7 +249,7 @@ impl fmt::Display for Air {
 }
 
 #[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
-struct Coordinate {
+pub struct Coordinate {
     x: i32,
     y: i32,
 }
@@ -308,11 +310,7 @@ impl Coordinate {
 fn count_sand(board: &Board) -> i32 {
     board
         .values()
-        .into_iter()
-        .filter(|bi| match bi {
-            BoardItem::Sand(_) => true,
-            _ => false,
-        })
+        .filter(|bi| matches!(bi, BoardItem::Sand(_)))
         .count() as i32
 }
 
@@ -323,88 +321,104 @@ fn print_board(board: &Board) {
         let max_y = board_max_y(board);
 
         for x in 0..=max_x {
+            let mut row = String::new();
             for y in min_y..=max_y {
                 if let Some(bi) = board.get(&Coordinate::new(y, x)) {
                     match bi {
-                        BoardItem::Air(_bi) => print!("{}", _bi),
-                        BoardItem::Rock(_bi) => print!("{}", _bi),
-                        BoardItem::Sand(_bi) => print!("{}", _bi),
+                        BoardItem::Air(_bi) => row.push_str(&_bi.to_string()),
+                        BoardItem::Rock(_bi) => row.push_str(&_bi.to_string()),
+                        BoardItem::Sand(_bi) => row.push_str(&_bi.to_string()),
                     }
                 }
             }
-            println!("\n");
+            debug!("{}", row);
         }
     }
 }
 
-fn input_to_board(input_path: &Path, infinite_board: bool) -> Board {
+// Each line is a path of rock through "y,x" corners joined by "->"
+fn parse_input(input: &str) -> Result<RockPaths> {
+    let mut paths: RockPaths = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let path = parser.list("->", |parser| {
+            let y = parser.number::<i32>()?;
+            parser.literal(",")?;
+            let x = parser.number::<i32>()?;
+
+            Ok(Coordinate::new(y, x))
+        })?;
+        parser.end()?;
+
+        paths.push(path);
+    }
+
+    if paths.is_empty() {
+        return Err(Error::parse(1, "expected at least one path of rock"));
+    }
+
+    Ok(paths)
+}
+
+fn input_to_board(paths: &RockPaths, infinite_board: bool) -> Board {
     let mut board: Board = Board::new();
 
+    // The board always spans the column the sand enters from, or sand that
+    // misses every rock would widen the board on the wrong side forever
     let min_x: i32 = 0;
     let mut max_x: i32 = -1;
-    let mut min_y: i32 = i32::MAX;
-    let mut max_y: i32 = -1;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let mut result_mut: String = result.clone();
-                result_mut.retain(|c| !c.is_whitespace());
-                let mut input_coordinates: Vec<Coordinate> = Vec::new();
-                for coordinate_input in result_mut.split("->") {
-                    let y: i32 = coordinate_input.split(',').nth(0).unwrap().parse().unwrap();
-                    let x: i32 = coordinate_input.split(',').last().unwrap().parse().unwrap();
-
-                    if x > max_x {
-                        max_x = x;
-                    }
-                    if y < min_y {
-                        min_y = y;
-                    }
+    let mut min_y: i32 = 500;
+    let mut max_y: i32 = 500;
 
-                    if y > max_y {
-                        max_y = y;
-                    }
-                    let input_coordinate: Coordinate = Coordinate::new(y, x);
-                    input_coordinates.push(input_coordinate);
-                    debug!("Adding a new rock at {}", input_coordinate);
+    for input_coordinates in paths {
+        for &input_coordinate in input_coordinates {
+            let (x, y) = (input_coordinate.x, input_coordinate.y);
+
+            if x > max_x {
+                max_x = x;
+            }
+            if y < min_y {
+                min_y = y;
+            }
+
+            if y > max_y {
+                max_y = y;
+            }
+            debug!("Adding a new rock at {}", input_coordinate);
+            board.insert(
+                input_coordinate,
+                BoardItem::Rock(Rock::new(input_coordinate)),
+            );
+        }
+
+        for (index, input_coordinate) in input_coordinates.iter().enumerate() {
+            if let Some(next_coordinate) = input_coordinates.get(index + 1) {
+                for coordinate_between in input_coordinate.coordinates_between(next_coordinate) {
                     board.insert(
-                        input_coordinate,
-                        BoardItem::Rock(Rock::new(input_coordinate)),
+                        coordinate_between,
+                        BoardItem::Rock(Rock::new(coordinate_between)),
                     );
                 }
-
-                for (index, input_coordinate) in input_coordinates.iter().enumerate() {
-                    if let Some(next_coordinate) = input_coordinates.get(index + 1) {
-                        for coordinate_between in
-                            input_coordinate.coordinates_between(next_coordinate)
-                        {
-                            board.insert(
-                                coordinate_between.clone(),
-                                BoardItem::Rock(Rock::new(coordinate_between.clone())),
-                            );
-                        }
-                    }
-                }
             }
         }
     }
 
     if infinite_board {
-        max_x = max_x + 2;
+        max_x += 2;
     }
 
     // Fill the rest of the board with air
     for row in min_x..=max_x {
         for col in min_y..=max_y {
             let fill_coordinate: Coordinate = Coordinate::new(col, row);
-            if board.get(&fill_coordinate).is_none() {
+            board.entry(fill_coordinate).or_insert_with(|| {
                 if row == max_x && infinite_board {
-                    board.insert(fill_coordinate, BoardItem::Rock(Rock::new(fill_coordinate)));
+                    BoardItem::Rock(Rock::new(fill_coordinate))
                 } else {
-                    board.insert(fill_coordinate, BoardItem::Air(Air::new(fill_coordinate)));
+                    BoardItem::Air(Air::new(fill_coordinate))
                 }
-            }
+            });
         }
     }
 
@@ -414,13 +428,11 @@ fn input_to_board(input_path: &Path, infinite_board: bool) -> Board {
 fn board_min_y(board: &Board) -> i32 {
     board
         .values()
-        .into_iter()
         .map(|bi| match bi {
             BoardItem::Air(_bi) => _bi.coordinate.y,
             BoardItem::Rock(_bi) => _bi.coordinate.y,
             BoardItem::Sand(_bi) => _bi.coordinate.y,
         })
-        .into_iter()
         .min()
         .unwrap()
 }
@@ -428,13 +440,11 @@ fn board_min_y(board: &Board) -> i32 {
 fn board_max_y(board: &Board) -> i32 {
     board
         .values()
-        .into_iter()
         .map(|bi| match bi {
             BoardItem::Air(_bi) => _bi.coordinate.y,
             BoardItem::Rock(_bi) => _bi.coordinate.y,
             BoardItem::Sand(_bi) => _bi.coordinate.y,
         })
-        .into_iter()
         .max()
         .unwrap()
 }
@@ -442,17 +452,11 @@ fn board_max_y(board: &Board) -> i32 {
 fn board_min_x(board: &Board) -> i32 {
     board
         .values()
-        .into_iter()
-        .map(|bi| {
-            let max = match bi {
-                BoardItem::Air(_bi) => _bi.coordinate.x,
-                BoardItem::Rock(_bi) => _bi.coordinate.x,
-                BoardItem::Sand(_bi) => _bi.coordinate.x,
-            };
-
-            max
+        .map(|bi| match bi {
+            BoardItem::Air(_bi) => _bi.coordinate.x,
+            BoardItem::Rock(_bi) => _bi.coordinate.x,
+            BoardItem::Sand(_bi) => _bi.coordinate.x,
         })
-        .into_iter()
         .min()
         .unwrap()
 }
@@ -460,17 +464,11 @@ fn board_min_x(board: &Board) -> i32 {
 fn board_max_x(board: &Board) -> i32 {
     board
         .values()
-        .into_iter()
-        .map(|bi| {
-            let max = match bi {
-                BoardItem::Air(_bi) => _bi.coordinate.x,
-                BoardItem::Rock(_bi) => _bi.coordinate.x,
-                BoardItem::Sand(_bi) => _bi.coordinate.x,
-            };
-
-            max
+        .map(|bi| match bi {
+            BoardItem::Air(_bi) => _bi.coordinate.x,
+            BoardItem::Rock(_bi) => _bi.coordinate.x,
+            BoardItem::Sand(_bi) => _bi.coordinate.x,
         })
-        .into_iter()
         .max()
         .unwrap()
 }
@@ -515,3 +513,22 @@ fn insert_columns(board: &mut Board, columns_to_add: i32, column_to_add: ColumnA
         }
     }
 }
+
+#[derive(Args)]
+pub struct DayFourteen;
+
+impl Solution for DayFourteen {
+    type Input = RockPaths;
+
+    fn parse(&self, input: &str) -> Result<RockPaths> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &RockPaths) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &RockPaths) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
@@ -1,83 +1,133 @@
-use advent_of_code::{read_lines, Coordinate};
+use advent_of_code::cycle::find_cycle;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
 use log::debug;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut patterns: JetPatterns = load_jet_patterns(input_path);
-    let mut top_of_pile: u32 = 0;
-    let mut chamber: HashSet<Coordinate> = HashSet::new();
-    let mut rocks_at_rest: u32 = 0;
-    let rock_goal: u32 = 2022;
-    let mut rock_factory = RockFactory::new();
+fn solve_part_one(patterns: &JetPatterns) -> i32 {
+    let mut tower = Tower::new(patterns.clone());
+    for _ in 0..2022 {
+        tower.drop_rock();
+    }
+
+    tower.top_of_pile() as i32
+}
+
+fn solve_part_two(patterns: &JetPatterns) -> i64 {
+    let mut tower = Tower::new(patterns.clone());
+    let cycle = find_cycle(&mut tower, Tower::drop_rock, Tower::key);
+
+    cycle.extrapolate(1_000_000_000_000, |rocks| tower.heights[rocks] as i64)
+}
+
+const CHAMBER_WIDTH: usize = 7;
+
+struct Tower {
+    chamber: HashSet<Coordinate>,
+    patterns: JetPatterns,
+    jets_used: usize,
+    rock_factory: RockFactory,
+    rocks_at_rest: usize,
+    // The highest rock in each column, where the floor is at 0
+    column_heights: [u32; CHAMBER_WIDTH],
+    // The height of the pile after each number of rocks, starting from none
+    heights: Vec<u32>,
+}
 
-    for y_coordinate in 1..=7 {
-        chamber.insert(Coordinate::new(0, y_coordinate));
+impl Tower {
+    pub fn new(patterns: JetPatterns) -> Tower {
+        let mut chamber: HashSet<Coordinate> = HashSet::new();
+        for y_coordinate in 1..=CHAMBER_WIDTH as i32 {
+            chamber.insert(Coordinate::new(0, y_coordinate));
+        }
+
+        Tower {
+            chamber,
+            patterns,
+            jets_used: 0,
+            rock_factory: RockFactory::new(),
+            rocks_at_rest: 0,
+            column_heights: [0; CHAMBER_WIDTH],
+            heights: vec![0],
+        }
+    }
+
+    pub fn top_of_pile(&self) -> u32 {
+        self.column_heights.iter().copied().max().unwrap()
     }
 
-    while rocks_at_rest != rock_goal {
+    pub fn drop_rock(&mut self) {
         debug!("A new rock begins falling");
-        let mut test_rock = rock_factory.next(top_of_pile + 4);
+        let mut test_rock = self.rock_factory.next(self.top_of_pile() + 4);
 
         loop {
-            if let Some(pattern) = next_jet_pattern(&mut patterns) {
+            if let Some(pattern) = next_jet_pattern(&mut self.patterns) {
                 debug!("Jet of gas pushes rock {:#?}", pattern);
-                test_rock.as_mut().push(&pattern, &chamber);
+                test_rock.as_mut().push(&pattern, &self.chamber);
+                self.jets_used = (self.jets_used + 1) % self.patterns.len();
             }
 
-            if !test_rock.has_come_to_rest(&chamber) {
+            if !test_rock.has_come_to_rest(&self.chamber) {
                 test_rock.as_mut().descend();
             } else {
                 break;
             }
         }
 
-        rocks_at_rest = rocks_at_rest + 1;
-        chamber.extend(test_rock.as_ref().all_coordinates());
-        top_of_pile = chamber.iter().map(|c| c.x).max().unwrap() as u32
-    }
-
-    top_of_pile as i32
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                println!("{}", result);
-            }
+        for coordinate in test_rock.as_ref().all_coordinates() {
+            let column = &mut self.column_heights[coordinate.y as usize - 1];
+            *column = (*column).max(coordinate.x as u32);
+            self.chamber.insert(coordinate);
         }
+        self.rocks_at_rest += 1;
+        self.heights.push(self.top_of_pile());
     }
 
-    0
+    // The next rock, the next jet and the shape of the top of the pile, which
+    // together decide everything that happens from here
+    pub fn key(&self) -> (usize, usize, [u32; CHAMBER_WIDTH]) {
+        let top = self.top_of_pile();
+        (
+            self.rocks_at_rest % 5,
+            self.jets_used,
+            self.column_heights.map(|height| top - height),
+        )
+    }
 }
 
 type JetPatterns = VecDeque<JetPattern>;
 
 #[derive(Debug, Clone)]
-enum JetPattern {
+pub enum JetPattern {
     Left,
     Right,
 }
 
-fn load_jet_patterns(input_path: &Path) -> JetPatterns {
+fn parse_input(input: &str) -> Result<JetPatterns> {
     let mut patterns: JetPatterns = JetPatterns::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                for char in result.chars() {
-                    match char {
-                        '<' => patterns.push_back(JetPattern::Left),
-                        '>' => patterns.push_back(JetPattern::Right),
-                        _ => debug!("Unknown jet pattern '{}'; ignoring", char),
-                    }
+    for (line_number, result) in numbered_lines(input) {
+        for (index, char) in result.chars().enumerate() {
+            match char {
+                '<' => patterns.push_back(JetPattern::Left),
+                '>' => patterns.push_back(JetPattern::Right),
+                _ => {
+                    return Err(Error::parse_at(
+                        line_number,
+                        index + 1,
+                        format!("expected '<' or '>', found {:?}", char),
+                    ))
                 }
             }
         }
     }
 
-    patterns
+    if patterns.is_empty() {
+        return Err(Error::parse(1, "expected at least one jet pattern"));
+    }
+
+    Ok(patterns)
 }
 
 trait Rock {
@@ -88,6 +138,7 @@ trait Rock {
 }
 
 #[derive(Clone)]
+#[allow(clippy::enum_variant_names)]
 enum RockType {
     FlatRock,
     CrossRock,
@@ -147,12 +198,12 @@ impl FlatRock {
 impl Rock for FlatRock {
     fn descend(&mut self) {
         for (_, c) in self.coordinates.iter_mut() {
-            c.x = c.x - 1;
+            c.x -= 1;
         }
     }
 
     fn has_come_to_rest(&self, chamber: &HashSet<Coordinate>) -> bool {
-        for (_, coordinate) in &self.coordinates {
+        for coordinate in self.coordinates.values() {
             if chamber.contains(&Coordinate::new(coordinate.x - 1, coordinate.y)) {
                 return true;
             }
@@ -162,11 +213,10 @@ impl Rock for FlatRock {
     }
 
     fn push(&mut self, jet_pattern: &JetPattern, chamber: &HashSet<Coordinate>) {
-        let mut can_push: bool = false;
         match jet_pattern {
             JetPattern::Left => {
                 let leftmost_coordinate = self.coordinates.get(&0).unwrap();
-                can_push = leftmost_coordinate.y != 1
+                let can_push = leftmost_coordinate.y != 1
                     && chamber
                         .get(&Coordinate::new(
                             leftmost_coordinate.x,
@@ -176,13 +226,13 @@ impl Rock for FlatRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y - 1;
+                        c.y -= 1;
                     }
                 }
             }
             JetPattern::Right => {
                 let rightmost_coordinate = self.coordinates.get(&3).unwrap();
-                can_push = rightmost_coordinate.y != 7
+                let can_push = rightmost_coordinate.y != 7
                     && chamber
                         .get(&Coordinate::new(
                             rightmost_coordinate.x,
@@ -192,7 +242,7 @@ impl Rock for FlatRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y + 1;
+                        c.y += 1;
                     }
                 }
             }
@@ -252,7 +302,7 @@ impl Rock for CrossRock {
     }
 
     fn has_come_to_rest(&self, chamber: &HashSet<Coordinate>) -> bool {
-        for (_, coordinate) in &self.coordinates {
+        for coordinate in self.coordinates.values() {
             if chamber.contains(&Coordinate::new(coordinate.x - 1, coordinate.y)) {
                 return true;
             }
@@ -263,18 +313,17 @@ impl Rock for CrossRock {
 
     fn descend(&mut self) {
         for (_, c) in self.coordinates.iter_mut() {
-            c.x = c.x - 1;
+            c.x -= 1;
         }
     }
 
     fn push(&mut self, jet_pattern: &JetPattern, chamber: &HashSet<Coordinate>) {
-        let mut can_push: bool = false;
         match jet_pattern {
             JetPattern::Left => {
                 let leftmost_coordinate = self.coordinates.get(&0).unwrap();
                 let center_coordinate = self.coordinates.get(&1).unwrap();
 
-                can_push = leftmost_coordinate.y != 1
+                let can_push = leftmost_coordinate.y != 1
                     && chamber
                         .get(&Coordinate::new(
                             leftmost_coordinate.x,
@@ -289,7 +338,7 @@ impl Rock for CrossRock {
                         .is_none();
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y - 1;
+                        c.y -= 1;
                     }
                 }
             }
@@ -297,7 +346,7 @@ impl Rock for CrossRock {
                 let rightmost_coordinate = self.coordinates.get(&2).unwrap();
                 let center_coordinate = self.coordinates.get(&1).unwrap();
 
-                can_push = rightmost_coordinate.y != 7
+                let can_push = rightmost_coordinate.y != 7
                     && chamber
                         .get(&Coordinate::new(
                             rightmost_coordinate.x,
@@ -313,7 +362,7 @@ impl Rock for CrossRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y + 1;
+                        c.y += 1;
                     }
                 }
             }
@@ -339,12 +388,12 @@ impl JRock {
 impl Rock for JRock {
     fn descend(&mut self) {
         for (_, c) in self.coordinates.iter_mut() {
-            c.x = c.x - 1;
+            c.x -= 1;
         }
     }
 
     fn has_come_to_rest(&self, chamber: &HashSet<Coordinate>) -> bool {
-        for (_, coordinate) in &self.coordinates {
+        for coordinate in self.coordinates.values() {
             if chamber.contains(&Coordinate::new(coordinate.x - 1, coordinate.y)) {
                 return true;
             }
@@ -354,13 +403,12 @@ impl Rock for JRock {
     }
 
     fn push(&mut self, jet_pattern: &JetPattern, chamber: &HashSet<Coordinate>) {
-        let mut can_push: bool = false;
         let leftmost_coordinate = self.coordinates.get(&0).unwrap();
         let rightmost_coordinate = self.coordinates.get(&2).unwrap();
 
         match jet_pattern {
             JetPattern::Left => {
-                can_push = leftmost_coordinate.y != 1
+                let can_push = leftmost_coordinate.y != 1
                     && chamber
                         .get(&Coordinate::new(
                             leftmost_coordinate.x,
@@ -382,12 +430,12 @@ impl Rock for JRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y - 1;
+                        c.y -= 1;
                     }
                 }
             }
             JetPattern::Right => {
-                can_push = rightmost_coordinate.y != 7
+                let can_push = rightmost_coordinate.y != 7
                     && chamber
                         .get(&Coordinate::new(
                             rightmost_coordinate.x,
@@ -409,7 +457,7 @@ impl Rock for JRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y + 1;
+                        c.y += 1;
                     }
                 }
             }
@@ -448,12 +496,12 @@ impl SkinnyRock {
 impl Rock for SkinnyRock {
     fn descend(&mut self) {
         for (_, c) in self.coordinates.iter_mut() {
-            c.x = c.x - 1;
+            c.x -= 1;
         }
     }
 
     fn has_come_to_rest(&self, chamber: &HashSet<Coordinate>) -> bool {
-        for (_, coordinate) in &self.coordinates {
+        for coordinate in self.coordinates.values() {
             if chamber.contains(&Coordinate::new(coordinate.x - 1, coordinate.y)) {
                 return true;
             }
@@ -463,12 +511,11 @@ impl Rock for SkinnyRock {
     }
 
     fn push(&mut self, jet_pattern: &JetPattern, chamber: &HashSet<Coordinate>) {
-        let mut can_push: bool = false;
         let bottom_coordinate = self.coordinates.get(&0).unwrap();
 
         match jet_pattern {
             JetPattern::Left => {
-                can_push = bottom_coordinate.y != 1
+                let can_push = bottom_coordinate.y != 1
                     && chamber
                         .get(&Coordinate::new(
                             bottom_coordinate.x,
@@ -496,12 +543,12 @@ impl Rock for SkinnyRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y - 1;
+                        c.y -= 1;
                     }
                 }
             }
             JetPattern::Right => {
-                can_push = bottom_coordinate.y != 7
+                let can_push = bottom_coordinate.y != 7
                     && chamber
                         .get(&Coordinate::new(
                             bottom_coordinate.x,
@@ -529,7 +576,7 @@ impl Rock for SkinnyRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y + 1;
+                        c.y += 1;
                     }
                 }
             }
@@ -565,12 +612,12 @@ impl SquareRock {
 impl Rock for SquareRock {
     fn descend(&mut self) {
         for (_, c) in self.coordinates.iter_mut() {
-            c.x = c.x - 1;
+            c.x -= 1;
         }
     }
 
     fn has_come_to_rest(&self, chamber: &HashSet<Coordinate>) -> bool {
-        for (_, coordinate) in &self.coordinates {
+        for coordinate in self.coordinates.values() {
             if chamber.contains(&Coordinate::new(coordinate.x - 1, coordinate.y)) {
                 return true;
             }
@@ -580,11 +627,10 @@ impl Rock for SquareRock {
     }
 
     fn push(&mut self, jet_pattern: &JetPattern, chamber: &HashSet<Coordinate>) {
-        let mut can_push: bool = false;
         match jet_pattern {
             JetPattern::Left => {
                 let leftmost_coordinate = self.coordinates.get(&0).unwrap();
-                can_push = leftmost_coordinate.y != 1
+                let can_push = leftmost_coordinate.y != 1
                     && chamber
                         .get(&Coordinate::new(
                             leftmost_coordinate.x,
@@ -600,13 +646,13 @@ impl Rock for SquareRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y - 1;
+                        c.y -= 1;
                     }
                 }
             }
             JetPattern::Right => {
                 let rightmost_coordinate = self.coordinates.get(&1).unwrap();
-                can_push = rightmost_coordinate.y != 7
+                let can_push = rightmost_coordinate.y != 7
                     && chamber
                         .get(&Coordinate::new(
                             rightmost_coordinate.x,
@@ -622,7 +668,7 @@ impl Rock for SquareRock {
 
                 if can_push {
                     for (_, c) in self.coordinates.iter_mut() {
-                        c.y = c.y + 1;
+                        c.y += 1;
                     }
                 }
             }
@@ -650,3 +696,22 @@ fn next_jet_pattern(patterns: &mut JetPatterns) -> Option<JetPattern> {
 
     None
 }
+
+#[derive(Args)]
+pub struct DaySeventeen;
+
+impl Solution for DaySeventeen {
+    type Input = JetPatterns;
+
+    fn parse(&self, input: &str) -> Result<JetPatterns> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &JetPatterns) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &JetPatterns) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
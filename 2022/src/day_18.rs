@@ -1,46 +1,41 @@
-use advent_of_code::{read_lines, Coordinate, Coordinate3D};
+use advent_of_code::{Answer, Error, Point, Result, Solution};
+use clap::Args;
 use std::collections::HashSet;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    let surface_coordinates: HashSet<Coordinate3D> = input_to_coordinates(input_path);
-    let mut uncovered_sides: u32 = 0;
-
-    for surface_coordinate in &surface_coordinates {
-        let neighbors = surface_coordinate.neighboring_coordinates();
-        for neighbor in &neighbors {
-            if !surface_coordinates.contains(neighbor) {
-                uncovered_sides = uncovered_sides + 1;
-            }
-        }
-    }
 
-    uncovered_sides as i32
+type Cube = Point<3>;
+
+// Every cube face that does not touch another cube
+fn solve_part_one(cubes: &HashSet<Cube>) -> i32 {
+    cubes
+        .iter()
+        .flat_map(Cube::face_neighbors)
+        .filter(|neighbor| !cubes.contains(neighbor))
+        .count() as i32
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    0
+fn input_to_coordinates(input: &str) -> Result<HashSet<Cube>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Cube::parse(index + 1, line))
+        .collect()
 }
 
-fn input_to_coordinates(input_path: &Path) -> HashSet<Coordinate3D> {
-    let mut coordinates: HashSet<Coordinate3D> = HashSet::new();
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let coordinate_value: Vec<i32> = result
-                    .split(",")
-                    .map(|s| s.parse::<i32>().unwrap())
-                    .collect::<Vec<i32>>();
-
-                coordinates.insert(Coordinate3D::new(
-                    coordinate_value[0],
-                    coordinate_value[1],
-                    coordinate_value[2],
-                ));
-            }
-        }
+#[derive(Args)]
+pub struct DayEighteen;
+
+impl Solution for DayEighteen {
+    type Input = HashSet<Cube>;
+
+    fn parse(&self, input: &str) -> Result<HashSet<Cube>> {
+        input_to_coordinates(input)
     }
 
-    coordinates
+    fn part_one(&self, input: &HashSet<Cube>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, _input: &HashSet<Cube>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
@@ -1,27 +1,38 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u64 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use log::debug;
+
+fn solve_part_one(input: &str) -> Result<u64> {
+    for line in input.lines() {
+        debug!("{}", line);
+    }
+
+    Err(Error::Unsolved)
+}
+
+fn solve_part_two(input: &str) -> Result<u64> {
+    for line in input.lines() {
+        debug!("{}", line);
     }
 
-    0
+    Err(Error::Unsolved)
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+#[derive(Args)]
+pub struct DayZero;
+
+impl Solution for DayZero {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.to_string())
     }
 
-    0
+    fn part_one(&self, input: &String) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &String) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
 }
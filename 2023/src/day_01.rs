@@ -1,7 +1,30 @@
-use advent_of_code::read_lines;
-use std::path::Path;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> i32 {
+// One line of lowercase letters and digits per calibration value
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    let mut lines: Vec<String> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if let Some(index) = line.find(|c: char| !c.is_ascii_lowercase() && !c.is_ascii_digit()) {
+            return Err(Error::parse_at(
+                line_number,
+                line[..index].chars().count() + 1,
+                format!("expected only letters and digits in {:?}", line),
+            ));
+        }
+        lines.push(line.to_string());
+    }
+
+    if lines.is_empty() {
+        return Err(Error::parse(1, "expected at least one calibration value"));
+    }
+
+    Ok(lines)
+}
+
+fn solve_part_one(input: &[String]) -> Result<i32> {
     let potential_coordinates: Vec<PotentialCoordinate> = vec![
         PotentialCoordinate {
             potential_ids: vec!["1".to_string()],
@@ -41,10 +64,10 @@ pub fn part_one(input_path: &Path) -> i32 {
         },
     ];
 
-    solve(input_path, potential_coordinates)
+    solve(input, potential_coordinates)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(input: &[String]) -> Result<i32> {
     let potential_coordinates: Vec<PotentialCoordinate> = vec![
         PotentialCoordinate {
             potential_ids: vec!["1".to_string(), "one".to_string()],
@@ -84,7 +107,7 @@ pub fn part_two(input_path: &Path) -> i32 {
         },
     ];
 
-    solve(input_path, potential_coordinates)
+    solve(input, potential_coordinates)
 }
 
 #[derive(Debug)]
@@ -99,57 +122,57 @@ struct LocatedCoordinate {
     value: String,
 }
 
-fn solve(input_path: &Path, potential_coordinates: Vec<PotentialCoordinate>) -> i32 {
+fn solve(input: &[String], potential_coordinates: Vec<PotentialCoordinate>) -> Result<i32> {
     let mut sum: i32 = 0;
 
     let mut located_coordinates: Vec<LocatedCoordinate> = Vec::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                for potential_coordinate in &potential_coordinates {
-                    for potential_id in &potential_coordinate.potential_ids {
-                        for located_coordinate in result
-                            .match_indices(potential_id.as_str())
-                            .collect::<Vec<_>>()
-                        {
-                            located_coordinates.push(LocatedCoordinate {
-                                rank: located_coordinate.0 as i32,
-                                value: potential_coordinate.value.as_str().to_string(),
-                            });
-                        }
-                    }
+    for (index, result) in input.iter().enumerate() {
+        for potential_coordinate in &potential_coordinates {
+            for potential_id in &potential_coordinate.potential_ids {
+                for located_coordinate in result
+                    .match_indices(potential_id.as_str())
+                    .collect::<Vec<_>>()
+                {
+                    located_coordinates.push(LocatedCoordinate {
+                        rank: located_coordinate.0 as i32,
+                        value: potential_coordinate.value.as_str().to_string(),
+                    });
                 }
-
-                located_coordinates.sort_by_key(|lc| lc.rank);
-
-                let mut located_coordinate_value = 0;
-                if located_coordinates.len() > 1 {
-                    located_coordinate_value = format!(
-                        "{}{}",
-                        located_coordinates.get(0).unwrap().value,
-                        located_coordinates
-                            .get(located_coordinates.len() - 1)
-                            .unwrap()
-                            .value
-                    )
-                    .parse::<i32>()
-                    .unwrap();
-                } else if located_coordinates.len() == 1 {
-                    located_coordinate_value = format!(
-                        "{}{}",
-                        located_coordinates.get(0).unwrap().value,
-                        located_coordinates.get(0).unwrap().value
-                    )
-                    .parse::<i32>()
-                    .unwrap();
-                }
-
-                sum += located_coordinate_value;
-                located_coordinates.clear();
             }
         }
+
+        located_coordinates.sort_by_key(|lc| lc.rank);
+
+        let (Some(first), Some(last)) = (located_coordinates.first(), located_coordinates.last())
+        else {
+            return Err(Error::parse(index + 1, "expected at least one digit"));
+        };
+        // Both values are single digits, so this always parses
+        sum += format!("{}{}", first.value, last.value)
+            .parse::<i32>()
+            .unwrap_or_default();
+        located_coordinates.clear();
     }
 
-    sum
+    Ok(sum)
+}
+
+#[derive(Args)]
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
 }
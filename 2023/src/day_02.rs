@@ -1,12 +1,10 @@
-use advent_of_code::read_lines;
-use regex::Regex;
-use std::path::Path;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> i32 {
+fn solve_part_one(games: &[Game]) -> i32 {
     let mut sum: i32 = 0;
 
-    let games: Vec<Game> = load_games(input_path);
-
     let mut game_result: u32 = 0;
     games.iter().for_each(|g| {
         game_result = g.id;
@@ -23,36 +21,26 @@ pub fn part_one(input_path: &Path) -> i32 {
     sum
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(games: &[Game]) -> i32 {
     let mut sum: i32 = 0;
 
-    let mut red_cubes: Vec<u32> = vec![];
-    let mut green_cubes: Vec<u32> = vec![];
-    let mut blue_cubes: Vec<u32> = vec![];
-
-    let games: Vec<Game> = load_games(input_path);
-
     games.iter().for_each(|g| {
-        g.rounds.iter().for_each(|r| {
-            red_cubes.push(r.red_cubes);
-            green_cubes.push(r.green_cubes);
-            blue_cubes.push(r.blue_cubes);
+        // The fewest cubes of each colour that make every round possible
+        let fewest = g.rounds.iter().fold((0, 0, 0), |(red, green, blue), r| {
+            (
+                red.max(r.red_cubes),
+                green.max(r.green_cubes),
+                blue.max(r.blue_cubes),
+            )
         });
 
-        let game_power = red_cubes.iter().max().unwrap()
-            * green_cubes.iter().max().unwrap()
-            * blue_cubes.iter().max().unwrap();
-        red_cubes.clear();
-        green_cubes.clear();
-        blue_cubes.clear();
-
-        sum += game_power as i32;
+        sum += (fewest.0 * fewest.1 * fewest.2) as i32;
     });
 
     sum
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 struct Round {
     red_cubes: u32,
     green_cubes: u32,
@@ -60,80 +48,64 @@ struct Round {
 }
 
 #[derive(Debug, Clone)]
-struct Game {
+pub struct Game {
     id: u32,
     rounds: Vec<Round>,
 }
 
-fn load_games(input_path: &Path) -> Vec<Game> {
+// Each line is a game like "Game 1: 3 blue, 4 red; 1 red, 2 green"
+fn load_games(input: &str) -> Result<Vec<Game>> {
     let mut games: Vec<Game> = vec![];
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(mut result) = line {
-                let mut game = Game {
-                    id: 0,
-                    rounds: vec![],
-                };
-
-                result = result.replace(" ", "");
-                let game_id = result
-                    .split_once(":")
-                    .unwrap()
-                    .0
-                    .to_string()
-                    .replace("Game", "")
-                    .parse::<u32>()
-                    .unwrap();
-                game.id = game_id;
-                let rounds: Vec<_> = result
-                    .split_once(":")
-                    .unwrap()
-                    .1
-                    .split(";")
-                    .collect::<Vec<_>>();
-                for round in &rounds {
-                    let mut new_round = Round {
-                        red_cubes: 0,
-                        green_cubes: 0,
-                        blue_cubes: 0,
-                    };
-
-                    let regex = Regex::new(
-                        r"(?m)((?P<red>\d+)red)?((?P<green>\d+)green)?((?P<blue>\d+)blue)?",
-                    )
-                    .unwrap();
-                    let result = regex.captures_iter(&round);
-                    for mat in result {
-                        match mat.name("red") {
-                            Some(cubes) => {
-                                new_round.red_cubes = cubes.as_str().to_string().parse().unwrap();
-                            }
-                            None => {}
-                        }
-                        match mat.name("green") {
-                            Some(cubes) => {
-                                new_round.green_cubes = cubes.as_str().to_string().parse().unwrap();
-                            }
-                            None => {}
-                        }
-                        match mat.name("blue") {
-                            Some(cubes) => {
-                                new_round.blue_cubes = cubes.as_str().to_string().parse().unwrap();
-                            }
-                            None => {}
-                        }
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        parser.literal("Game")?;
+        let id = parser.number()?;
+        parser.literal(":")?;
+        let rounds = parser.list(";", |parser| {
+            let mut round = Round::default();
+            parser.list(",", |parser| {
+                let cubes = parser.number()?;
+                match parser.word()? {
+                    "red" => round.red_cubes = cubes,
+                    "green" => round.green_cubes = cubes,
+                    "blue" => round.blue_cubes = cubes,
+                    colour => {
+                        return Err(parser
+                            .error(format!("expected red, green or blue, found {:?}", colour)))
                     }
-
-                    game.rounds.push(new_round.clone());
                 }
+                Ok(())
+            })?;
+            Ok(round)
+        })?;
+        parser.end()?;
 
-                if game.id != 0 {
-                    games.push(game.clone());
-                }
-            }
-        }
+        games.push(Game { id, rounds });
+    }
+
+    if games.is_empty() {
+        return Err(Error::parse(1, "expected at least one game"));
+    }
+
+    Ok(games)
+}
+
+#[derive(Args)]
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>> {
+        load_games(input)
     }
 
-    games
+    fn part_one(&self, input: &Vec<Game>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Game>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
@@ -1,12 +1,11 @@
-use advent_of_code::read_lines;
-use advent_of_code::Coordinate;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
 use regex::Regex;
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::HashMap;
 
 const PART_NUMBER_REGEX_STR: &str = r"\d+";
-const SYMBOL_REGEX_STR: &str = r"[^\d.\n]";
-const GEAR_SYMBOL_REGEX_STR: &str = r"\*";
+const GEAR_SYMBOL: char = '*';
 
 #[derive(Debug)]
 struct PossiblePartNumber {
@@ -19,15 +18,24 @@ struct Gear {
     adjacent_part_numbers: Vec<i32>,
 }
 
-fn extract_part_numbers_from_line(line: &str, row_index: i32) -> Vec<PossiblePartNumber> {
+fn extract_part_numbers_from_line(
+    line: &str,
+    line_number: usize,
+    part_number_regex: &Regex,
+) -> Result<Vec<PossiblePartNumber>> {
     let mut possible_part_numbers: Vec<PossiblePartNumber> = vec![];
-
-    let part_number_regex = Regex::new(PART_NUMBER_REGEX_STR).unwrap();
-    let part_number_captures = part_number_regex.captures_iter(&line);
-    for mat in part_number_captures {
-        let mat_value = mat.get(0).unwrap();
+    let row_index = line_number as i32 - 1;
+
+    for mat_value in part_number_regex.find_iter(line) {
+        let value = mat_value.as_str().parse::<i32>().map_err(|err| {
+            Error::parse_at(
+                line_number,
+                mat_value.start() + 1,
+                format!("{:?}: {}", mat_value.as_str(), err),
+            )
+        })?;
         let mut possible_part_number = PossiblePartNumber {
-            value: mat_value.as_str().to_string().parse::<i32>().unwrap(),
+            value,
             neighboring_coordinates: vec![],
         };
 
@@ -69,37 +77,58 @@ fn extract_part_numbers_from_line(line: &str, row_index: i32) -> Vec<PossiblePar
         possible_part_numbers.push(possible_part_number);
     }
 
-    possible_part_numbers
+    Ok(possible_part_numbers)
 }
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut sum: i32 = 0;
-    let symbol_regex = Regex::new(SYMBOL_REGEX_STR).unwrap();
-    let mut possible_part_numbers: Vec<PossiblePartNumber> = vec![];
-    let mut symbol_coordinates: HashSet<Coordinate> = HashSet::new();
-
-    let mut row_index: i32 = 0;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                possible_part_numbers
-                    .extend(extract_part_numbers_from_line(&result, row_index).into_iter());
-                let symbol_captures = symbol_regex.captures_iter(&result);
+// The numbers and symbols of the engine schematic. Periods are blank space and
+// any other character is a symbol.
+pub struct Schematic {
+    possible_part_numbers: Vec<PossiblePartNumber>,
+    symbols: HashMap<Coordinate, char>,
+}
 
-                for mat in symbol_captures {
-                    let mat_value = mat.get(0).unwrap();
-                    symbol_coordinates.insert(Coordinate::new(row_index, mat_value.start() as i32));
-                }
+fn parse_input(input: &str) -> Result<Schematic> {
+    let part_number_regex = Regex::new(PART_NUMBER_REGEX_STR).unwrap();
+    let mut possible_part_numbers: Vec<PossiblePartNumber> = vec![];
+    let mut symbols: HashMap<Coordinate, char> = HashMap::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        possible_part_numbers.extend(extract_part_numbers_from_line(
+            line,
+            line_number,
+            &part_number_regex,
+        )?);
+
+        for (column, c) in line.chars().enumerate() {
+            if !c.is_ascii_graphic() {
+                return Err(Error::parse_at(
+                    line_number,
+                    column + 1,
+                    format!("expected a digit, '.' or a symbol, found {:?}", c),
+                ));
+            }
+            if c != '.' && !c.is_ascii_digit() {
+                symbols.insert(Coordinate::new(line_number as i32 - 1, column as i32), c);
             }
-
-            row_index += 1;
         }
     }
 
-    for possible_part_number in &possible_part_numbers {
+    if possible_part_numbers.is_empty() {
+        return Err(Error::parse(1, "expected at least one number"));
+    }
+
+    Ok(Schematic {
+        possible_part_numbers,
+        symbols,
+    })
+}
+
+fn solve_part_one(schematic: &Schematic) -> i32 {
+    let mut sum: i32 = 0;
+
+    for possible_part_number in &schematic.possible_part_numbers {
         for neighboring_coordinate in &possible_part_number.neighboring_coordinates {
-            if symbol_coordinates.contains(neighboring_coordinate) {
+            if schematic.symbols.contains_key(neighboring_coordinate) {
                 sum += possible_part_number.value;
                 break;
             }
@@ -109,40 +138,26 @@ pub fn part_one(input_path: &Path) -> i32 {
     sum
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(schematic: &Schematic) -> i32 {
     let mut sum: i32 = 0;
-    let symbol_regex = Regex::new(GEAR_SYMBOL_REGEX_STR).unwrap();
-    let mut possible_part_numbers: Vec<PossiblePartNumber> = vec![];
-    let mut gears: HashMap<Coordinate, Gear> = HashMap::new();
-
-    let mut row_index: i32 = 0;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                possible_part_numbers
-                    .extend(extract_part_numbers_from_line(&result, row_index).into_iter());
-
-                let symbol_captures = symbol_regex.captures_iter(&result);
-                for mat in symbol_captures {
-                    let mat_value = mat.get(0).unwrap();
-                    gears.insert(
-                        Coordinate::new(row_index, mat_value.start() as i32),
-                        Gear {
-                            adjacent_part_numbers: vec![],
-                        },
-                    );
-                }
-            }
-
-            row_index += 1;
-        }
-    }
-
-    for possible_part_number in &possible_part_numbers {
+    let mut gears: HashMap<Coordinate, Gear> = schematic
+        .symbols
+        .iter()
+        .filter(|(_, &symbol)| symbol == GEAR_SYMBOL)
+        .map(|(&coordinate, _)| {
+            (
+                coordinate,
+                Gear {
+                    adjacent_part_numbers: vec![],
+                },
+            )
+        })
+        .collect();
+
+    for possible_part_number in &schematic.possible_part_numbers {
         for neighboring_coordinate in &possible_part_number.neighboring_coordinates {
             if gears.contains_key(neighboring_coordinate) {
-                if let Some(c) = gears.get_mut(&neighboring_coordinate) {
+                if let Some(c) = gears.get_mut(neighboring_coordinate) {
                     c.adjacent_part_numbers.push(possible_part_number.value);
                 }
             }
@@ -150,12 +165,29 @@ pub fn part_two(input_path: &Path) -> i32 {
     }
 
     for (_, gear) in gears.iter_mut() {
-        if gear.adjacent_part_numbers.len() == 2 {
-            let gear_ratio: i32 = gear.adjacent_part_numbers.pop().unwrap()
-                * gear.adjacent_part_numbers.pop().unwrap();
-            sum += gear_ratio;
+        if let [first, second] = gear.adjacent_part_numbers[..] {
+            sum += first * second;
         }
     }
 
     sum
 }
+
+#[derive(Args)]
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = Schematic;
+
+    fn parse(&self, input: &str) -> Result<Schematic> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Schematic) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Schematic) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
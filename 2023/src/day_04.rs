@@ -1,118 +1,116 @@
-use advent_of_code::read_lines;
-use regex::Regex;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> i32 {
+pub struct Scratchcard {
+    card_number: i32,
+    winning_numbers: Vec<u32>,
+    my_numbers: Vec<u32>,
+}
+
+impl Scratchcard {
+    fn my_winning_numbers_count(&self) -> i32 {
+        self.my_numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count() as i32
+    }
+}
+
+// Each line is a card like "Card 1: 41 48 83 | 83 86 6 31"
+fn parse_input(input: &str) -> Result<Vec<Scratchcard>> {
+    let mut scratchcards: Vec<Scratchcard> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        parser.literal("Card")?;
+        let card_number = parser.number()?;
+        parser.literal(":")?;
+
+        let mut winning_numbers: Vec<u32> = Vec::new();
+        while !parser.optional("|") {
+            winning_numbers.push(parser.number()?);
+        }
+        let mut my_numbers: Vec<u32> = Vec::new();
+        while !parser.rest().trim().is_empty() {
+            my_numbers.push(parser.number()?);
+        }
+
+        scratchcards.push(Scratchcard {
+            card_number,
+            winning_numbers,
+            my_numbers,
+        });
+    }
+
+    if scratchcards.is_empty() {
+        return Err(Error::parse(1, "expected at least one scratchcard"));
+    }
+
+    Ok(scratchcards)
+}
+
+fn solve_part_one(scratchcards: &[Scratchcard]) -> i32 {
     let mut sum: i32 = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let mut scratchcard_value: i32 = 0;
-
-                let parts = result.split(" | ").collect::<Vec<_>>();
-                let winning_numbers = parts
-                    .get(0)
-                    .unwrap()
-                    .split(":")
-                    .collect::<Vec<_>>()
-                    .get(1)
-                    .unwrap()
-                    .trim()
-                    .split(" ")
-                    .filter(|n| !n.is_empty())
-                    .map(|n| format!(r"\b{}\b", n))
-                    .collect::<Vec<_>>();
-                let winning_numbers_re_string = winning_numbers.join("|");
-                let winning_numbers_re = Regex::new(&winning_numbers_re_string).unwrap();
-                let my_numbers = parts.get(1).unwrap();
-                let my_winning_numbers: Vec<&str> = winning_numbers_re
-                    .find_iter(&my_numbers)
-                    .map(|m| m.as_str())
-                    .collect();
-
-                for _ in my_winning_numbers {
-                    match scratchcard_value {
-                        0 => scratchcard_value = 1,
-                        _ => scratchcard_value *= 2,
-                    }
-                }
-
-                sum += scratchcard_value;
+    for scratchcard in scratchcards {
+        let mut scratchcard_value: i32 = 0;
+
+        for _ in 0..scratchcard.my_winning_numbers_count() {
+            match scratchcard_value {
+                0 => scratchcard_value = 1,
+                _ => scratchcard_value *= 2,
             }
         }
+
+        sum += scratchcard_value;
     }
 
     sum
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(scratchcards: &[Scratchcard]) -> i32 {
     let mut scratchcard_inventory: HashMap<i32, i32> = HashMap::new();
     let mut max_card_number: i32 = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let parts = result.split(" | ").collect::<Vec<_>>();
-                let card_number = parts
-                    .get(0)
-                    .unwrap()
-                    .split(":")
-                    .collect::<Vec<_>>()
-                    .get(0)
-                    .unwrap()
-                    .split("Card")
-                    .collect::<Vec<_>>()
-                    .get(1)
-                    .unwrap()
-                    .trim()
-                    .parse::<i32>()
-                    .unwrap();
-                if card_number > max_card_number {
-                    max_card_number = card_number;
-                }
-                let _ = *scratchcard_inventory.entry(card_number).or_insert(1);
-
-                let winning_numbers = parts
-                    .get(0)
-                    .unwrap()
-                    .split(":")
-                    .collect::<Vec<_>>()
-                    .get(1)
-                    .unwrap()
-                    .trim()
-                    .split(" ")
-                    .filter(|n| !n.is_empty())
-                    .map(|n| format!(r"\b{}\b", n))
-                    .collect::<Vec<_>>();
-                let winning_numbers_re_string = winning_numbers.join("|");
-                let winning_numbers_re = Regex::new(&winning_numbers_re_string).unwrap();
-                let my_numbers = parts.get(1).unwrap();
-                let my_winning_numbers: Vec<&str> = winning_numbers_re
-                    .find_iter(&my_numbers)
-                    .map(|m| m.as_str())
-                    .collect();
-                let my_winning_numbers_count = my_winning_numbers.len() as i32;
-
-                let extra_card_multiplier: i32 = match scratchcard_inventory.get(&card_number) {
-                    Some(n) => *n,
-                    None => 1,
-                };
-
-                for extra_card_number in card_number + 1..=card_number + my_winning_numbers_count {
-                    *scratchcard_inventory.entry(extra_card_number).or_insert(1) +=
-                        1 * extra_card_multiplier;
-                }
-            }
+    for scratchcard in scratchcards {
+        let card_number = scratchcard.card_number;
+        if card_number > max_card_number {
+            max_card_number = card_number;
+        }
+        let extra_card_multiplier: i32 = *scratchcard_inventory.entry(card_number).or_insert(1);
+
+        for extra_card_number in
+            card_number + 1..=card_number + scratchcard.my_winning_numbers_count()
+        {
+            *scratchcard_inventory.entry(extra_card_number).or_insert(1) += extra_card_multiplier;
         }
     }
 
-    // Remove any possible scratch cards from the inventory that have a number greater than the max
-    // card given as input.
-    for x in max_card_number + 1..=*scratchcard_inventory.keys().max().unwrap() as i32 {
-        scratchcard_inventory.remove(&x);
+    // Leave out any copies won of cards past the last one in the input
+    scratchcard_inventory
+        .iter()
+        .filter(|(&card_number, _)| card_number <= max_card_number)
+        .map(|(_, count)| count)
+        .sum::<i32>()
+}
+
+#[derive(Args)]
+pub struct DayFour;
+
+impl Solution for DayFour {
+    type Input = Vec<Scratchcard>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Scratchcard>> {
+        parse_input(input)
     }
 
-    scratchcard_inventory.values().sum::<i32>()
+    fn part_one(&self, input: &Vec<Scratchcard>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Scratchcard>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
@@ -1,138 +1,119 @@
-use advent_of_code::read_lines;
-use log::debug;
-use std::collections::HashMap;
-use std::path::Path;
+use advent_of_code::{Answer, Error, RangeMap, RangeSet, Result, Solution};
+use clap::Args;
 
-#[derive(Debug)]
-struct Range {
-    start: i64,
-    end: i64,
-    range: i64,
-    offset: i64,
+// The seeds and the maps from seed to soil through to humidity to location,
+// in the order they are applied
+pub struct Almanac {
+    seeds: Vec<i64>,
+    // Where the seeds were listed, for errors about them
+    seeds_line: usize,
+    maps: Vec<RangeMap>,
 }
 
-impl Range {
-    pub fn new(start: i64, range: i64, offset: i64) -> Range {
-        Range {
-            start,
-            end: start + range - 1,
-            range,
-            offset,
-        }
-    }
+fn parse_numbers(line_number: usize, text: &str) -> Result<Vec<i64>> {
+    text.split_whitespace()
+        .map(|s| {
+            s.parse::<i64>()
+                .map_err(|err| Error::parse(line_number, format!("{:?}: {}", s, err)))
+        })
+        .collect()
+}
 
-    pub fn in_range(&self, val: i64) -> bool {
-        val >= self.start && val <= self.end
-    }
+fn parse_input(input: &str) -> Result<Almanac> {
+    let mut seeds: Option<(usize, Vec<i64>)> = None;
+    let mut maps: Vec<RangeMap> = vec![];
 
-    pub fn convert_to_destination_value(&self, value: i64) -> i64 {
-        value + self.offset
+    for (index, line) in input.lines().enumerate() {
+        let line_number = index + 1;
+        if line.is_empty() {
+            continue;
+        } else if let Some(numbers) = line.strip_prefix("seeds:") {
+            let numbers = parse_numbers(line_number, numbers)?;
+            if numbers.is_empty() {
+                return Err(Error::parse(line_number, "expected at least one seed"));
+            }
+            seeds = Some((line_number, numbers));
+        } else if line.ends_with("map:") {
+            maps.push(RangeMap::new());
+        } else {
+            let map = maps.last_mut().ok_or_else(|| {
+                Error::parse(line_number, format!("mapping outside a map: {:?}", line))
+            })?;
+            match parse_numbers(line_number, line)?[..] {
+                [destination, source, length] => {
+                    map.insert(source..source + length, destination - source)
+                }
+                _ => {
+                    return Err(Error::parse(
+                        line_number,
+                        format!(
+                            "expected `<destination> <source> <length>`, found {:?}",
+                            line
+                        ),
+                    ))
+                }
+            }
+        }
     }
-}
-
-fn convert_map_item_to_range(item: &str) -> Range {
-    let results: Vec<i64> = item
-        .split_whitespace()
-        .collect::<Vec<&str>>()
-        .into_iter()
-        .map(|s| s.parse::<i64>().unwrap())
-        .collect::<Vec<i64>>();
-    let dest_range_start: i64 = *results.get(0).unwrap();
-    let src_range_start: i64 = *results.get(1).unwrap();
-    let range_length: i64 = *results.get(2).unwrap();
 
-    Range::new(
-        src_range_start,
-        range_length,
-        dest_range_start - src_range_start,
-    )
+    let (seeds_line, seeds) = seeds.ok_or_else(|| Error::parse(1, "missing the seeds line"))?;
+    Ok(Almanac {
+        seeds,
+        seeds_line,
+        maps,
+    })
 }
 
-pub fn part_one(input_path: &Path) -> i64 {
-    let mut minimum_seed_location: i64 = i64::MAX;
+fn solve_part_one(almanac: &Almanac) -> Result<i64> {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| almanac.maps.iter().fold(seed, |value, map| map.get(value)))
+        .min()
+        .ok_or_else(|| Error::parse(almanac.seeds_line, "expected at least one seed"))
+}
 
-    let mapping_order: Vec<String> = vec![
-        "seed-to-soil".to_string(),
-        "soil-to-fertilizer".to_string(),
-        "fertilizer-to-water".to_string(),
-        "water-to-light".to_string(),
-        "light-to-temperature".to_string(),
-        "temperature-to-humidity".to_string(),
-        "humidity-to-location".to_string(),
-    ];
-    let mut mappings: HashMap<String, Vec<Range>> = HashMap::new();
-    for mapping_id in &mapping_order {
-        mappings.insert(mapping_id.to_string(), vec![]);
+// The seeds line lists pairs of a start and a length
+fn solve_part_two(almanac: &Almanac) -> Result<i64> {
+    if !almanac.seeds.len().is_multiple_of(2) {
+        return Err(Error::parse(
+            almanac.seeds_line,
+            format!(
+                "expected pairs of a start and a length, found {} numbers",
+                almanac.seeds.len()
+            ),
+        ));
     }
 
-    let mut seeds: Vec<i64> = vec![];
-    let mut current_input_map: String = "".to_string();
+    let seeds: RangeSet = almanac
+        .seeds
+        .chunks(2)
+        .map(|pair| pair[0]..pair[0] + pair[1])
+        .collect();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if result.is_empty() {
-                    continue;
-                } else if result.contains("seeds:") {
-                    seeds = result
-                        .split(": ")
-                        .collect::<Vec<&str>>()
-                        .get(1)
-                        .unwrap()
-                        .split_whitespace()
-                        .collect::<Vec<&str>>()
-                        .into_iter()
-                        .map(|s| s.parse::<i64>().unwrap())
-                        .collect::<Vec<i64>>();
-                } else if result.contains("map:") {
-                    current_input_map = result
-                        .split(" map:")
-                        .collect::<Vec<&str>>()
-                        .get(0)
-                        .unwrap()
-                        .trim()
-                        .to_string();
-                } else {
-                    if let Some(mapping) = mappings.get_mut(&current_input_map) {
-                        mapping.push(convert_map_item_to_range(&result));
-                    }
-                }
-            }
-        }
-    }
+    almanac
+        .maps
+        .iter()
+        .fold(seeds, |ranges, map| map.apply(&ranges))
+        .min()
+        .ok_or_else(|| Error::parse(almanac.seeds_line, "every seed range is empty"))
+}
 
-    for seed in seeds {
-        let mut current_value: i64 = seed;
-        for mapping in &mapping_order {
-            match mappings.get(mapping) {
-                Some(_mapping) => {
-                    for m in _mapping {
-                        if m.in_range(current_value as i64) {
-                            current_value = m.convert_to_destination_value(current_value);
-                            break;
-                        }
-                    }
-                }
-                None => todo!(),
-            }
-        }
+#[derive(Args)]
+pub struct DayFive;
 
-        if current_value < minimum_seed_location {
-            minimum_seed_location = current_value
-        }
-    }
+impl Solution for DayFive {
+    type Input = Almanac;
 
-    minimum_seed_location
-}
+    fn parse(&self, input: &str) -> Result<Almanac> {
+        parse_input(input)
+    }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+    fn part_one(&self, input: &Almanac) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
     }
 
-    0
+    fn part_two(&self, input: &Almanac) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
 }
@@ -1,46 +1,86 @@
-use advent_of_code::read_lines;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use log::debug;
-use regex::Regex;
 use std::iter::zip;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut solution: u64 = 1;
-    let re = Regex::new(r"\d+").unwrap();
-
-    let mut times: Vec<u64> = vec![];
-    let mut distances: Vec<u64> = vec![];
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let results: Vec<u64> = re
-                    .find_iter(&result)
-                    .map(|m| m.as_str().parse::<u64>().unwrap())
-                    .collect::<Vec<_>>();
-
-                if result.starts_with("Time") {
-                    times = results.clone();
-                } else if result.starts_with("Distance") {
-                    distances = results.clone();
-                }
-            }
-        }
+// The races as separate numbers, and the one long race that the same lines
+// describe once the spaces between the numbers are ignored
+pub struct Races {
+    races: Vec<(u64, u64)>,
+    long_race: (u64, u64),
+}
+
+// A "Time:" line and a "Distance:" line, each with one number per race
+fn parse_input(input: &str) -> Result<Races> {
+    let lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let [times, distances] = lines[..] else {
+        return Err(Error::parse(
+            1,
+            format!(
+                "expected a line of times and a line of distances, found {} lines",
+                lines.len()
+            ),
+        ));
+    };
+
+    let (times, long_time) = parse_line(times, "Time:")?;
+    let (distances, long_distance) = parse_line(distances, "Distance:")?;
+    if times.len() != distances.len() {
+        return Err(Error::parse(
+            lines[1].0,
+            format!(
+                "expected {} distances, found {}",
+                times.len(),
+                distances.len()
+            ),
+        ));
     }
 
-    let races = zip(times, distances);
+    Ok(Races {
+        races: zip(times, distances).collect(),
+        long_race: (long_time, long_distance),
+    })
+}
+
+fn parse_line((line_number, line): (usize, &str), label: &str) -> Result<(Vec<u64>, u64)> {
+    let mut parser = Parser::new(line_number, line);
+    parser.literal(label)?;
 
-    for race in races {
-        let current_record_distance: u64 = race.1;
-        let race_time: u64 = race.0;
-        let mut record_distances: u64 = 0;
+    let digits: String = parser.rest().split_whitespace().collect();
+    let mut numbers: Vec<u64> = Vec::new();
+    while !parser.rest().trim().is_empty() {
+        numbers.push(parser.number()?);
+    }
+    if numbers.is_empty() {
+        return Err(parser.error("expected at least one number"));
+    }
+    let joined = digits
+        .parse::<u64>()
+        .map_err(|err| Error::parse(line_number, format!("{:?}: {}", digits, err)))?;
 
-        for t in 0..=race_time {
-            let possible_distance = t * (race_time - t);
-            if possible_distance > current_record_distance {
-                record_distances += 1;
-            }
+    Ok((numbers, joined))
+}
+
+// The number of ways to hold the button that beat the record distance
+fn ways_to_win(race_time: u64, current_record_distance: u64) -> u64 {
+    let mut record_distances: u64 = 0;
+
+    for t in 0..=race_time {
+        let possible_distance = t * (race_time - t);
+        if possible_distance > current_record_distance {
+            record_distances += 1;
         }
+    }
+
+    record_distances
+}
+
+fn solve_part_one(races: &Races) -> u64 {
+    let mut solution: u64 = 1;
+
+    for &(race_time, current_record_distance) in &races.races {
+        let record_distances = ways_to_win(race_time, current_record_distance);
 
         debug!("Possible record distances: {}", record_distances);
         if record_distances > 0 {
@@ -51,49 +91,28 @@ pub fn part_one(input_path: &Path) -> u64 {
     solution
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut solution: u64 = 1;
-    let re = Regex::new(r"\d+").unwrap();
-
-    let mut times: Vec<u64> = vec![];
-    let mut distances: Vec<u64> = vec![];
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let results: Vec<u64> = re
-                    .find_iter(&result.replace(" ", ""))
-                    .map(|m| m.as_str().parse::<u64>().unwrap())
-                    .collect::<Vec<_>>();
-
-                if result.starts_with("Time") {
-                    times = results.clone();
-                } else if result.starts_with("Distance") {
-                    distances = results.clone();
-                }
-            }
-        }
-    }
+fn solve_part_two(races: &Races) -> u64 {
+    let (race_time, current_record_distance) = races.long_race;
+    debug!("{:#?} {:#?}", race_time, current_record_distance);
 
-    let races = zip(times, distances);
+    ways_to_win(race_time, current_record_distance)
+}
 
-    for race in races {
-        debug!("{:#?} {:#?}", race.0, race.1);
-        let current_record_distance: u64 = race.1;
-        let race_time: u64 = race.0;
-        let mut record_distances: u64 = 0;
+#[derive(Args)]
+pub struct DaySix;
 
-        for t in 0..=race_time {
-            let possible_distance = t * (race_time - t);
-            if possible_distance > current_record_distance {
-                record_distances += 1;
-            }
-        }
+impl Solution for DaySix {
+    type Input = Races;
 
-        if record_distances > 0 {
-            solution *= record_distances;
-        }
+    fn parse(&self, input: &str) -> Result<Races> {
+        parse_input(input)
     }
 
-    solution
+    fn part_one(&self, input: &Races) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Races) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
@@ -1,11 +1,12 @@
-use advent_of_code::read_lines;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> u32 {
-    load_hands(input_path, false, true)
+fn solve_part_one(input: &[Play]) -> u32 {
+    load_hands(input, false, true)
         .iter()
         .enumerate()
         .map(|(idx, hand)| hand.bid * (idx as u32 + 1))
@@ -14,8 +15,8 @@ pub fn part_one(input_path: &Path) -> u32 {
         .sum::<u32>()
 }
 
-pub fn part_two(input_path: &Path) -> u32 {
-    load_hands(input_path, true, true)
+fn solve_part_two(input: &[Play]) -> u32 {
+    load_hands(input, true, true)
         .iter()
         .enumerate()
         .map(|(idx, hand)| hand.bid * (idx as u32 + 1))
@@ -34,7 +35,7 @@ enum HandRank {
     FiveOfAKind = 6,
 }
 
-#[derive(Debug, Ord, Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq)]
 struct Hand {
     cards: Vec<char>,
     bid: u32,
@@ -75,10 +76,8 @@ impl Hand {
         let mut number_of_jokers_in_hand: u32 = 0;
         for card in cards {
             *mapping.entry(card).or_insert(0) += 1;
-            if use_jokers {
-                if card == 'J' {
-                    number_of_jokers_in_hand += 1;
-                }
+            if use_jokers && card == 'J' {
+                number_of_jokers_in_hand += 1;
             }
         }
 
@@ -131,7 +130,6 @@ impl Hand {
                 1 => HandRank::OnePair,
                 _ => HandRank::HighCard,
             },
-            _ => HandRank::HighCard,
         };
 
         hand_rank as u32
@@ -158,8 +156,8 @@ impl Hand {
     }
 }
 
-impl PartialOrd for Hand {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         if self.strength == other.strength {
             for (i, value) in self.card_values.iter().enumerate() {
                 let other_value: u32 = *other.card_values.get(i).unwrap();
@@ -167,26 +165,63 @@ impl PartialOrd for Hand {
                     continue;
                 }
 
-                return Some(value.cmp(&other_value));
+                return value.cmp(&other_value);
             }
         }
 
-        Some(self.strength.cmp(&other.strength))
+        self.strength.cmp(&other.strength)
     }
 }
 
-fn load_hands(input_path: &Path, use_jokers: bool, sort_hands: bool) -> Vec<Hand> {
-    let mut hands: Vec<Hand> = vec![];
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let split_result: Vec<&str> = result.split_whitespace().collect::<Vec<_>>();
-                let cards: Vec<char> = split_result.get(0).unwrap().chars().collect();
-                let bid: u32 = split_result.get(1).unwrap().parse::<u32>().unwrap();
-                hands.push(Hand::new(cards, bid, use_jokers));
-            }
+// A hand of cards and its bid, before deciding what 'J' means
+pub type Play = (Vec<char>, u32);
+
+const CARDS: &str = "AKQJT98765432";
+const HAND_SIZE: usize = 5;
+
+// Each line is a hand of five cards and a bid, "32T3K 765"
+fn parse_input(input: &str) -> Result<Vec<Play>> {
+    let mut plays: Vec<Play> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let cards: Vec<char> = parser.word()?.chars().collect();
+        if cards.len() != HAND_SIZE || cards.iter().any(|card| !CARDS.contains(*card)) {
+            return Err(Error::parse_at(
+                line_number,
+                1,
+                format!(
+                    "expected {} cards from {:?}, found {:?}",
+                    HAND_SIZE,
+                    CARDS,
+                    cards.iter().collect::<String>()
+                ),
+            ));
         }
+        let bid = parser.number()?;
+        parser.end()?;
+
+        plays.push((cards, bid));
+    }
+
+    if plays.is_empty() {
+        return Err(Error::parse(1, "expected at least one hand"));
+    }
+
+    Ok(plays)
+}
+
+fn load_hands(input: &[Play], use_jokers: bool, sort_hands: bool) -> Vec<Hand> {
+    let mut hands: Vec<Hand> = vec![];
+
+    for (cards, bid) in input {
+        hands.push(Hand::new(cards.clone(), *bid, use_jokers));
     }
 
     if sort_hands {
@@ -195,3 +230,22 @@ fn load_hands(input_path: &Path, use_jokers: bool, sort_hands: bool) -> Vec<Hand
 
     hands
 }
+
+#[derive(Args)]
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    type Input = Vec<Play>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Play>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Play>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Play>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
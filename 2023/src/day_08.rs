@@ -1,121 +1,154 @@
-use advent_of_code::read_lines;
-use num::integer::lcm;
-use regex::Regex;
+use advent_of_code::math::lcm_all;
+use advent_of_code::parse::{blocks, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i64 {
-    let node_regex = Regex::new(r"[A-Z]{3}").unwrap();
-    let mut instructions: Vec<char> = vec![];
-    let mut nodes: HashMap<String, (String, String)> = HashMap::new();
-    let mut current_node_id: String = String::from("AAA");
-
-    if let Ok(lines) = read_lines(input_path) {
-        for (idx, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                match idx {
-                    0 => instructions = result.chars().collect::<Vec<char>>(),
-                    _ => {
-                        if result.is_empty() {
-                            continue;
-                        }
-
-                        let mut node_captures = node_regex.captures_iter(&result);
-                        let (id, []) = node_captures.nth(0).unwrap().extract();
-                        let (left, []) = node_captures.nth(0).unwrap().extract();
-                        let (right, []) = node_captures.nth(0).unwrap().extract();
-
-                        nodes.insert(id.to_string(), (left.to_string(), right.to_string()));
-                    }
+
+type Nodes = HashMap<String, (String, String)>;
+
+#[derive(Clone, Copy)]
+enum Instruction {
+    Left,
+    Right,
+}
+
+pub struct Network {
+    instructions: Vec<Instruction>,
+    nodes: Nodes,
+    // The line of the first node, for errors about the network as a whole
+    line: usize,
+}
+
+impl Network {
+    fn next(&self, node_id: &str, instruction: Instruction) -> &String {
+        let (left, right) = &self.nodes[node_id];
+        match instruction {
+            Instruction::Left => left,
+            Instruction::Right => right,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Network> {
+    let blocks = blocks(input);
+    let [instructions, network] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            "expected the instructions and the network separated by a blank line",
+        ));
+    };
+
+    let mut parsed_instructions: Vec<Instruction> = Vec::new();
+    for (line_number, line) in instructions.numbered_lines() {
+        for (index, c) in line.chars().enumerate() {
+            parsed_instructions.push(match c {
+                'L' => Instruction::Left,
+                'R' => Instruction::Right,
+                _ => {
+                    return Err(Error::parse_at(
+                        line_number,
+                        index + 1,
+                        format!("expected L or R, found {:?}", c),
+                    ))
                 }
-            }
+            });
         }
     }
 
-    let instructions_count: usize = instructions.len();
-    let mut current_index: usize = 0;
-    let mut left_or_right: char = 'z';
-    let mut current_node: (String, String) = nodes.get(&current_node_id).unwrap().clone();
-    let mut steps: i64 = 0;
+    let mut nodes: Nodes = Nodes::new();
+    let mut references: Vec<(usize, String)> = Vec::new();
+    for (line_number, line) in network.numbered_lines() {
+        // AAA = (BBB, CCC)
+        let mut parser = Parser::new(line_number, line);
+        let id = parser.word()?;
+        parser.literal("= (")?;
+        let left = parser.word()?;
+        parser.literal(",")?;
+        let right = parser.word()?;
+        parser.literal(")")?;
+        parser.end()?;
+
+        references.push((line_number, left.to_string()));
+        references.push((line_number, right.to_string()));
+        nodes.insert(id.to_string(), (left.to_string(), right.to_string()));
+    }
 
-    while current_node_id != "ZZZ" {
-        left_or_right = instructions[current_index];
-        current_node_id = match left_or_right {
-            'L' => current_node.0.clone(),
-            'R' => current_node.1.clone(),
-            _ => todo!(),
-        };
-        current_index = (current_index + 1) % instructions_count;
-        current_node = nodes.get(&current_node_id).unwrap().clone();
-        steps += 1;
+    // Every step must land on a node, so check the references up front
+    for (line_number, id) in references {
+        if !nodes.contains_key(&id) {
+            return Err(Error::parse(
+                line_number,
+                format!("{:?} is not a node in the network", id),
+            ));
+        }
     }
 
-    steps
+    Ok(Network {
+        instructions: parsed_instructions,
+        nodes,
+        line: network.line,
+    })
 }
 
-pub fn part_two(input_path: &Path) -> i64 {
-    let node_regex = Regex::new(r"[A-Z\d]{3}").unwrap();
-    let mut instructions: Vec<char> = vec![];
-    let mut nodes: HashMap<String, (String, String)> = HashMap::new();
-    let _current_node_id: String = String::from("AAA");
-    let mut starting_nodes: Vec<String> = vec![];
-
-    if let Ok(lines) = read_lines(input_path) {
-        for (idx, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                match idx {
-                    0 => instructions = result.chars().collect::<Vec<char>>(),
-                    _ => {
-                        if result.is_empty() {
-                            continue;
-                        }
-
-                        let mut node_captures = node_regex.captures_iter(&result);
-                        let (id, []) = node_captures.nth(0).unwrap().extract();
-                        let (left, []) = node_captures.nth(0).unwrap().extract();
-                        let (right, []) = node_captures.nth(0).unwrap().extract();
-
-                        if id.ends_with('A') {
-                            starting_nodes.push(String::from(id));
-                        }
-
-                        nodes.insert(id.to_string(), (left.to_string(), right.to_string()));
-                    }
-                }
-            }
-        }
+fn solve_part_one(network: &Network) -> Result<i64> {
+    if !network.nodes.contains_key("AAA") {
+        return Err(Error::parse(
+            network.line,
+            "the network has no starting node \"AAA\"",
+        ));
     }
 
-    let instructions_count: usize = instructions.len();
-    let mut current_index: usize = 0;
-    let mut left_or_right: char = 'z';
-    let mut current_node: (String, String) = (String::from(""), String::from(""));
+    let mut current_node_id: &String = &String::from("AAA");
     let mut steps: i64 = 0;
-    let mut next_node_id: String = String::from("");
 
+    for &instruction in network.instructions.iter().cycle() {
+        if current_node_id == "ZZZ" {
+            break;
+        }
+        current_node_id = network.next(current_node_id, instruction);
+        steps += 1;
+    }
+
+    Ok(steps)
+}
+
+fn solve_part_two(network: &Network) -> i64 {
+    let starting_nodes = network.nodes.keys().filter(|id| id.ends_with('A'));
     let mut distances: Vec<i64> = vec![];
 
     for current_node_id in starting_nodes {
-        next_node_id = current_node_id.to_string();
-        current_index = 0;
-        steps = 0;
-        while !next_node_id.ends_with('Z') {
-            left_or_right = instructions[current_index];
+        let mut next_node_id: &String = current_node_id;
+        let mut steps: i64 = 0;
 
+        for &instruction in network.instructions.iter().cycle() {
+            if next_node_id.ends_with('Z') {
+                break;
+            }
+            next_node_id = network.next(next_node_id, instruction);
             steps += 1;
-            current_node = nodes.get(&next_node_id).unwrap().clone();
-
-            next_node_id = match left_or_right {
-                'L' => current_node.0.clone(),
-                'R' => current_node.1.clone(),
-                _ => todo!(),
-            };
-
-            current_index = (current_index + 1) % instructions_count;
         }
 
         distances.push(steps);
     }
 
-    distances.iter().copied().reduce(|a, b| lcm(a, b)).unwrap()
+    lcm_all(distances)
+}
+
+#[derive(Args)]
+pub struct DayEight;
+
+impl Solution for DayEight {
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Network> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Network) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Network) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
@@ -1,44 +1,73 @@
-use advent_of_code::read_lines;
-use log::debug;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i64 {
-    read_lines(input_path)
-        .unwrap()
-        .map(|l| {
-            determine_next_number_in_sequence(
-                l.unwrap()
-                    .split_whitespace()
-                    .flat_map(|x| x.parse::<i64>())
-                    .collect::<Vec<i64>>(),
-            )
-        })
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// One sequence of numbers per line
+fn parse_input(input: &str) -> Result<Vec<Vec<i64>>> {
+    let mut sequences: Vec<Vec<i64>> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        let mut sequence: Vec<i64> = vec![parser.number()?];
+        while !parser.rest().trim().is_empty() {
+            sequence.push(parser.number()?);
+        }
+        sequences.push(sequence);
+    }
+
+    if sequences.is_empty() {
+        return Err(Error::parse(1, "expected at least one sequence"));
+    }
+
+    Ok(sequences)
+}
+
+fn solve_part_one(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| determine_next_number_in_sequence(sequence))
         .sum::<i64>()
 }
 
-pub fn part_two(input_path: &Path) -> i64 {
-    read_lines(input_path)
-        .unwrap()
-        .map(|l| {
-            determine_next_number_in_sequence(
-                l.unwrap()
-                    .split_whitespace()
-                    .rev()
-                    .flat_map(|x| x.parse::<i64>())
-                    .collect::<Vec<i64>>(),
-            )
+fn solve_part_two(sequences: &[Vec<i64>]) -> i64 {
+    sequences
+        .iter()
+        .map(|sequence| {
+            determine_next_number_in_sequence(&sequence.iter().rev().copied().collect::<Vec<i64>>())
         })
         .sum::<i64>()
 }
 
-fn determine_next_number_in_sequence(sequence: Vec<i64>) -> i64 {
+fn determine_next_number_in_sequence(sequence: &[i64]) -> i64 {
     let next: Vec<i64> = sequence
         .windows(2)
         .map(|w| w[1] - w[0])
         .collect::<Vec<i64>>();
+    // Parsing keeps every sequence non-empty, and the differences stop being
+    // taken once they are all zero
+    let last = sequence.last().copied().unwrap_or_default();
+
+    match next.iter().all(|&difference| difference == 0) {
+        true => last,
+        false => last + determine_next_number_in_sequence(&next),
+    }
+}
+
+#[derive(Args)]
+pub struct DayNine;
+
+impl Solution for DayNine {
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i64>>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Vec<i64>>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
 
-    match next.iter().sum::<i64>() {
-        0 => sequence.last().copied().unwrap(),
-        _ => sequence.last().copied().unwrap() + determine_next_number_in_sequence(next),
+    fn part_two(&self, input: &Vec<Vec<i64>>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
     }
 }
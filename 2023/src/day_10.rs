@@ -1,12 +1,25 @@
-use advent_of_code::read_lines;
-use advent_of_code::Coordinate;
-use log::{debug, log_enabled, Level};
-use std::borrow::Borrow;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
+use log::debug;
 use std::collections::HashMap;
-use std::path::Path;
 
 type Board = HashMap<Coordinate, Tile>;
 
+pub struct Maze {
+    board: Board,
+    starting_coordinate: Coordinate,
+}
+
+// Errors about a tile point at its line and column in the input
+fn tile_error<M: Into<String>>(coordinate: Coordinate, message: M) -> Error {
+    Error::parse_at(
+        coordinate.x as usize + 1,
+        coordinate.y as usize + 1,
+        message,
+    )
+}
+
 #[derive(Debug, Clone)]
 struct Cursor {
     current_tile: Tile,
@@ -29,95 +42,104 @@ impl Cursor {
         self.steps += 1;
     }
 
-    pub fn next(&mut self, board: Board) {
+    // Follows the pipe out of the current tile. The tile must connect back
+    // to the one we came from, or the loop is broken.
+    pub fn next(&mut self, board: &Board) -> Result<()> {
         let previous_coordinate: Coordinate = self.previous_tile.coordinate;
-        let next_coordinate: Coordinate = *self
+        let current_coordinate: Coordinate = self.current_tile.coordinate;
+        if !self
+            .current_tile
+            .neighboring_coordinates
+            .contains(&previous_coordinate)
+        {
+            return Err(tile_error(
+                current_coordinate,
+                "the pipe does not connect to the previous tile",
+            ));
+        }
+
+        let next_coordinate: Coordinate = self
             .current_tile
             .neighboring_coordinates
             .iter()
             .copied()
-            .filter(|c| *c != previous_coordinate)
-            .collect::<Vec<Coordinate>>()
-            .get(0)
-            .unwrap();
+            .find(|c| *c != previous_coordinate)
+            .ok_or_else(|| tile_error(current_coordinate, "the pipe leads nowhere"))?;
         self.previous_tile = self.current_tile.clone();
-        self.current_tile = board.get(&next_coordinate).unwrap().clone();
+        self.current_tile = board
+            .get(&next_coordinate)
+            .ok_or_else(|| tile_error(current_coordinate, "the pipe leads off the board"))?
+            .clone();
         self.increment_step();
 
         debug!("Next coordinate: {:#?}", next_coordinate);
+
+        Ok(())
     }
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut starting_coordinate: Coordinate = Coordinate::new(0, 0);
+fn parse_input(input: &str) -> Result<Maze> {
+    let mut starting_coordinate: Option<Coordinate> = None;
     let mut board: Board = Board::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for (x, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                for (y, result_char) in result.chars().enumerate() {
-                    let tile_coordinate: Coordinate = Coordinate::new(x as i32, y as i32);
-                    let tile: Tile = Tile::new(result_char, tile_coordinate.clone());
-                    if result_char == 'S' {
-                        starting_coordinate = tile_coordinate.clone();
-                    }
-
-                    board.insert(tile_coordinate.clone(), tile.clone());
+    for (line_number, result) in numbered_lines(input) {
+        for (y, result_char) in result.chars().enumerate() {
+            let tile_coordinate: Coordinate = Coordinate::new(line_number as i32 - 1, y as i32);
+            let kind: TileKind = Tile::determine_tile_kind(result_char).ok_or_else(|| {
+                tile_error(
+                    tile_coordinate,
+                    format!("expected a pipe, ground or 'S', found {:?}", result_char),
+                )
+            })?;
+            if let TileKind::Start = kind {
+                if starting_coordinate.is_some() {
+                    return Err(tile_error(tile_coordinate, "found a second starting tile"));
                 }
+                starting_coordinate = Some(tile_coordinate);
             }
+
+            board.insert(tile_coordinate, Tile::new(kind, tile_coordinate));
         }
     }
 
-    let starting_tile_actual_symbol: char =
-        determine_starting_tile_symbol(board.clone(), starting_coordinate);
-    debug!("Starting tile symbol: {}", starting_tile_actual_symbol);
-    board
-        .entry(starting_coordinate)
-        .and_modify(|t| *t = Tile::new(starting_tile_actual_symbol, t.coordinate));
-    let starting_tile: Tile = board.get(&starting_coordinate).unwrap().clone();
+    let starting_coordinate =
+        starting_coordinate.ok_or_else(|| Error::parse(1, "expected a starting tile 'S'"))?;
+
+    Ok(Maze {
+        board,
+        starting_coordinate,
+    })
+}
 
+fn solve_part_one(maze: &Maze) -> Result<u64> {
+    let mut board: Board = maze.board.clone();
+    let starting_coordinate: Coordinate = maze.starting_coordinate;
+
+    let starting_tile_actual_kind: TileKind =
+        determine_starting_tile_kind(&board, starting_coordinate)?;
+    debug!("Starting tile kind: {:?}", starting_tile_actual_kind);
+    let starting_tile: Tile = Tile::new(starting_tile_actual_kind, starting_coordinate);
+    board.insert(starting_coordinate, starting_tile.clone());
+
+    // Both neighbors are on the board, or the start would not connect to them
     let mut cursor_one: Cursor = Cursor::new(
-        board
-            .get(&starting_tile.neighboring_coordinates[0])
-            .unwrap()
-            .clone(),
+        board[&starting_tile.neighboring_coordinates[0]].clone(),
         starting_tile.clone(),
     );
     debug!("Cursor one: {:#?}", cursor_one);
-    let mut cursor_two: Cursor = Cursor::new(
-        board
-            .get(&starting_tile.neighboring_coordinates[1])
-            .unwrap()
-            .clone(),
+    let cursor_two: Cursor = Cursor::new(
+        board[&starting_tile.neighboring_coordinates[1]].clone(),
         starting_tile.clone(),
     );
     debug!("Cursor two: {:#?}", cursor_two);
 
-    let mut current_distance: i32 = cursor_one
-        .current_tile
-        .coordinate
-        .distance(starting_coordinate);
-    let mut previous_distance: i32 = current_distance;
-
     while cursor_one.current_tile.coordinate != starting_coordinate {
-        cursor_one.next(board.clone());
+        cursor_one.next(&board)?;
     }
 
     debug!("Cursor one: {:#?}", cursor_one);
 
-    cursor_one.steps / 2
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
-
-    0
+    Ok(cursor_one.steps / 2)
 }
 
 #[derive(Debug, Clone)]
@@ -130,26 +152,22 @@ enum TileKind {
     NinetyDegreeSE,
     Start,
     Ground,
-    Unknown,
 }
 
 #[derive(Debug, Clone)]
 struct Tile {
     coordinate: Coordinate,
-    symbol: char,
     kind: TileKind,
     neighboring_coordinates: Vec<Coordinate>,
 }
 
 impl Tile {
-    pub fn new(symbol: char, coordinate: Coordinate) -> Tile {
-        let kind: TileKind = Tile::determine_tile_kind(symbol);
+    pub fn new(kind: TileKind, coordinate: Coordinate) -> Tile {
         let neighboring_coordinates: Vec<Coordinate> =
             Tile::determine_neighboring_coordinates(kind.clone(), coordinate);
 
         Tile {
             coordinate,
-            symbol,
             kind,
             neighboring_coordinates,
         }
@@ -182,22 +200,25 @@ impl Tile {
         }
     }
 
-    fn determine_tile_kind(symbol: char) -> TileKind {
+    fn determine_tile_kind(symbol: char) -> Option<TileKind> {
         match symbol {
-            '|' => TileKind::Vertical,
-            '-' => TileKind::Horizontal,
-            'L' => TileKind::NinetyDegreeNE,
-            'J' => TileKind::NinetyDegreeNW,
-            '7' => TileKind::NinetyDegreeSW,
-            'F' => TileKind::NinetyDegreeSE,
-            '.' => TileKind::Ground,
-            'S' => TileKind::Start,
-            _ => TileKind::Unknown,
+            '|' => Some(TileKind::Vertical),
+            '-' => Some(TileKind::Horizontal),
+            'L' => Some(TileKind::NinetyDegreeNE),
+            'J' => Some(TileKind::NinetyDegreeNW),
+            '7' => Some(TileKind::NinetyDegreeSW),
+            'F' => Some(TileKind::NinetyDegreeSE),
+            '.' => Some(TileKind::Ground),
+            'S' => Some(TileKind::Start),
+            _ => None,
         }
     }
 }
 
-fn determine_starting_tile_symbol(board: Board, starting_tile_coordinate: Coordinate) -> char {
+fn determine_starting_tile_kind(
+    board: &Board,
+    starting_tile_coordinate: Coordinate,
+) -> Result<TileKind> {
     // To determine type of starting tile
     // North MUST BE Vertical or South East or West
     // South MUST BE Vertical or North East or West
@@ -257,18 +278,34 @@ fn determine_starting_tile_symbol(board: Board, starting_tile_coordinate: Coordi
         .collect::<String>()
         .as_str()
     {
-        // "NS" => TileKind::Vertical,
-        "NS" => '|',
-        // "EW" => TileKind::Horizontal,
-        "EW" => '-',
-        // "NE" => TileKind::NinetyDegreeNE,
-        "NE" => 'L',
-        // "NW" => TileKind::NinetyDegreeNW,
-        "NW" => 'J',
-        // "SW" => TileKind::NinetyDegreeSW,
-        "SW" => '7',
-        // "SE" => TileKind::NinetyDegreeSE,
-        "SE" => 'F',
-        _ => todo!(),
+        "NS" => Ok(TileKind::Vertical),
+        "EW" => Ok(TileKind::Horizontal),
+        "NE" => Ok(TileKind::NinetyDegreeNE),
+        "NW" => Ok(TileKind::NinetyDegreeNW),
+        "SW" => Ok(TileKind::NinetyDegreeSW),
+        "SE" => Ok(TileKind::NinetyDegreeSE),
+        _ => Err(tile_error(
+            starting_tile_coordinate,
+            "the starting tile does not connect to exactly two pipes",
+        )),
+    }
+}
+
+#[derive(Args)]
+pub struct DayTen;
+
+impl Solution for DayTen {
+    type Input = Maze;
+
+    fn parse(&self, input: &str) -> Result<Maze> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Maze) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, _input: &Maze) -> Result<Answer> {
+        Err(Error::Unsolved)
     }
 }
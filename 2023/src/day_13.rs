@@ -1,92 +1,131 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use std::path::Path;
+use advent_of_code::parse::blocks;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use log::debug;
 use std::cmp::min;
-use std::iter::zip;
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut rows: Vec<String> = vec![];
-    let mut columns: Vec<String> = vec![];
-    let mut sum: u64 = 0;
+// A pattern of ash and rocks, as rows and as columns of the same characters
+pub struct Pattern {
+    // The first line of the pattern, for errors about it
+    line: usize,
+    rows: Vec<String>,
+    columns: Vec<String>,
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-                if result.is_empty() {
-                    debug!("Split between entry points");
-                    sum += determine_reflection_result(&rows, &columns);
-
-                    rows.clear();
-                    columns.clear();
-
-                } else {
-                    rows.push(String::from(&result));
-                    for (idx, c) in result.chars().enumerate() {
-                        if let Some(elem) = columns.get_mut(idx) {
-                            *elem = elem.clone() + String::from(c).as_str();
-                        } else {
-                            columns.push(String::from(c));
-                        }
-                    }
+// Patterns of '.' and '#' with blank lines between them
+fn parse_input(input: &str) -> Result<Vec<Pattern>> {
+    let mut patterns: Vec<Pattern> = Vec::new();
+
+    for block in blocks(input) {
+        let width = block.lines[0].chars().count();
+        let mut columns: Vec<String> = vec![String::new(); width];
+
+        for (line_number, line) in block.numbered_lines() {
+            debug!("{}", line);
+            if line.chars().count() != width {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected {} columns, found {}", width, line.chars().count()),
+                ));
+            }
+            for (idx, c) in line.chars().enumerate() {
+                if c != '.' && c != '#' {
+                    return Err(Error::parse_at(
+                        line_number,
+                        idx + 1,
+                        format!("expected '.' or '#', found {:?}", c),
+                    ));
                 }
+                columns[idx].push(c);
             }
         }
+
+        patterns.push(Pattern {
+            line: block.line,
+            rows: block.lines.iter().map(|line| line.to_string()).collect(),
+            columns,
+        });
     }
 
-    sum += determine_reflection_result(&rows, &columns);
+    if patterns.is_empty() {
+        return Err(Error::parse(1, "expected at least one pattern"));
+    }
 
-    sum
+    Ok(patterns)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
+fn solve_part_one(patterns: &[Pattern]) -> Result<u64> {
+    let mut sum: u64 = 0;
+
+    for pattern in patterns {
+        debug!("Split between entry points");
+        let result = determine_reflection_result(&pattern.rows, &pattern.columns);
+        if result == 0 {
+            return Err(Error::parse(
+                pattern.line,
+                "the pattern has no line of reflection",
+            ));
         }
+        sum += result;
     }
 
-    0
+    Ok(sum)
 }
 
-fn determine_reflection_result_inner(line: &Vec<String>, summary_fn: fn(u64) -> u64) -> u64 {
+fn determine_reflection_result_inner(line: &[String], summary_fn: fn(u64) -> u64) -> u64 {
     let mut sum: u64 = 0;
 
     for (idx, item) in line.iter().enumerate() {
-        match line.get(idx + 1) {
-            Some(next_item) => {
-                if item == next_item {
-                    let items_to_check_left_side = idx;
-                    let items_to_check_right_side = line.len() - idx - 2;
-                    let items_to_check: usize = min(items_to_check_left_side, items_to_check_right_side);
-
-                    let mut found_reflection: bool = true;
-
-                    for check_idx in 1..=items_to_check {
-                        let left_item_to_check = line.get(idx - check_idx);
-                        let right_item_to_check = line.get(idx + 1 + check_idx);
-
-                        if left_item_to_check != right_item_to_check {
-                            found_reflection = false;
-                            break;
-                        }
-                    }
+        if let Some(next_item) = line.get(idx + 1) {
+            if item == next_item {
+                let items_to_check_left_side = idx;
+                let items_to_check_right_side = line.len() - idx - 2;
+                let items_to_check: usize =
+                    min(items_to_check_left_side, items_to_check_right_side);
+
+                let mut found_reflection: bool = true;
 
-                    if found_reflection {
-                        sum += summary_fn((idx + 1) as u64);
+                for check_idx in 1..=items_to_check {
+                    let left_item_to_check = line.get(idx - check_idx);
+                    let right_item_to_check = line.get(idx + 1 + check_idx);
+
+                    if left_item_to_check != right_item_to_check {
+                        found_reflection = false;
                         break;
                     }
                 }
+
+                if found_reflection {
+                    sum += summary_fn((idx + 1) as u64);
+                    break;
+                }
             }
-            _ => {},
         }
     }
 
     sum
 }
-fn determine_reflection_result(rows: &Vec<String>, columns: &Vec<String>) -> u64 {
-    determine_reflection_result_inner(columns, |x| { x })
-        + determine_reflection_result_inner(rows, |x| { x * 100 })
-}
\ No newline at end of file
+
+fn determine_reflection_result(rows: &[String], columns: &[String]) -> u64 {
+    determine_reflection_result_inner(columns, |x| x)
+        + determine_reflection_result_inner(rows, |x| x * 100)
+}
+
+#[derive(Args)]
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    type Input = Vec<Pattern>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Pattern>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Pattern>) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, _input: &Vec<Pattern>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
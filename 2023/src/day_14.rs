@@ -1,78 +1,82 @@
-use advent_of_code::{read_lines, read_file_to_string};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
+use advent_of_code::cycle::find_cycle;
+use advent_of_code::{Answer, Coordinate, Grid, Result, Solution};
+use clap::Args;
+use log::debug;
 
-fn tilt_rock_column (rocks: Vec<char>) -> Vec<char> {
-    let mut tilted: Vec<char> = rocks.clone();
-    let mut spaces_to_shift: usize = 0;
+type Platform = Grid<char>;
 
-    for (idx, rock) in rocks.iter().copied().enumerate() {
-        match rock {
-            '.' => {
-                spaces_to_shift += 1;
-            },
-            'O' => {
-                if spaces_to_shift != 0 {
-                    tilted.remove(idx);
-                    tilted.insert(idx - spaces_to_shift, 'O');
+// Round rocks 'O', cube rocks '#' and empty space '.'
+fn parse_input(input: &str) -> Result<Platform> {
+    Grid::parse_with(input, |c| match c {
+        'O' | '#' | '.' => Some(c),
+        _ => None,
+    })
+}
+
+fn solve_part_one(platform: &Platform) -> u64 {
+    let mut platform = platform.clone();
+    tilt_north(&mut platform);
+    debug!("\n{}", platform);
+
+    north_load(&platform)
+}
+
+// Rolls every round rock as far north as it will go
+fn tilt_north(platform: &mut Platform) {
+    for column in 0..platform.width() as i32 {
+        let mut free_row: i32 = 0;
+        for row in 0..platform.height() as i32 {
+            match platform[Coordinate::new(row, column)] {
+                '#' => free_row = row + 1,
+                'O' => {
+                    platform[Coordinate::new(row, column)] = '.';
+                    platform[Coordinate::new(free_row, column)] = 'O';
+                    free_row += 1;
                 }
+                _ => {}
             }
-            '#' => {
-                spaces_to_shift = 0;
-            }
-            _ => {},
         }
     }
+}
 
-    tilted
+// Tilts north, west, south then east. Rotating clockwise after each tilt
+// brings the next side round to the north.
+fn spin_cycle(platform: &mut Platform) {
+    for _ in 0..4 {
+        tilt_north(platform);
+        *platform = platform.rotate_clockwise();
+    }
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut rock_columns: Vec<Vec<char>> = vec![];
-    let mut tilted_columns: Vec<Vec<char>> = vec![];
+fn north_load(platform: &Platform) -> u64 {
+    platform
+        .find_all(&'O')
+        .map(|rock| (platform.height() as i32 - rock.x) as u64)
+        .sum()
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                for (idx, rock) in result.chars().enumerate() {
-                    match rock_columns.get_mut(idx) {
-                        Some(column) => {
-                            column.push(rock);
-                        }
-                        None => {
-                            rock_columns.push(vec![rock]);
-                        }
-                    }
-                }
+fn solve_part_two(initial: &Platform) -> u64 {
+    let mut platform = initial.clone();
+    let cycle = find_cycle(&mut platform, spin_cycle, |platform| platform.clone());
 
-                debug!("{}", result);
-            }
-        }
-    }
+    north_load(&cycle.state_at(initial.clone(), spin_cycle, 1_000_000_000))
+}
 
-    let mut sum: u64 = 0;
+#[derive(Args)]
+pub struct DayFourteen;
 
-    for column in rock_columns {
-        let column_length = column.len();
-        for (idx, rock) in tilt_rock_column(column).iter().copied().enumerate() {
-            if rock == 'O' {
-                sum += (column_length - idx) as u64;
-            }
-        }
+impl Solution for DayFourteen {
+    type Input = Platform;
 
+    fn parse(&self, input: &str) -> Result<Platform> {
+        parse_input(input)
     }
 
-    sum
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+    fn part_one(&self, input: &Platform) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
     }
 
-    0
+    fn part_two(&self, input: &Platform) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
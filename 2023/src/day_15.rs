@@ -1,8 +1,6 @@
-use std::io::BufRead;
-use advent_of_code::{read_lines, read_file_to_string};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
-use regex::Regex;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
 
 fn holiday_ascii_string_helper_algorithm(step: &str) -> u64 {
@@ -14,79 +12,128 @@ fn holiday_ascii_string_helper_algorithm(step: &str) -> u64 {
     current_value
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut input: String = String::from("");
+pub enum Operation {
+    Remove,
+    Insert(u64),
+}
+
+pub struct Step {
+    text: String,
+    label: String,
+    operation: Operation,
+}
+
+// Comma-separated steps like "rn=1" or "cm-". Newlines are ignored, as the
+// puzzle says, so a long sequence may be wrapped.
+fn parse_input(input: &str) -> Result<Vec<Step>> {
+    let mut steps: Vec<Step> = Vec::new();
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
 
-    if let Ok(_input) = read_file_to_string(input_path) {
-        input = _input;
+        let mut column = 1;
+        for text in line.split(',') {
+            steps.push(parse_step(line_number, column, text)?);
+            column += text.chars().count() + 1;
+        }
+    }
+
+    if steps.is_empty() {
+        return Err(Error::parse(1, "expected at least one step"));
     }
 
-    input
-        .split(",")
-        .map(|m| holiday_ascii_string_helper_algorithm(m)).sum::<u64>()
+    Ok(steps)
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut input: String = String::from("");
-    let re = Regex::new(r"(?P<label>[a-z]+)[=-](?P<focal_length>\d+)*").unwrap();
-    let mut boxes: HashMap<u64, HashMap<&str, u64>> = HashMap::new();
-    let mut boxes_new: HashMap<u64, Vec<(&str, u64)>> = HashMap::new();
+fn parse_step(line_number: usize, column: usize, text: &str) -> Result<Step> {
+    let label_length = text
+        .find(|c: char| !c.is_ascii_lowercase())
+        .unwrap_or(text.len());
+    let (label, rest) = text.split_at(label_length);
+    let error = |message: String| Error::parse_at(line_number, column + label_length, message);
 
-    if let Ok(_input) = read_file_to_string(input_path) {
-        input = _input;
+    if label.is_empty() {
+        return Err(error(format!("expected a label, found {:?}", text)));
     }
+    let operation = match rest.strip_prefix('=') {
+        Some(focal_length) => Operation::Insert(
+            focal_length
+                .parse()
+                .map_err(|err| error(format!("{:?}: {}", focal_length, err)))?,
+        ),
+        None if rest == "-" => Operation::Remove,
+        None => return Err(error(format!("expected '=' or '-', found {:?}", rest))),
+    };
+
+    Ok(Step {
+        text: text.to_string(),
+        label: label.to_string(),
+        operation,
+    })
+}
 
-    for step in input.split(",") {
-        let caps = re.captures(step).unwrap();
-        let label: &str = match caps.name("label") {
-            Some(_label) => _label.as_str(),
-            None => "",
-        };
+fn solve_part_one(steps: &[Step]) -> u64 {
+    steps
+        .iter()
+        .map(|step| holiday_ascii_string_helper_algorithm(&step.text))
+        .sum::<u64>()
+}
 
+fn solve_part_two(steps: &[Step]) -> u64 {
+    let mut boxes: HashMap<u64, Vec<(&str, u64)>> = HashMap::new();
+
+    for step in steps {
+        let label: &str = &step.label;
         let hashed_label: u64 = holiday_ascii_string_helper_algorithm(label);
 
-        match caps.name("focal_length") {
-            Some(focal_length) => {
-                let focal_length_u64 = focal_length.as_str().parse::<u64>().unwrap();
-
-                boxes.entry(hashed_label)
-                    .and_modify(|_box| {_box.entry(label).and_modify(|fl| *fl = focal_length_u64).or_insert(focal_length_u64);})
-                    .or_insert(HashMap::from([(label, focal_length_u64)]));
-
-                boxes_new.entry(hashed_label)
-                    .and_modify(|_box| {
-                        match _box.iter().position(|b| b.0 == label) {
-                            Some(idx) => {
-                                _box.remove(idx);
-                                _box.insert(idx, (label, focal_length_u64));
-                            },
-                            None => _box.push((label, focal_length_u64)),
-                        }
-                    })
-                    .or_insert(vec![(label, focal_length_u64)]);
+        match step.operation {
+            Operation::Insert(focal_length) => {
+                let lenses = boxes.entry(hashed_label).or_default();
+                match lenses.iter().position(|lens| lens.0 == label) {
+                    Some(idx) => lenses[idx] = (label, focal_length),
+                    None => lenses.push((label, focal_length)),
+                }
             }
-            None => {
-                boxes.entry(hashed_label).and_modify(|_box| { _box.remove(label); });
-                boxes_new.entry(hashed_label)
-                    .and_modify(|_box| {
-                        _box.retain(|b| b.0 != label);
-                    });
+            Operation::Remove => {
+                boxes.entry(hashed_label).and_modify(|lenses| {
+                    lenses.retain(|lens| lens.0 != label);
+                });
             }
         }
     }
 
     let mut focusing_power: u64 = 0;
 
-    for _box in boxes_new {
-        let box_number: u64 = _box.0 + 1;
-        let lenses: Vec<(&str, u64)> = _box.1.clone();
+    for (hashed_label, lenses) in boxes {
+        let box_number: u64 = hashed_label + 1;
         for (idx, lens) in lenses.iter().enumerate() {
             let slot_number: u64 = (idx + 1) as u64;
             let focal_length: u64 = lens.1;
 
-            focusing_power += box_number * slot_number *focal_length;
+            focusing_power += box_number * slot_number * focal_length;
         }
     }
 
     focusing_power
 }
+
+#[derive(Args)]
+pub struct DayFifteen;
+
+impl Solution for DayFifteen {
+    type Input = Vec<Step>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Step>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Step>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Step>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
@@ -1,31 +1,30 @@
-use advent_of_code::{read_lines, Coordinate};
-use log::{debug, log_enabled, Level};
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::Path;
+use advent_of_code::{Answer, Coordinate, Direction, Error, Grid, Result, Solution};
+use clap::Args;
+use std::collections::{HashMap, VecDeque};
+
+// Empty space '.', mirrors '/' and '\\', and splitters '|' and '-'
+fn parse_input(input: &str) -> Result<Grid<char>> {
+    let contraption = Grid::parse_with(input, |c| match c {
+        '.' | '/' | '\\' | '|' | '-' => Some(c),
+        _ => None,
+    })?;
+    if contraption.height() == 0 || contraption.width() == 0 {
+        return Err(Error::parse(1, "expected a contraption"));
+    }
+
+    Ok(contraption)
+}
 
-pub fn part_one(input_path: &Path) -> u64 {
-    traverse(&mut input_to_board(input_path), 0, 0, Direction::East)
+fn solve_part_one(contraption: &Grid<char>) -> u64 {
+    traverse(&mut input_to_board(contraption), 0, 0, Direction::East)
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    let mut board: Board = input_to_board(input_path);
+fn solve_part_two(contraption: &Grid<char>) -> u64 {
+    let board: Board = input_to_board(contraption);
     let mut results: Vec<u64> = vec![];
 
-    let max_x = board
-        .values()
-        .into_iter()
-        .map(|bi| bi.coordinate.x)
-        .into_iter()
-        .max()
-        .unwrap();
-    let max_y = board
-        .values()
-        .into_iter()
-        .map(|bi| bi.coordinate.y)
-        .into_iter()
-        .max()
-        .unwrap();
+    let max_x = contraption.height() as i32 - 1;
+    let max_y = contraption.width() as i32 - 1;
 
     let mut directions_to_traverse: Vec<Direction> = vec![];
 
@@ -53,7 +52,7 @@ pub fn part_two(input_path: &Path) -> u64 {
                 directions_to_traverse.push(Direction::West);
             }
 
-            for direction in directions_to_traverse.iter().cloned() {
+            for direction in directions_to_traverse.iter().copied() {
                 results.push(traverse(&mut board.clone(), x, y, direction));
             }
 
@@ -84,156 +83,26 @@ fn traverse(board: &mut Board, x: i32, y: i32, starting_direction: Direction) ->
         next.energized = true;
         next.visits += 1;
 
-        match next.symbol {
-            '.' => match direction {
-                Direction::North => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x - 1, next.coordinate.y),
-                        Direction::North,
-                    ));
-                }
-                Direction::South => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x + 1, next.coordinate.y),
-                        Direction::South,
-                    ));
-                }
-                Direction::East => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y + 1),
-                        Direction::East,
-                    ));
-                }
-                Direction::West => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y - 1),
-                        Direction::West,
-                    ));
-                }
-            },
-            '|' => match direction {
-                Direction::North => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x - 1, next.coordinate.y),
-                        Direction::North,
-                    ));
-                }
-                Direction::South => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x + 1, next.coordinate.y),
-                        Direction::South,
-                    ));
-                }
-                Direction::East | Direction::West => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x - 1, next.coordinate.y),
-                        Direction::North,
-                    ));
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x + 1, next.coordinate.y),
-                        Direction::South,
-                    ));
-                }
-                _ => {}
-            },
-            '-' => match direction {
-                Direction::North | Direction::South => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y - 1),
-                        Direction::West,
-                    ));
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y + 1),
-                        Direction::East,
-                    ));
-                }
-                Direction::East => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y + 1),
-                        Direction::East,
-                    ));
-                }
-                Direction::West => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y - 1),
-                        Direction::West,
-                    ));
-                }
-            },
-            '/' => match direction {
-                Direction::North => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y + 1),
-                        Direction::East,
-                    ));
-                }
-                Direction::South => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y - 1),
-                        Direction::West,
-                    ));
-                }
-                Direction::East => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x - 1, next.coordinate.y),
-                        Direction::North,
-                    ));
-                }
-                Direction::West => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x + 1, next.coordinate.y),
-                        Direction::South,
-                    ));
-                }
-            },
-            '\\' => match direction {
-                Direction::North => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y - 1),
-                        Direction::West,
-                    ));
-                }
-                Direction::South => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x, next.coordinate.y + 1),
-                        Direction::East,
-                    ));
-                }
-                Direction::East => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x + 1, next.coordinate.y),
-                        Direction::South,
-                    ));
-                }
-                Direction::West => {
-                    neighbor_coordinates.push((
-                        Coordinate::new(next.coordinate.x - 1, next.coordinate.y),
-                        Direction::North,
-                    ));
-                }
-            },
-            _ => {}
+        for outgoing in deflect(next.symbol, direction) {
+            neighbor_coordinates.push((next.coordinate + outgoing, outgoing));
         }
 
         while let Some(nc) = neighbor_coordinates.pop() {
             let next_index: Coordinate = Coordinate::new(nc.0.x, nc.0.y);
             let bi: Option<&BoardItem> = board.get(&next_index);
 
-            match bi {
-                Some(_bi) => {
-                    let mut __bi = _bi.clone();
-                    __bi.visits += 1;
-                    __bi.energized = true;
-                    if !next.visited_neighbors.contains(&next_index) {
-                        next.visited_neighbors.push(next_index);
-                        queue.push_back(__bi.clone());
-                        direction_queue.push_back(nc.1);
-                    }
-
-                    board.insert(__bi.coordinate, __bi.clone());
-                    board.insert(next.coordinate, next.clone());
+            if let Some(_bi) = bi {
+                let mut __bi = _bi.clone();
+                __bi.visits += 1;
+                __bi.energized = true;
+                if !next.visited_neighbors.contains(&next_index) {
+                    next.visited_neighbors.push(next_index);
+                    queue.push_back(__bi.clone());
+                    direction_queue.push_back(nc.1);
                 }
-                None => {}
+
+                board.insert(__bi.coordinate, __bi.clone());
+                board.insert(next.coordinate, next.clone());
             }
         }
     }
@@ -264,29 +133,46 @@ impl BoardItem {
     }
 }
 
-fn input_to_board(input_path: &Path) -> Board {
-    let mut board: Board = Board::new();
+fn input_to_board(contraption: &Grid<char>) -> Board {
+    contraption
+        .iter()
+        .map(|(coordinate, &symbol)| {
+            (
+                coordinate,
+                BoardItem::new(coordinate.x, coordinate.y, symbol),
+            )
+        })
+        .collect()
+}
 
-    if let Ok(lines) = read_lines(input_path) {
-        for (x, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                for (y, char) in result.chars().enumerate() {
-                    board.insert(
-                        Coordinate::new(x as i32, y as i32),
-                        BoardItem::new(x as i32, y as i32, char),
-                    );
-                }
-            }
+// Where a beam heading in `direction` goes after meeting `symbol`
+fn deflect(symbol: char, direction: Direction) -> Vec<Direction> {
+    match (symbol, direction) {
+        ('|', Direction::East | Direction::West) => vec![Direction::North, Direction::South],
+        ('-', Direction::North | Direction::South) => vec![Direction::West, Direction::East],
+        ('/', Direction::North | Direction::South) | ('\\', Direction::East | Direction::West) => {
+            vec![direction.turn_right()]
         }
+        ('/', _) | ('\\', _) => vec![direction.turn_left()],
+        _ => vec![direction],
     }
-
-    board
 }
 
-#[derive(Debug, Clone)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
+#[derive(Args)]
+pub struct DaySixteen;
+
+impl Solution for DaySixteen {
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Grid<char>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Grid<char>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Grid<char>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
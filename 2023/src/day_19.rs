@@ -1,41 +1,42 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use serde::{Deserialize, Serialize};
-use serde_json::Result;
+use advent_of_code::parse::{blocks, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
-use std::fmt;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut rules: Rules = Rules::new();
-    let mut parts: Parts = Parts::new();
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
 
-    if let Ok(input) = read_file_to_string(input_path) {
-        (rules, parts) = parse_input(input);
-    }
+pub struct System {
+    rules: Rules,
+    parts: Parts,
+}
 
-    parts
-        .iter()
-        .map(|p| {
-            if (rules.get("in").unwrap()).run(&p, &rules) {
-                return p.total_rating();
-            }
+fn solve_part_one(system: &System) -> Result<u64> {
+    let mut total = 0;
+    for part in &system.parts {
+        if accepts(&system.rules, part)? {
+            total += part.total_rating();
+        }
+    }
 
-            0
-        })
-        .sum()
+    Ok(total)
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
+// Follows the part from the "in" workflow until it is accepted or rejected.
+// Without a loop a part can visit each workflow at most once.
+fn accepts(rules: &Rules, part: &Part) -> Result<bool> {
+    let mut rule_id = "in";
+    for _ in 0..=rules.len() {
+        match rules[rule_id].run(part) {
+            "A" => return Ok(true),
+            "R" => return Ok(false),
+            next => rule_id = next,
         }
     }
 
-    0
+    Err(Error::parse(
+        1,
+        "the workflows send a part around in a loop",
+    ))
 }
 
 #[derive(Debug)]
@@ -68,7 +69,7 @@ impl Operation {
         }
     }
 
-    pub fn run(&self, left_side: u64) -> Option<String> {
+    pub fn run(&self, left_side: u64) -> Option<&str> {
         let result = match &self.comparison {
             Comparison::GreaterThan => left_side > self.right_side,
             Comparison::LessThan => left_side < self.right_side,
@@ -76,13 +77,13 @@ impl Operation {
         };
 
         match result {
-            true => return Some(self.result.clone()),
+            true => Some(&self.result),
             false => None,
         }
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug)]
 struct Part {
     x: u64,
     m: u64,
@@ -91,10 +92,6 @@ struct Part {
 }
 
 impl Part {
-    pub fn new(x: u64, m: u64, a: u64, s: u64) -> Part {
-        Part { x, m, a, s }
-    }
-
     pub fn get(&self, key: &str) -> u64 {
         match key {
             "x" => self.x,
@@ -115,92 +112,149 @@ type Parts = Vec<Part>;
 
 #[derive(Debug)]
 struct Rule {
-    id: String,
     operations: Vec<Operation>,
 }
 
 impl Rule {
-    pub fn new(id: String, operations: Vec<Operation>) -> Rule {
-        Rule { id, operations }
+    pub fn new(operations: Vec<Operation>) -> Rule {
+        Rule { operations }
     }
 
-    pub fn run(&self, part: &Part, rules: &Rules) -> bool {
-        for operation in &self.operations {
-            if let Some(result) = operation.run(part.get(&operation.left_side_key)) {
-                return match result.as_str() {
-                    "A" => true,
-                    "R" => false,
-                    rule_id => (rules.get(&rule_id.to_string()).unwrap()).run(part, rules),
-                };
-            }
-        }
-
-        return false;
+    // The target of the first operation that matches the part, rejecting parts
+    // that match none
+    pub fn run(&self, part: &Part) -> &str {
+        self.operations
+            .iter()
+            .find_map(|operation| operation.run(part.get(&operation.left_side_key)))
+            .unwrap_or("R")
     }
 }
 
-fn parse_input(input: String) -> (Rules, Parts) {
+// Workflows like "px{a<2006:qkq,m>2090:A,rfg}", a blank line, then parts
+// like "{x=787,m=2655,a=1222,s=2876}"
+fn parse_input(input: &str) -> Result<System> {
+    let blocks = blocks(input);
+    let [workflows, ratings] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            format!(
+                "expected workflows and part ratings, found {} sections",
+                blocks.len()
+            ),
+        ));
+    };
+
     let mut rules: Rules = Rules::new();
-    let mut parts: Parts = Parts::new();
-
-    let split_input = input.split("\n\n").collect::<Vec<_>>();
-    let rules_input = split_input.get(0).unwrap();
-    let parts_input = split_input.get(1).unwrap();
-
-    for rule_str in rules_input.lines() {
-        let rule_id: &str = rule_str.split("{").collect::<Vec<_>>().get(0).unwrap();
-        let rules_str: String = rule_str
-            .split("{")
-            .collect::<Vec<_>>()
-            .get(1)
-            .unwrap()
-            .replace("}", "");
-        let mut operations: Vec<Operation> = vec![];
-
-        for r in rules_str.split(",").collect::<Vec<_>>() {
-            if r.contains(":") {
-                let mut comparison: Comparison = Comparison::GreaterThan;
-                if r.contains("<") {
-                    comparison = Comparison::LessThan;
+    let mut targets: Vec<(usize, String)> = vec![];
+    for (line_number, line) in workflows.numbered_lines() {
+        let mut parser = Parser::new(line_number, line);
+        let rule_id = parser.word()?;
+        parser.literal("{")?;
+        let operations = parser.list(",", |parser| {
+            let operation = parse_operation(parser)?;
+            targets.push((line_number, operation.result.clone()));
+            Ok(operation)
+        })?;
+        parser.literal("}")?;
+        parser.end()?;
+
+        if rules
+            .insert(rule_id.to_string(), Rule::new(operations))
+            .is_some()
+        {
+            return Err(Error::parse(
+                line_number,
+                format!("workflow {:?} is defined twice", rule_id),
+            ));
+        }
+    }
+
+    if !rules.contains_key("in") {
+        return Err(Error::parse(workflows.line, "expected an \"in\" workflow"));
+    }
+    for (line_number, target) in targets {
+        if target != "A" && target != "R" && !rules.contains_key(&target) {
+            return Err(Error::parse(
+                line_number,
+                format!("workflow {:?} does not exist", target),
+            ));
+        }
+    }
+
+    let parts = ratings
+        .numbered_lines()
+        .map(|(line_number, line)| {
+            let mut parser = Parser::new(line_number, line);
+            parser.literal("{")?;
+            let mut ratings = [0; 4];
+            for (index, category) in CATEGORIES.iter().enumerate() {
+                if index > 0 {
+                    parser.literal(",")?;
                 }
-                let result: String = r.split(":").collect::<Vec<_>>().get(1).unwrap().to_string();
-                let right_side: u64 = r
-                    .chars()
-                    .filter(|c| c.is_digit(10))
-                    .collect::<String>()
-                    .parse::<u64>()
-                    .unwrap();
-                let category: String = r.to_string().get(0..=0).unwrap().to_string();
-
-                operations.push(Operation::new(category, comparison, right_side, result));
-            } else {
-                operations.push(Operation::new(
-                    String::from(""),
-                    Comparison::Default,
-                    0,
-                    r.to_string(),
-                ));
+                parser.literal(category)?;
+                parser.literal("=")?;
+                ratings[index] = parser.number()?;
             }
-        }
+            parser.literal("}")?;
+            parser.end()?;
+
+            let [x, m, a, s] = ratings;
+            Ok(Part { x, m, a, s })
+        })
+        .collect::<Result<Parts>>()?;
+
+    Ok(System { rules, parts })
+}
+
+// Either "a<2006:qkq" or a bare fallback target like "rfg"
+fn parse_operation(parser: &mut Parser) -> Result<Operation> {
+    let word = parser.word()?;
+    let comparison = if parser.optional("<") {
+        Comparison::LessThan
+    } else if parser.optional(">") {
+        Comparison::GreaterThan
+    } else {
+        return Ok(Operation::new(
+            String::from(""),
+            Comparison::Default,
+            0,
+            word.to_string(),
+        ));
+    };
 
-        rules.insert(
-            String::from(rule_id),
-            Rule::new(String::from(rule_id), operations),
-        );
+    if !CATEGORIES.contains(&word) {
+        return Err(parser.error(format!(
+            "expected one of {:?}, found {:?}",
+            CATEGORIES, word
+        )));
     }
+    let right_side = parser.number()?;
+    parser.literal(":")?;
+    let result = parser.word()?;
 
-    for part_input in parts_input.lines() {
-        let mut json_input = String::from(part_input);
-        json_input = json_input.replace("=", ":");
-        json_input = json_input.replace("x", "\"x\"");
-        json_input = json_input.replace("m", "\"m\"");
-        json_input = json_input.replace("a", "\"a\"");
-        json_input = json_input.replace("s", "\"s\"");
+    Ok(Operation::new(
+        word.to_string(),
+        comparison,
+        right_side,
+        result.to_string(),
+    ))
+}
 
-        if let Ok(part) = serde_json::from_str(&json_input) {
-            parts.push(part);
-        }
+#[derive(Args)]
+pub struct DayNineteen;
+
+impl Solution for DayNineteen {
+    type Input = System;
+
+    fn parse(&self, input: &str) -> Result<System> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &System) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
     }
 
-    (rules, parts)
+    fn part_two(&self, _input: &System) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
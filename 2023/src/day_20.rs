@@ -1,15 +1,20 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use std::collections::{HashMap, HashSet, VecDeque};
+use advent_of_code::math::checked_lcm_all;
+use advent_of_code::parse::{arrow, numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use log::debug;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> u64 {
-    solve(input_path, 1000)
-}
+// Far more than a real module configuration sends for one press; past this the
+// modules are taken to be passing pulses round a loop forever
+const MAX_WORK_ITEMS_PER_PRESS: usize = 1_000_000;
+
+// Real inputs' conjunction inputs cycle within a few thousand presses
+const MAX_PRESSES: usize = 100_000;
 
-pub fn part_two(input_path: &Path) -> u64 {
-    0
+fn solve_part_one(configuration: &Configuration) -> Result<u64> {
+    solve(configuration, 1000)
 }
 
 #[derive(Clone, Debug)]
@@ -46,7 +51,7 @@ struct ConjunctionModule {
 
 impl ConjunctionModule {
     pub fn new(id: String, destination_ids: Vec<String>) -> ConjunctionModule {
-        let mut memory: HashMap<String, Pulse> = HashMap::new();
+        let memory: HashMap<String, Pulse> = HashMap::new();
 
         ConjunctionModule {
             id,
@@ -135,15 +140,7 @@ trait Module {
 }
 
 #[derive(Clone, Debug)]
-struct NoOpModule {
-    id: String,
-}
-
-impl NoOpModule {
-    pub fn new(id: String) -> NoOpModule {
-        NoOpModule { id }
-    }
-}
+struct NoOpModule;
 
 impl Module for NoOpModule {
     fn broadcast_outputs(&self) -> Vec<WorkItem> {
@@ -154,7 +151,7 @@ impl Module for NoOpModule {
         vec![]
     }
 
-    fn receive(&mut self, work_item: &WorkItem) -> Vec<WorkItem> {
+    fn receive(&mut self, _work_item: &WorkItem) -> Vec<WorkItem> {
         vec![]
     }
 }
@@ -330,124 +327,262 @@ impl WorkItem {
     }
 }
 
-fn solve(input_path: &Path, iterations: usize) -> u64 {
-    let mut _iterations: usize = iterations;
-    let mut work_queue: VecDeque<WorkItem> = VecDeque::new();
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum ModuleKind {
+    Broadcast,
+    FlipFlop,
+    Conjunction,
+}
+
+#[derive(Debug)]
+struct ModuleDefinition {
+    id: String,
+    kind: ModuleKind,
+    destination_ids: Vec<String>,
+}
+
+pub struct Configuration {
+    modules: Vec<ModuleDefinition>,
+}
+
+// Each line is a module, its kind marked by '%' or '&' unless it is the
+// broadcaster, and its destinations, like "%a -> inv, con"
+fn parse_input(input: &str) -> Result<Configuration> {
+    let mut modules: Vec<ModuleDefinition> = vec![];
+
+    for (line_number, line) in numbered_lines(input) {
+        let (module, destinations) = arrow(line_number, line)?;
+        let mut parser = Parser::new(line_number, module);
+        let kind = if parser.optional("%") {
+            ModuleKind::FlipFlop
+        } else if parser.optional("&") {
+            ModuleKind::Conjunction
+        } else {
+            ModuleKind::Broadcast
+        };
+        let id = parser.word()?;
+        parser.end()?;
+        if kind == ModuleKind::Broadcast && id != "broadcaster" {
+            return Err(Error::parse(
+                line_number,
+                format!("expected \"broadcaster\", '%' or '&', found {:?}", id),
+            ));
+        }
+        if modules.iter().any(|module| module.id == id) {
+            return Err(Error::parse(
+                line_number,
+                format!("module {:?} is defined twice", id),
+            ));
+        }
+
+        let mut parser = Parser::new(line_number, destinations);
+        let destination_ids = parser.list(",", |parser| Ok(parser.word()?.to_string()))?;
+        parser.end()?;
+
+        modules.push(ModuleDefinition {
+            id: id.to_string(),
+            kind,
+            destination_ids,
+        });
+    }
+
+    if !modules
+        .iter()
+        .any(|module| module.kind == ModuleKind::Broadcast)
+    {
+        return Err(Error::parse(1, "expected a broadcaster module"));
+    }
+
+    Ok(Configuration { modules })
+}
+
+fn build_modules(configuration: &Configuration) -> HashMap<String, Box<dyn Module>> {
     let mut modules: HashMap<String, Box<dyn Module>> = HashMap::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let split_result: Vec<&str> = result.split(" -> ").collect::<Vec<_>>();
-                let destination_ids_str = split_result.get(1).unwrap().replace(" ", "");
-                let destination_ids = destination_ids_str
-                    .split(",")
-                    .map(|m| m.to_string())
-                    .collect::<Vec<String>>();
-                let mut module_id: String = split_result.get(0).unwrap().to_string();
-
-                if module_id.contains("&") {
-                    module_id = module_id.clone().replace("&", "");
-
-                    modules.insert(
-                        module_id.clone(),
-                        Box::new(ConjunctionModule::new(module_id.clone(), destination_ids)),
-                    );
-                } else if module_id.contains("%") {
-                    module_id = module_id.clone().replace("%", "");
-
-                    modules.insert(
-                        module_id.clone(),
-                        Box::new(FlipFlopModule::new(module_id.clone(), destination_ids)),
-                    );
-                } else {
-                    if module_id == "broadcaster" {
-                        modules.insert(
-                            module_id.clone(),
-                            Box::new(BroadcastModule::new(module_id.clone(), destination_ids)),
-                        );
-                    } else {
-                        modules.insert(
-                            module_id.clone(),
-                            Box::new(NoOpModule::new(module_id.clone())),
-                        );
-                    }
-                }
+    for definition in &configuration.modules {
+        let id = definition.id.clone();
+        let destination_ids = definition.destination_ids.clone();
+        let module: Box<dyn Module> = match definition.kind {
+            ModuleKind::Broadcast => Box::new(BroadcastModule::new(id.clone(), destination_ids)),
+            ModuleKind::FlipFlop => Box::new(FlipFlopModule::new(id.clone(), destination_ids)),
+            ModuleKind::Conjunction => {
+                Box::new(ConjunctionModule::new(id.clone(), destination_ids))
             }
-        }
+        };
+        modules.insert(id, module);
     }
 
-    if !modules.contains_key("rx") {
-        modules.insert(
-            "rx".to_string(),
-            Box::new(NoOpModule::new("rx".to_string())),
-        );
+    // Destinations like "output" or "rx" are never defined and do nothing
+    for definition in &configuration.modules {
+        for destination_id in &definition.destination_ids {
+            if !modules.contains_key(destination_id) {
+                modules.insert(destination_id.clone(), Box::new(NoOpModule));
+            }
+        }
     }
 
-    let mut low_pulses_sent: u64 = 0;
-    let mut high_pulses_sent: u64 = 0;
+    modules
+}
 
-    for idx in 1..=_iterations {
-        if idx == 1 {
-            work_queue.push_back(WorkItem::new(
-                String::from("*"),
-                String::from("*"),
-                WorkAction::BroadcastRequest,
-                None,
+// Presses the button once, calling `on_pulse` with the sender and the pulse
+// for every pulse sent, the button's own included
+fn press<F>(
+    modules: &mut HashMap<String, Box<dyn Module>>,
+    first_press: bool,
+    mut on_pulse: F,
+) -> Result<()>
+where
+    F: FnMut(&str, Pulse),
+{
+    let mut work_queue: VecDeque<WorkItem> = VecDeque::new();
+
+    if first_press {
+        work_queue.push_back(WorkItem::new(
+            String::from("*"),
+            String::from("*"),
+            WorkAction::BroadcastRequest,
+            None,
+        ));
+    } else {
+        work_queue.push_back(WorkItem::new(
+            String::from("button"),
+            String::from("broadcaster"),
+            WorkAction::Publish,
+            Some(Pulse::Low),
+        ));
+        on_pulse("button", Pulse::Low);
+    }
+    debug!("button -low-> broadcaster");
+    let mut work_items: usize = 0;
+    while let Some(work_item) = work_queue.pop_front() {
+        work_items += 1;
+        if work_items > MAX_WORK_ITEMS_PER_PRESS {
+            return Err(Error::parse(
+                1,
+                "the pulses from one button press never settle",
             ));
-        } else {
+        }
+
+        if work_item.action == WorkAction::BroadcastOutput {
+            for module in modules.values_mut() {
+                module.receive(&work_item);
+            }
+        } else if work_item.action == WorkAction::BroadcastRequest {
+            for module in modules.values() {
+                work_queue.extend(module.broadcast_outputs());
+            }
             work_queue.push_back(WorkItem::new(
                 String::from("button"),
                 String::from("broadcaster"),
                 WorkAction::Publish,
                 Some(Pulse::Low),
             ));
-            low_pulses_sent += 1;
-        }
-        debug!("button -low-> broadcaster");
-        while !work_queue.is_empty() {
-            let work_item: WorkItem = work_queue.pop_front().unwrap();
-
-            if work_item.action == WorkAction::BroadcastOutput
-            {
-                for module in modules.values_mut() {
-                    module.receive(&work_item);
-                }
-            } else if work_item.action == WorkAction::BroadcastRequest {
-                for module in modules.values() {
-                    work_queue.extend(module.broadcast_outputs());
+            on_pulse("button", Pulse::Low);
+        } else {
+            let module: &mut Box<dyn Module> = modules.get_mut(&work_item.consumer).unwrap();
+            match work_item.action {
+                WorkAction::Publish => {
+                    work_queue.extend(module.publish());
                 }
-                work_queue.push_back(WorkItem::new(
-                    String::from("button"),
-                    String::from("broadcaster"),
-                    WorkAction::Publish,
-                    Some(Pulse::Low),
-                ));
-                low_pulses_sent += 1;
-            } else {
-                let module: &mut Box<dyn Module> =
-                    modules.get_mut(&work_item.consumer).unwrap();
-                match work_item.action {
-                    WorkAction::Publish => {
-                        work_queue.extend(module.publish());
-                    }
-                    WorkAction::Receive => {
-                        work_queue.extend(module.receive(&work_item));
-                    }
-                    _ => {}
+                WorkAction::Receive => {
+                    work_queue.extend(module.receive(&work_item));
                 }
+                _ => {}
+            }
 
-                if work_item.action == WorkAction::Receive {
-                    if let Some(pulse) = work_item.pulse {
-                        match pulse {
-                            Pulse::High => high_pulses_sent += 1,
-                            Pulse::Low => low_pulses_sent += 1,
-                        }
-                    }
+            if work_item.action == WorkAction::Receive {
+                if let Some(pulse) = work_item.pulse {
+                    on_pulse(&work_item.publisher, pulse);
                 }
             }
         }
     }
 
-    low_pulses_sent * high_pulses_sent
+    Ok(())
+}
+
+fn solve(configuration: &Configuration, iterations: usize) -> Result<u64> {
+    let mut modules = build_modules(configuration);
+
+    let mut low_pulses_sent: u64 = 0;
+    let mut high_pulses_sent: u64 = 0;
+
+    for idx in 1..=iterations {
+        press(&mut modules, idx == 1, |_, pulse| match pulse {
+            Pulse::High => high_pulses_sent += 1,
+            Pulse::Low => low_pulses_sent += 1,
+        })?;
+    }
+
+    Ok(low_pulses_sent * high_pulses_sent)
+}
+
+// rx is fed by a single conjunction, which sends it a low pulse once all of its
+// own inputs last sent a high one. Each input sends its high pulse on a fixed
+// cycle, so rx first gets a low pulse at the lcm of those cycle lengths.
+fn solve_part_two(configuration: &Configuration) -> Result<i128> {
+    let mut feeders = configuration
+        .modules
+        .iter()
+        .filter(|module| module.destination_ids.iter().any(|id| id == "rx"));
+    let feeder = match (feeders.next(), feeders.next()) {
+        (Some(feeder), None) if feeder.kind == ModuleKind::Conjunction => feeder,
+        _ => {
+            return Err(Error::parse(
+                1,
+                "expected exactly one conjunction module sending to \"rx\"",
+            ))
+        }
+    };
+
+    let inputs: Vec<&str> = configuration
+        .modules
+        .iter()
+        .filter(|module| module.destination_ids.contains(&feeder.id))
+        .map(|module| module.id.as_str())
+        .collect();
+    let mut cycle_lengths: HashMap<String, i128> = HashMap::new();
+    let mut modules = build_modules(configuration);
+
+    for presses in 1..=MAX_PRESSES {
+        press(&mut modules, presses == 1, |publisher, pulse| {
+            if pulse == Pulse::High && inputs.contains(&publisher) {
+                cycle_lengths
+                    .entry(publisher.to_string())
+                    .or_insert(presses as i128);
+            }
+        })?;
+
+        if cycle_lengths.len() == inputs.len() {
+            return checked_lcm_all(cycle_lengths.into_values())
+                .ok_or_else(|| Error::parse(1, "the number of button presses overflows"));
+        }
+    }
+
+    Err(Error::parse(
+        1,
+        format!(
+            "the inputs of {:?} do not all send a high pulse within {} presses",
+            feeder.id, MAX_PRESSES
+        ),
+    ))
+}
+
+#[derive(Args)]
+pub struct DayTwenty;
+
+impl Solution for DayTwenty {
+    type Input = Configuration;
+
+    fn parse(&self, input: &str) -> Result<Configuration> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Configuration) -> Result<Answer> {
+        Ok(solve_part_one(input)?.into())
+    }
+
+    fn part_two(&self, input: &Configuration) -> Result<Answer> {
+        Ok(solve_part_two(input)?.into())
+    }
 }
@@ -1,173 +1,88 @@
-use advent_of_code::{read_lines, Coordinate};
+use advent_of_code::pathfinding::bfs;
+use advent_of_code::{Answer, Coordinate, Error, Grid, Result, Solution};
+use clap::Args;
 use log::{debug, log_enabled, Level};
-use std::collections::{HashMap, HashSet, VecDeque};
-use std::fmt;
-use std::fmt::Display;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path, goal_distance: u64) -> u64 {
-    let mut height_map: HashMap<Coordinate, Item> = HashMap::new();
-    let mut starting_node: Option<Item> = None;
-
-    if let Ok(lines) = read_lines(input_path) {
-        for (row, line) in lines.enumerate() {
-            if let Ok(result) = line {
-                for (column, char) in result.chars().enumerate() {
-                    let coordinate: Coordinate = Coordinate::new(row as i32, column as i32);
-                    let item: Item = Item::new(char, coordinate);
-                    height_map.insert(coordinate, item.clone());
-
-                    if char == STARTING_CHAR {
-                        starting_node = Some(item.clone());
-                    }
-                }
-            }
-        }
-    }
-
-    print_board(&height_map);
-
-    let mut result: u64 = u64::MIN;
-    if let Some(_starting_node) = starting_node {
-        result = running_up_that_hill(&_starting_node, goal_distance, &mut height_map);
-    }
-
-    print_board(&height_map);
+const STARTING_CHAR: char = 'S';
+const ROCK_CHAR: char = '#';
 
-    result
+pub struct Garden {
+    grid: Grid<char>,
+    start: Coordinate,
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
+fn parse_input(input: &str) -> Result<Garden> {
+    let grid = Grid::parse_with(input, |c| match c {
+        '.' | ROCK_CHAR | STARTING_CHAR => Some(c),
+        _ => None,
+    })?;
+    let start = grid
+        .find(&STARTING_CHAR)
+        .ok_or_else(|| Error::parse(1, "expected a starting plot 'S'"))?;
 
-    0
+    Ok(Garden { grid, start })
 }
 
-fn running_up_that_hill(
-    start: &Item,
-    goal_distance: u64,
-    map: &mut HashMap<Coordinate, Item>,
-) -> u64 {
-    let mut queue: VecDeque<Item> = VecDeque::new();
-    queue.push_front(start.clone());
-
-    // Distances stores previous distance and current distance to check if the elf gets
-    // stuck in a loop ;(
-    let mut distances: HashMap<Coordinate, (u64, u64)> = HashMap::with_capacity(map.len());
-    distances.insert(start.coordinate, (1, 0));
-
-    while !queue.is_empty() {
-        let mut node: Item = queue.pop_front().unwrap();
-        for neighbor in node.neighbors(map) {
-            let previous_neighbor_distance: u64 =
-                distances.get(&neighbor.coordinate).unwrap_or(&(1, 0)).0;
-            let current_neighbor_distance: u64 =
-                distances.get(&neighbor.coordinate).unwrap_or(&(1, 0)).1;
-
-            if previous_neighbor_distance != goal_distance {
-                let current_distance: u64 = distances.get(&node.coordinate).unwrap().1 + 1;
-
-                if current_distance < goal_distance && current_distance != current_neighbor_distance
-                {
-                    queue.push_back(neighbor.clone());
-                }
-                distances
-                    .entry(neighbor.coordinate.clone())
-                    .and_modify(|v| *v = (v.1, current_distance))
-                    .or_insert((0, current_distance));
-                map.entry(neighbor.coordinate).and_modify(|e| e.value = 'O');
-            }
-        }
-    }
-
-    distances
-        .values()
-        .copied()
-        .filter(|v| v.1 == goal_distance)
-        .collect::<Vec<_>>()
-        .len() as u64
+// The garden plots next to a plot, leaving out rocks and the edge of the map
+fn plots_around(grid: &Grid<char>, coordinate: Coordinate) -> Vec<Coordinate> {
+    grid.neighbors(coordinate)
+        .filter(|neighbor| grid[*neighbor] != ROCK_CHAR)
+        .collect()
 }
 
-const STARTING_CHAR: char = 'S';
-
-#[derive(Debug, Clone)]
-struct Item {
-    value: char,
-    coordinate: Coordinate,
-    neighbors: Option<Vec<Item>>,
+fn solve_part_one(garden: &Garden, goal_distance: u64) -> u64 {
+    // Walk the whole garden, since no plot is a goal
+    let search = bfs(
+        garden.start,
+        |coordinate| plots_around(&garden.grid, *coordinate),
+        |_| false,
+    );
+
+    // The elf can step back and forth, so any plot reached in fewer steps of
+    // the same parity can also be ended on
+    let reachable: Vec<Coordinate> = search
+        .distances
+        .iter()
+        .filter(|(_, &distance)| {
+            let distance = distance as u64;
+            distance <= goal_distance && distance % 2 == goal_distance % 2
+        })
+        .map(|(&coordinate, _)| coordinate)
+        .collect();
+
+    print_board(&garden.grid, &reachable);
+
+    reachable.len() as u64
 }
 
-impl Item {
-    pub fn new(value: char, coordinate: Coordinate) -> Item {
-        Item {
-            value,
-            coordinate,
-            neighbors: None,
+fn print_board(grid: &Grid<char>, reachable: &[Coordinate]) {
+    if log_enabled!(Level::Debug) {
+        let mut board = grid.clone();
+        for &coordinate in reachable {
+            board[coordinate] = 'O';
         }
-    }
-
-    pub fn can_climb(&self, other: &Item) -> bool {
-        other.value != '#'
-    }
-
-    pub fn neighbors(&mut self, height_map: &HashMap<Coordinate, Item>) -> Vec<Item> {
-        return match &self.neighbors {
-            Some(n) => n.to_vec(),
-            None => {
-                let mut neighbors: Vec<Item> = Vec::new();
-
-                for neighboring_coordinate in self.neighboring_coordinates() {
-                    if let Some(neighbor) = height_map.get(&neighboring_coordinate) {
-                        if self.can_climb(neighbor) {
-                            neighbors.push(neighbor.clone());
-                        }
-                    }
-                }
-
-                self.neighbors = Some(neighbors.clone());
-                neighbors
-            }
-        };
-    }
-
-    pub fn neighboring_coordinates(&self) -> Vec<Coordinate> {
-        self.coordinate.neighboring_cardinal_directions()
+        debug!("\n{}", board);
     }
 }
 
-impl fmt::Display for Item {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.value,)
-    }
+#[derive(Args)]
+pub struct DayTwentyOne {
+    #[arg(short, long, default_value_t = 64)]
+    goal_distance: u64,
 }
 
-fn print_board(board: &HashMap<Coordinate, Item>) {
-    if log_enabled!(Level::Debug) {
-        let x_iter = board.keys().into_iter().map(|c| c.x).into_iter();
+impl Solution for DayTwentyOne {
+    type Input = Garden;
 
-        let y_iter = board.keys().into_iter().map(|c| c.y).into_iter();
+    fn parse(&self, input: &str) -> Result<Garden> {
+        parse_input(input)
+    }
 
-        let min_x = x_iter.clone().min().unwrap_or(0);
-        let max_x = x_iter.max().unwrap_or(0);
-        let min_y = y_iter.clone().min().unwrap_or(0);
-        let max_y = y_iter.max().unwrap_or(0);
+    fn part_one(&self, input: &Garden) -> Result<Answer> {
+        Ok(solve_part_one(input, self.goal_distance).into())
+    }
 
-        for x in min_x..=max_x {
-            for y in min_y..=max_y {
-                if let Some(bi) = board.get(&Coordinate::new(x, y)) {
-                    print!("{}", bi);
-                } else {
-                    print!(".");
-                }
-            }
-            println!();
-        }
-        println!("{:-<1$}", "", (max_x + 5) as usize);
+    fn part_two(&self, _input: &Garden) -> Result<Answer> {
+        Err(Error::Unsolved)
     }
 }
@@ -1,77 +1,41 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-// read_lines from https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-pub fn read_file_to_string<P>(filename: P) -> Result<String, std::io::Error>
-where
-    P: AsRef<Path>,
-{
-    std::fs::read_to_string(filename)
-}
-
-#[derive(Clone, Copy, Ord, Eq, PartialEq, Hash, Debug)]
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Coordinate {
-    pub fn new(x: i32, y: i32) -> Coordinate {
-        Coordinate { x, y }
-    }
-
-    pub fn neighboring_cardinal_directions(&self) -> Vec<Coordinate> {
-        return Vec::from([
-            self.north_coordinate(),
-            self.south_coordinate(),
-            self.east_coordinate(),
-            self.west_coordinate(),
-        ]);
-    }
-
-    pub fn north_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x - 1, self.y)
-    }
-
-    pub fn south_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x + 1, self.y)
-    }
-
-    pub fn east_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x, self.y + 1)
-    }
-
-    pub fn west_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x, self.y - 1)
-    }
-
-    pub fn distance(&self, other: Coordinate) -> i32 {
-        (((self.x - other.x).abs().pow(2) + (self.y - other.y).abs().pow(2)) as f32).sqrt() as i32
-    }
-}
-
-impl PartialOrd for Coordinate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.x == other.x {
-            return Some(self.y.cmp(&other.y));
-        }
-        Some(self.y.cmp(&other.y))
-    }
-}
-
-impl fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "x={}, y={}", self.x, self.y,)
-    }
+use advent_of_code::Registry;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_08;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_13;
+mod day_14;
+mod day_15;
+mod day_16;
+mod day_19;
+mod day_20;
+mod day_21;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<day_01::DayOne>(2023, 1);
+    registry.register::<day_02::DayTwo>(2023, 2);
+    registry.register::<day_03::DayThree>(2023, 3);
+    registry.register::<day_04::DayFour>(2023, 4);
+    registry.register::<day_05::DayFive>(2023, 5);
+    registry.register::<day_06::DaySix>(2023, 6);
+    registry.register::<day_07::DaySeven>(2023, 7);
+    registry.register::<day_08::DayEight>(2023, 8);
+    registry.register::<day_09::DayNine>(2023, 9);
+    registry.register::<day_10::DayTen>(2023, 10);
+    registry.register::<day_11::DayEleven>(2023, 11);
+    registry.register::<day_13::DayThirteen>(2023, 13);
+    registry.register::<day_14::DayFourteen>(2023, 14);
+    registry.register::<day_15::DayFifteen>(2023, 15);
+    registry.register::<day_16::DaySixteen>(2023, 16);
+    registry.register::<day_19::DayNineteen>(2023, 19);
+    registry.register::<day_20::DayTwenty>(2023, 20);
+    registry.register::<day_21::DayTwentyOne>(2023, 21);
 }
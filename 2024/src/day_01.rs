@@ -1,45 +1,58 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Result, Solution};
+use clap::Args;
 use std::iter::zip;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut left: Vec<i32> = vec![];
-    let mut right: Vec<i32> = vec![];
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let mut split_result = result.split_whitespace();
-                left.push(split_result.next().unwrap().parse().unwrap());
-                right.push(split_result.next().unwrap().parse().unwrap());
-            }
-        }
-    }
+
+// The left and right lists of location IDs, in input order
+pub type LocationLists = (Vec<u32>, Vec<u32>);
+
+fn solve_part_one(lists: &LocationLists) -> u64 {
+    let (mut left, mut right) = lists.clone();
 
     left.sort();
     right.sort();
 
-    zip(left, right).map(|(x, y)| (x - y).abs()).sum()
+    zip(left, right).map(|(x, y)| x.abs_diff(y) as u64).sum()
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    let mut left: Vec<i32> = vec![];
-    let mut right: Vec<i32> = vec![];
-
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let mut split_result = result.split_whitespace();
-                left.push(split_result.next().unwrap().parse().unwrap());
-                right.push(split_result.next().unwrap().parse().unwrap());
-            }
-        }
-    }
+fn solve_part_two(lists: &LocationLists) -> u64 {
+    let (left, right) = lists;
 
     left.iter()
-        .map(|x| x * right.iter().filter(|y| *y == x).count() as i32)
-        .collect::<Vec<i32>>()
-        .iter()
+        .map(|x| *x as u64 * right.iter().filter(|y| *y == x).count() as u64)
         .sum()
 }
+
+// Each line is a pair of location IDs, like "3   4"
+fn parse_input(input: &str) -> Result<LocationLists> {
+    let mut left: Vec<u32> = vec![];
+    let mut right: Vec<u32> = vec![];
+
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        left.push(parser.number()?);
+        right.push(parser.number()?);
+        parser.end()?;
+    }
+
+    Ok((left, right))
+}
+
+#[derive(Args)]
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = LocationLists;
+
+    fn parse(&self, input: &str) -> Result<LocationLists> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &LocationLists) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &LocationLists) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
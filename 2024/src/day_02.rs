@@ -1,45 +1,39 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use std::path::Path;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> i32 {
+type Report = Vec<u32>;
+
+fn solve_part_one(reports: &[Report]) -> i32 {
     let mut safe_reports: i32 = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if is_safe_report(&result, false) {
-                    safe_reports += 1;
-                }
-            }
+    for report in reports {
+        if is_safe_report(report, false) {
+            safe_reports += 1;
         }
     }
 
     safe_reports
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(reports: &[Report]) -> i32 {
     let mut safe_reports: i32 = 0;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if is_safe_report(&result, true) {
-                    safe_reports += 1;
-                }
-            }
+    for report in reports {
+        if is_safe_report(report, true) {
+            safe_reports += 1;
         }
     }
 
     safe_reports
 }
 
-fn safe_report_check(report_items: Vec<&str>) -> bool {
-    let mut report_results: Vec<i32> = vec![];
+fn safe_report_check(report_items: Vec<u32>) -> bool {
+    let mut report_results: Vec<i64> = vec![];
     let expected_report_results = report_items.len() - 1;
 
     for w in report_items.windows(2) {
-        report_results.push(w[0].parse::<i32>().unwrap() - w[1].parse::<i32>().unwrap());
+        report_results.push(w[0] as i64 - w[1] as i64);
     }
 
     let only_decreasing_report_results = report_results
@@ -69,8 +63,8 @@ fn safe_report_check(report_items: Vec<&str>) -> bool {
     (all_levels_are_decreasing || all_levels_are_increasing) && all_levels_are_within_range
 }
 
-fn is_safe_report(report: &str, dampener_enabled: bool) -> bool {
-    let report_items = report.split_whitespace().collect::<Vec<_>>();
+fn is_safe_report(report: &Report, dampener_enabled: bool) -> bool {
+    let report_items = report.clone();
 
     match dampener_enabled {
         true => {
@@ -93,3 +87,43 @@ fn is_safe_report(report: &str, dampener_enabled: bool) -> bool {
         false => safe_report_check(report_items),
     }
 }
+
+// Each line is a report of at least two levels, like "7 6 4 2 1"
+fn parse_input(input: &str) -> Result<Vec<Report>> {
+    numbered_lines(input)
+        .map(|(line_number, line)| {
+            let mut parser = Parser::new(line_number, line);
+            let mut report: Report = vec![];
+            while !parser.rest().trim().is_empty() {
+                report.push(parser.number()?);
+            }
+            if report.len() < 2 {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected at least two levels, found {:?}", line),
+                ));
+            }
+
+            Ok(report)
+        })
+        .collect()
+}
+
+#[derive(Args)]
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input = Vec<Report>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Report>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Report>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Report>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
@@ -1,53 +1,89 @@
-use advent_of_code::read_file_to_string;
-use log::{debug, log_enabled, Level};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use regex::Regex;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    let mut result: i32 = 0;
-
-    if let Ok(input) = read_file_to_string(input_path) {
-        let regex =
-            Regex::new(r"(?m)mul\((?<left_operand>\d{1,3}),(?<right_operand>\d{1,3})\)").unwrap();
-
-        result = regex
-            .captures_iter(&input)
-            .map(|capture| {
-                capture["left_operand"].parse::<i32>().unwrap()
-                    * capture["right_operand"].parse::<i32>().unwrap()
-            })
-            .sum::<i32>()
-    }
 
-    result
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    DoNot,
+}
+
+fn solve_part_one(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(left_operand, right_operand) => left_operand * right_operand,
+            _ => 0,
+        })
+        .sum()
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(instructions: &[Instruction]) -> u32 {
     let mut instructions_enabled: bool = true;
-    let mut instruction_results: Vec<i32> = vec![];
+    let mut instruction_results: Vec<u32> = vec![];
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => instructions_enabled = true,
+            Instruction::DoNot => instructions_enabled = false,
+            Instruction::Mul(left_operand, right_operand) => {
+                if instructions_enabled {
+                    instruction_results.push(left_operand * right_operand);
+                }
+            }
+        }
+    }
 
-    if let Ok(input) = read_file_to_string(input_path) {
-        let regex = Regex::new(r"(?m)mul\((?<left_operand>\d{1,3}),(?<right_operand>\d{1,3})\)|(?<do>do\(\))|(?<do_not>don't\(\))").unwrap();
+    instruction_results.iter().sum()
+}
 
-        for capture in regex.captures_iter(&input) {
+// Corrupted memory, from which only the exact "mul(X,Y)", "do()" and "don't()"
+// instructions count
+fn parse_input(input: &str) -> Result<Vec<Instruction>> {
+    let regex = Regex::new(r"(?m)mul\((?<left_operand>\d{1,3}),(?<right_operand>\d{1,3})\)|(?<do>do\(\))|(?<do_not>don't\(\))").unwrap();
+
+    let instructions = regex
+        .captures_iter(input)
+        .map(|capture| {
             if capture.name("do").is_some() {
-                instructions_enabled = true;
-                continue;
+                Instruction::Do
+            } else if capture.name("do_not").is_some() {
+                Instruction::DoNot
+            } else {
+                // At most three digits each, so these always fit
+                Instruction::Mul(
+                    capture["left_operand"].parse().unwrap(),
+                    capture["right_operand"].parse().unwrap(),
+                )
             }
+        })
+        .collect::<Vec<_>>();
 
-            if capture.name("do_not").is_some() {
-                instructions_enabled = false;
-                continue;
-            }
+    if !instructions
+        .iter()
+        .any(|instruction| matches!(instruction, Instruction::Mul(..)))
+    {
+        return Err(Error::parse(1, "expected at least one mul instruction"));
+    }
 
-            if instructions_enabled {
-                instruction_results.push(
-                    capture["left_operand"].parse::<i32>().unwrap()
-                        * capture["right_operand"].parse::<i32>().unwrap(),
-                );
-            }
-        }
+    Ok(instructions)
+}
+
+#[derive(Args)]
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Instruction>> {
+        parse_input(input)
     }
 
-    instruction_results.iter().sum::<i32>()
+    fn part_one(&self, input: &Vec<Instruction>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Instruction>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
@@ -1,10 +1,10 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
-use std::collections::HashMap;
-use std::path::Path;
+use advent_of_code::graph::Graph;
+use advent_of_code::parse::{blocks, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
 #[derive(Debug)]
-struct Rule {
+pub struct Rule {
     pub x: i32,
     pub y: i32,
 }
@@ -14,7 +14,7 @@ impl Rule {
         Rule { x, y }
     }
 
-    pub fn is_met(&self, update: &Vec<i32>) -> bool {
+    pub fn is_met(&self, update: &[i32]) -> bool {
         match update.iter().position(|&x| x == self.x) {
             Some(x_index) => match update.iter().position(|&y| y == self.y) {
                 Some(y_index) => y_index > x_index,
@@ -26,50 +26,48 @@ impl Rule {
 }
 
 type Rules = Vec<Rule>;
-type Updates = Vec<Vec<i32>>;
+// Each update's pages with its line, for errors about the update
+type Updates = Vec<(usize, Vec<i32>)>;
+
+// The rules, "X|Y", then a blank line and the updates, "A,B,C"
+fn parse_input(input: &str) -> Result<(Rules, Updates)> {
+    let blocks = blocks(input);
+    let [rules_block, updates_block] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            "expected the rules and the updates separated by a blank line",
+        ));
+    };
 
-fn parse_input(input_path: &Path) -> (Rules, Updates) {
     let mut rules: Rules = Rules::new();
-    let mut updates: Updates = Updates::new();
+    for (line_number, line) in rules_block.numbered_lines() {
+        let mut parser = Parser::new(line_number, line);
+        let x = parser.number::<i32>()?;
+        parser.literal("|")?;
+        let y = parser.number::<i32>()?;
+        parser.end()?;
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                if result.contains("|") {
-                    let split_rule = result
-                        .split("|")
-                        .filter(|s| s.parse::<i32>().is_ok())
-                        .map(|s| s.parse::<i32>().unwrap())
-                        .collect::<Vec<i32>>();
-                    if split_rule.len() == 2 {
-                        rules.push(Rule::new(split_rule[0], split_rule[1]));
-                    }
-                } else if result.contains(",") {
-                    updates.push(
-                        result
-                            .split(",")
-                            .filter(|s| s.parse::<i32>().is_ok())
-                            .map(|s| s.parse::<i32>().unwrap())
-                            .collect::<Vec<i32>>(),
-                    );
-                }
-            }
-        }
+        rules.push(Rule::new(x, y));
     }
 
-    (rules, updates)
+    let mut updates: Updates = Updates::new();
+    for (line_number, line) in updates_block.numbered_lines() {
+        let mut parser = Parser::new(line_number, line);
+        updates.push((
+            line_number,
+            parser.list(",", |parser| parser.number::<i32>())?,
+        ));
+        parser.end()?;
+    }
+
+    Ok((rules, updates))
 }
 
-fn solve_part_one(rules: Rules, mut updates: Updates) -> i32 {
+fn solve_part_one(rules: &[Rule], updates: &Updates) -> i32 {
     updates
-        .iter_mut()
-        .filter(|u| {
-            rules
-                .iter()
-                .map(|r| r.is_met(&u))
-                .reduce(|acc, e| acc && e)
-                .unwrap()
-        })
+        .iter()
+        .map(|(_, u)| u)
+        .filter(|u| is_ordered(rules, u))
         .map(|u| {
             let midpoint: usize = (u.len() as f32 / 2.0).floor() as usize;
             u[midpoint]
@@ -77,12 +75,89 @@ fn solve_part_one(rules: Rules, mut updates: Updates) -> i32 {
         .sum::<i32>()
 }
 
-pub fn part_one(input_path: &Path) -> i32 {
-    let (rules, mut updates) = parse_input(input_path);
+fn is_ordered(rules: &[Rule], update: &[i32]) -> bool {
+    rules.iter().all(|r| r.is_met(update))
+}
+
+// Reorders an update by the rules between its own pages. The rules as a whole
+// are cyclic, but those between the pages of one update must not be.
+fn reorder(rules: &[Rule], line: usize, update: &[i32]) -> Result<Vec<i32>> {
+    let mut graph = Graph::directed();
+    for page in update {
+        graph.add_node(&page.to_string());
+    }
+    for rule in rules {
+        if update.contains(&rule.x) && update.contains(&rule.y) {
+            graph.add_edge(&rule.x.to_string(), &rule.y.to_string());
+        }
+    }
+
+    let order = graph
+        .topological_sort()
+        .map_err(|err| Error::parse(line, format!("cannot reorder the update: {}", err)))?;
+    // Where each node falls in the order, indexed by node id
+    let mut rank = vec![0; graph.len()];
+    for (position, id) in order.into_iter().enumerate() {
+        rank[id] = position;
+    }
+
+    // Sorting the pages themselves keeps a page listed twice in the update
+    let mut ordered = update.to_vec();
+    ordered.sort_by_key(|page| graph.id(&page.to_string()).map(|id| rank[id]));
+
+    Ok(ordered)
+}
+
+fn solve_part_two(rules: &[Rule], updates: &Updates) -> Result<i32> {
+    updates
+        .iter()
+        .filter(|(_, u)| !is_ordered(rules, u))
+        .map(|(line, u)| {
+            let ordered = reorder(rules, *line, u)?;
+            Ok(ordered[ordered.len() / 2])
+        })
+        .sum()
+}
+
+#[derive(Args)]
+pub struct DayFive;
+
+impl Solution for DayFive {
+    type Input = (Rules, Updates);
+
+    fn parse(&self, input: &str) -> Result<(Rules, Updates)> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &(Rules, Updates)) -> Result<Answer> {
+        let (rules, updates) = input;
 
-    solve_part_one(rules, updates)
+        Ok(solve_part_one(rules, updates).into())
+    }
+
+    fn part_two(&self, input: &(Rules, Updates)) -> Result<Answer> {
+        let (rules, updates) = input;
+
+        Ok(solve_part_two(rules, updates)?.into())
+    }
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
-    0
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reorder_keeps_repeated_pages() {
+        let rules = vec![Rule::new(1, 2), Rule::new(2, 3)];
+        assert_eq!(reorder(&rules, 1, &[3, 2, 3, 1]).unwrap(), vec![1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_reorder_reports_a_cycle() {
+        let rules = vec![Rule::new(1, 2), Rule::new(2, 1)];
+        assert!(matches!(
+            reorder(&rules, 4, &[2, 1]),
+            Err(Error::Parse { line: 4, .. })
+        ));
+    }
 }
@@ -1,27 +1,6 @@
-use advent_of_code::read_lines;
-use log::{debug, log_enabled, Level};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::VecDeque;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u64 {
-    let equations: Equations = parse_input(input_path);
-    solve(
-        &equations,
-        &vec![Operation::Addition, Operation::Multiplication],
-    )
-}
-
-pub fn part_two(input_path: &Path) -> u64 {
-    let equations: Equations = parse_input(input_path);
-    solve(
-        &equations,
-        &vec![
-            Operation::Addition,
-            Operation::Multiplication,
-            Operation::Concatenation,
-        ],
-    )
-}
 
 fn solve(equations: &Equations, operations: &Vec<Operation>) -> u64 {
     equations
@@ -31,28 +10,32 @@ fn solve(equations: &Equations, operations: &Vec<Operation>) -> u64 {
         .sum::<u64>()
 }
 
-fn parse_input(input_path: &Path) -> Equations {
+fn parse_input(input: &str) -> Result<Equations> {
     let mut equations: Equations = Equations::new();
 
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let split_result: Vec<&str> = result.split(": ").collect::<Vec<_>>();
-                equations.push(Equation::new(
-                    split_result[0].parse().unwrap(),
-                    VecDeque::from(
-                        split_result[1]
-                            .split_whitespace()
-                            .filter(|s| s.parse::<u64>().is_ok())
-                            .map(|s| s.parse::<u64>().unwrap())
-                            .collect::<Vec<_>>(),
-                    ),
-                ))
-            }
-        }
+    for (index, result) in input.lines().enumerate() {
+        let line_number = index + 1;
+        let parse_number = |s: &str| {
+            s.parse::<u64>()
+                .map_err(|err| Error::parse(line_number, format!("{:?}: {}", s, err)))
+        };
+
+        let (solution, operators) = result.split_once(": ").ok_or_else(|| {
+            Error::parse(
+                line_number,
+                format!("expected `solution: operators`, found {:?}", result),
+            )
+        })?;
+        equations.push(Equation::new(
+            parse_number(solution)?,
+            operators
+                .split_whitespace()
+                .map(parse_number)
+                .collect::<Result<VecDeque<_>>>()?,
+        ))
     }
 
-    equations
+    Ok(equations)
 }
 
 fn is_operation_possible(
@@ -63,16 +46,17 @@ fn is_operation_possible(
 ) -> u64 {
     let mut successful_operations: u64 = 0;
 
+    // Results that overflow a u64 cannot reach the solution and are dropped
     if let Some(r) = operands.pop_front() {
         for operation in allowed_operations {
             match operation {
                 Operation::Addition => {
-                    let result: u64 = accumulator + r;
+                    let Some(result) = accumulator.checked_add(r) else {
+                        continue;
+                    };
 
-                    if operands.is_empty() {
-                        if result == solution {
-                            successful_operations += 1;
-                        }
+                    if operands.is_empty() && result == solution {
+                        successful_operations += 1;
                     }
                     successful_operations += is_operation_possible(
                         operands.clone(),
@@ -82,11 +66,11 @@ fn is_operation_possible(
                     );
                 }
                 Operation::Multiplication => {
-                    let result: u64 = accumulator * r;
-                    if operands.is_empty() {
-                        if result == solution {
-                            successful_operations += 1;
-                        }
+                    let Some(result) = accumulator.checked_mul(r) else {
+                        continue;
+                    };
+                    if operands.is_empty() && result == solution {
+                        successful_operations += 1;
                     }
                     successful_operations += is_operation_possible(
                         operands.clone(),
@@ -96,12 +80,12 @@ fn is_operation_possible(
                     );
                 }
                 Operation::Concatenation => {
-                    let result: u64 = format!("{}{}", accumulator, r).parse::<u64>().unwrap();
+                    let Ok(result) = format!("{}{}", accumulator, r).parse::<u64>() else {
+                        continue;
+                    };
 
-                    if operands.is_empty() {
-                        if result == solution {
-                            successful_operations += 1;
-                        }
+                    if operands.is_empty() && result == solution {
+                        successful_operations += 1;
                     }
 
                     successful_operations += is_operation_possible(
@@ -118,6 +102,33 @@ fn is_operation_possible(
     successful_operations
 }
 
+#[derive(Args)]
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    type Input = Equations;
+
+    fn parse(&self, input: &str) -> Result<Equations> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Equations) -> Result<Answer> {
+        Ok(solve(input, &vec![Operation::Addition, Operation::Multiplication]).into())
+    }
+
+    fn part_two(&self, input: &Equations) -> Result<Answer> {
+        Ok(solve(
+            input,
+            &vec![
+                Operation::Addition,
+                Operation::Multiplication,
+                Operation::Concatenation,
+            ],
+        )
+        .into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
@@ -187,7 +198,7 @@ enum Operation {
 }
 
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     solution: u64,
     operators: VecDeque<u64>,
 }
@@ -1,22 +1,15 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(parse_input(input.as_str())),
-        _ => 0,
-    }
-}
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
 fn solve_part_one(mut filesystem: Vec<String>) -> u64 {
     let mut head: usize = 0;
-    let mut tail: usize = filesystem.len() - 1;
+    let mut tail: usize = filesystem.len().saturating_sub(1);
 
     while head < tail {
         let head_str: String = filesystem[head].clone();
         if head_str == "." {
-            let mut tail_str: String = filesystem[tail].clone();
+            let tail_str: String = filesystem[tail].clone();
             match tail_str == "." {
                 true => {
                     tail -= 1;
@@ -36,7 +29,7 @@ fn solve_part_one(mut filesystem: Vec<String>) -> u64 {
         .enumerate()
         .map(|(idx, x)| {
             if *x != "." {
-                if let Some(file_id) = x.parse::<u64>().ok() {
+                if let Ok(file_id) = x.parse::<u64>() {
                     return file_id * idx as u64;
                 }
             }
@@ -46,6 +39,25 @@ fn solve_part_one(mut filesystem: Vec<String>) -> u64 {
         .sum::<u64>()
 }
 
+#[derive(Args)]
+pub struct DayNine;
+
+impl Solution for DayNine {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_one(input.clone()).into())
+    }
+
+    fn part_two(&self, _input: &Vec<String>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
+
 #[cfg(test)]
 mod parse_input_tests {
     use super::parse_input;
@@ -53,7 +65,7 @@ mod parse_input_tests {
     #[test]
     fn simple() {
         assert_eq!(
-            parse_input(&"12345"),
+            parse_input("12345").unwrap(),
             vec!["0", ".", ".", "1", "1", "1", ".", ".", ".", ".", "2", "2", "2", "2", "2",]
         )
     }
@@ -61,7 +73,7 @@ mod parse_input_tests {
     #[test]
     fn first_example() {
         assert_eq!(
-            parse_input(&"2333133121414131402"),
+            parse_input("2333133121414131402").unwrap(),
             vec![
                 "0", "0", ".", ".", ".", "1", "1", "1", ".", ".", ".", "2", ".", ".", ".", "3",
                 "3", "3", ".", "4", "4", ".", "5", "5", "5", "5", ".", "6", "6", "6", "6", ".",
@@ -71,31 +83,50 @@ mod parse_input_tests {
     }
 }
 
-fn parse_input(input: &str) -> Vec<String> {
+// A single line of digits, alternating between the length of a file and the
+// length of the free space after it
+fn parse_input(input: &str) -> Result<Vec<String>> {
     let mut file_id: u64 = 0;
     let mut result: Vec<String> = vec![];
 
-    for (idx, c) in input.chars().enumerate() {
+    let mut lines = numbered_lines(input).filter(|(_, line)| !line.trim().is_empty());
+    let Some((line_number, disk_map)) = lines.next() else {
+        return Err(Error::parse(1, "expected a disk map"));
+    };
+    if let Some((line_number, line)) = lines.next() {
+        return Err(Error::parse(
+            line_number,
+            format!("expected a single disk map, found {:?}", line),
+        ));
+    }
+
+    for (idx, c) in disk_map.trim_end().chars().enumerate() {
+        let Some(file_block_size) = c.to_digit(10) else {
+            return Err(Error::parse_at(
+                line_number,
+                idx + 1,
+                format!("expected a digit, found {:?}", c),
+            ));
+        };
+
         // If index is even, it's a file add n chars to the vector
-        if let Some(file_block_size) = c.to_digit(10) {
-            if idx % 2 == 0 {
-                result.append(
-                    &mut (0..file_block_size)
-                        .map(|_| file_id.to_string())
-                        .collect::<Vec<String>>(),
-                );
-                file_id += 1;
-            } else {
-                result.append(
-                    &mut (0..file_block_size)
-                        .map(|_| ".".to_string())
-                        .collect::<Vec<String>>(),
-                );
-            }
+        if idx % 2 == 0 {
+            result.append(
+                &mut (0..file_block_size)
+                    .map(|_| file_id.to_string())
+                    .collect::<Vec<String>>(),
+            );
+            file_id += 1;
+        } else {
+            result.append(
+                &mut (0..file_block_size)
+                    .map(|_| ".".to_string())
+                    .collect::<Vec<String>>(),
+            );
         }
     }
 
-    result
+    Ok(result)
 }
 
 #[cfg(test)]
@@ -104,23 +135,14 @@ mod solve_part_one_tests {
 
     #[test]
     fn simple() {
-        assert_eq!(solve_part_one(parse_input(&"12345")), 60)
+        assert_eq!(solve_part_one(parse_input("12345").unwrap()), 60)
     }
 
     #[test]
     fn first_example() {
-        assert_eq!(solve_part_one(parse_input(&"2333133121414131402")), 1928)
-    }
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+        assert_eq!(
+            solve_part_one(parse_input("2333133121414131402").unwrap()),
+            1928
+        )
     }
-
-    0
 }
@@ -1,7 +1,116 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use clap::builder::{Str, TypedValueParser};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
+use advent_of_code::memo::memoize;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use log::debug;
+
+fn blink(value: &String) -> Vec<String> {
+    // If the stone is engraved with the number 0,
+    //   it is replaced by a stone engraved with the number 1.
+    // If the stone is engraved with a number that has an even number of digits,
+    //   it is replaced by two stones.
+    //   The left half of the digits are engraved on the new left stone,
+    //   and the right half of the digits are engraved on the new right stone.
+    //   (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
+    // If none of the other rules apply, the stone is replaced by a new stone;
+    //   the old stone's number multiplied by 2024 is engraved on the new stone.
+    if value == "0" {
+        vec![String::from('1')]
+    } else if value.len().is_multiple_of(2) {
+        let (left, right) = value.split_at(value.len() / 2);
+        vec![
+            left.parse::<u64>().unwrap().to_string(),
+            right.parse::<u64>().unwrap().to_string(),
+        ]
+    } else {
+        vec![(value.parse::<u64>().unwrap() * 2024).to_string()]
+    }
+}
+
+fn solve_part_one(stones: Vec<String>, iterations: usize) -> u64 {
+    let mut starting_stones: Vec<String> = stones.clone();
+    let mut updated_stones: Vec<String> = vec![];
+    debug!("Initial arrangement: \n{:?}", starting_stones);
+
+    for iteration in 0..iterations {
+        let mut insert_index: usize = 0;
+        for stone in &starting_stones {
+            for updated_stone in blink(stone).iter() {
+                updated_stones.insert(insert_index, updated_stone.clone());
+                insert_index += 1;
+            }
+        }
+
+        starting_stones = updated_stones.clone();
+        updated_stones.clear();
+
+        debug!("After {} blink \n{:?}", iteration + 1, starting_stones);
+    }
+
+    starting_stones.len() as u64
+}
+
+// Counts stones by what each one becomes rather than tracking the row, since
+// stones never affect each other and the same numbers come up again and again
+fn count_stones(stones: &[String], blinks: usize) -> u64 {
+    let mut count = memoize(
+        |count: &mut dyn FnMut((String, usize)) -> u64, (stone, blinks): (String, usize)| {
+            if blinks == 0 {
+                return 1;
+            }
+
+            blink(&stone)
+                .into_iter()
+                .map(|next| count((next, blinks - 1)))
+                .sum()
+        },
+    );
+
+    let total = stones
+        .iter()
+        .map(|stone| count.call((stone.clone(), blinks)))
+        .sum();
+    debug!("Stone counts cached: {}", count.stats());
+
+    total
+}
+
+// The numbers engraved on the stones, separated by spaces. Numbers that fit in
+// a u32 never grow past a u64 however often the stones blink.
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    let mut stones: Vec<String> = vec![];
+    for (line_number, line) in numbered_lines(input) {
+        let mut parser = Parser::new(line_number, line);
+        while !parser.rest().trim().is_empty() {
+            stones.push(parser.number::<u32>()?.to_string());
+        }
+    }
+
+    if stones.is_empty() {
+        return Err(Error::parse(1, "expected at least one stone"));
+    }
+
+    Ok(stones)
+}
+
+#[derive(Args)]
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve_part_one(input.clone(), 25).into())
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(count_stones(input, 75).into())
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -48,71 +157,11 @@ mod test {
             55312
         )
     }
-}
-
-fn blink(value: &String) -> Vec<String> {
-    // If the stone is engraved with the number 0,
-    //   it is replaced by a stone engraved with the number 1.
-    // If the stone is engraved with a number that has an even number of digits,
-    //   it is replaced by two stones.
-    //   The left half of the digits are engraved on the new left stone,
-    //   and the right half of the digits are engraved on the new right stone.
-    //   (The new numbers don't keep extra leading zeroes: 1000 would become stones 10 and 0.)
-    // If none of the other rules apply, the stone is replaced by a new stone;
-    //   the old stone's number multiplied by 2024 is engraved on the new stone.
-    if value == "0" {
-        vec![String::from('1')]
-    } else if value.len() % 2 == 0 {
-        let (left, right) = value.split_at(value.len() / 2);
-        vec![
-            left.parse::<u64>().unwrap().to_string(),
-            right.parse::<u64>().unwrap().to_string(),
-        ]
-    } else {
-        vec![(value.parse::<u64>().unwrap() * 2024).to_string()]
-    }
-}
-
-fn solve_part_one(stones: Vec<String>, iterations: usize) -> u64 {
-    let mut starting_stones: Vec<String> = stones.clone();
-    let mut updated_stones: Vec<String> = vec![];
-    debug!("Initial arrangement: \n{:?}", starting_stones);
-
-    let mut insert_index: usize = 0;
-
-    for iteration in 0..iterations {
-        insert_index = 0;
-        for i in 0..starting_stones.len() {
-            let stone: String = starting_stones[i].clone();
-
-            for (j, updated_stone) in blink(&stone).iter().enumerate() {
-                updated_stones.insert(insert_index, updated_stone.clone());
-                insert_index += 1;
-            }
-        }
-
-        starting_stones = updated_stones.clone();
-        updated_stones.clear();
-
-        debug!("After {} blink \n{:?}", iteration + 1, starting_stones);
-    }
-
-    starting_stones.len() as u64
-}
 
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(
-            input
-                .split_whitespace()
-                .map(|s| String::from(s))
-                .collect::<Vec<String>>(),
-            25,
-        ),
-        _ => 0,
+    #[test]
+    fn count_stones_matches_simulation() {
+        let stones = vec!["125".to_string(), "17".to_string()];
+        assert_eq!(count_stones(&stones, 6), 22);
+        assert_eq!(count_stones(&stones, 25), 55312);
     }
 }
-
-pub fn part_two(input_path: &Path) -> u64 {
-    0
-}
@@ -1,26 +1,6 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use regex::Regex;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u32 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(parse_input(input.as_str())),
-        _ => u32::MAX,
-    }
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
-
-    0
-}
+use advent_of_code::parse::{blocks, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
 type Prize = (u32, u32);
 type Button = (u32, u32);
@@ -28,7 +8,7 @@ type Button = (u32, u32);
 type Cost = (u32, u32);
 
 #[derive(Debug)]
-struct Machine {
+pub struct Machine {
     button_a: Button,
     button_b: Button,
     prize: Prize,
@@ -44,33 +24,68 @@ impl Machine {
     }
 }
 
-type Machines = Vec<Machine>;
-
-fn parse_input(input: &str) -> Machines {
-    let regex = Regex::new(r"(?m)Button A: X\+(?<ax>\d+),\sY\+(?<ay>\d+)\nButton B: X\+(?<bx>\d+),\sY\+(?<by>\d+)\nPrize: X=(?<x_goal>\d+),\sY=(?<y_goal>\d+)").unwrap();
-
-    regex
-        .captures_iter(input)
-        .map(|capture| {
-            Machine::new(
-                (
-                    capture["ax"].parse::<u32>().unwrap(),
-                    capture["ay"].parse::<u32>().unwrap(),
-                ),
-                (
-                    capture["bx"].parse::<u32>().unwrap(),
-                    capture["by"].parse::<u32>().unwrap(),
-                ),
-                (
-                    capture["x_goal"].parse::<u32>().unwrap(),
-                    capture["y_goal"].parse::<u32>().unwrap(),
-                ),
-            )
+pub type Machines = Vec<Machine>;
+
+// Blocks of three lines, like
+//   Button A: X+94, Y+34
+//   Button B: X+22, Y+67
+//   Prize: X=8400, Y=5400
+// Button moves are read as u16s so a hundred presses cannot overflow.
+fn parse_input(input: &str) -> Result<Machines> {
+    let blocks = blocks(input);
+    if blocks.is_empty() {
+        return Err(Error::parse(1, "expected at least one claw machine"));
+    }
+
+    blocks
+        .iter()
+        .map(|block| {
+            let [button_a, button_b, prize] = block.lines[..] else {
+                return Err(Error::parse(
+                    block.line,
+                    format!(
+                        "expected two buttons and a prize, found {} lines",
+                        block.lines.len()
+                    ),
+                ));
+            };
+
+            Ok(Machine::new(
+                parse_button(block.line, button_a, "A")?,
+                parse_button(block.line + 1, button_b, "B")?,
+                parse_prize(block.line + 2, prize)?,
+            ))
         })
-        .collect::<Machines>()
+        .collect()
+}
+
+fn parse_button(line_number: usize, line: &str, name: &str) -> Result<Button> {
+    let mut parser = Parser::new(line_number, line);
+    parser.literal(&format!("Button {}:", name))?;
+    parser.literal("X+")?;
+    let x = parser.number::<u16>()?;
+    parser.literal(",")?;
+    parser.literal("Y+")?;
+    let y = parser.number::<u16>()?;
+    parser.end()?;
+
+    Ok((x as u32, y as u32))
 }
 
-fn solve_part_one(machines: Machines) -> u32 {
+fn parse_prize(line_number: usize, line: &str) -> Result<Prize> {
+    let mut parser = Parser::new(line_number, line);
+    parser.literal("Prize:")?;
+    parser.literal("X=")?;
+    let x = parser.number()?;
+    parser.literal(",")?;
+    parser.literal("Y=")?;
+    let y = parser.number()?;
+    parser.end()?;
+
+    Ok((x, y))
+}
+
+fn solve_part_one(machines: &Machines) -> u32 {
     machines
         .iter()
         .map(|m| determine_minimum_tokens_to_win_prize(m.prize, m.button_a, m.button_b))
@@ -85,7 +100,6 @@ fn determine_minimum_tokens_to_win_prize(
     button_b: Button,
 ) -> Option<Cost> {
     let mut cost: Option<Cost> = None;
-    let mut total_cost: u32 = 0;
     let mut min_cost: u32 = u32::MAX;
 
     for (a_pushes, b_pushes) in possible_button_pushes(prize.0, button_a.0, button_b.0, 100) {
@@ -94,7 +108,7 @@ fn determine_minimum_tokens_to_win_prize(
 
         if result == target {
             let local_cost: Cost = (a_pushes * 3, b_pushes);
-            total_cost = local_cost.0 + local_cost.1;
+            let total_cost: u32 = local_cost.0 + local_cost.1;
 
             if total_cost < min_cost {
                 cost = Some(local_cost);
@@ -112,24 +126,20 @@ fn possible_button_pushes(
     b_increment: u32,
     max_pushes: u32,
 ) -> Vec<(u32, u32)> {
-    let mut result: u32 = 0;
-    let mut a_result: u32 = 0;
-    let mut b_result: u32 = 0;
-
     let mut possible_push_counts: Vec<(u32, u32)> = vec![];
 
     for i in 1..=max_pushes {
-        a_result = a_increment * i;
+        let a_result: u32 = a_increment * i;
         match a_result >= target {
             true => {}
             false => {
                 for j in 1..=max_pushes {
-                    b_result = b_increment * j;
+                    let b_result: u32 = b_increment * j;
 
                     match b_result > target {
                         true => {}
                         false => {
-                            result = a_result + b_result;
+                            let result: u32 = a_result + b_result;
                             if result == target {
                                 possible_push_counts.push((i, j));
                             }
@@ -143,6 +153,25 @@ fn possible_button_pushes(
     possible_push_counts
 }
 
+#[derive(Args)]
+pub struct DayThirteen;
+
+impl Solution for DayThirteen {
+    type Input = Machines;
+
+    fn parse(&self, input: &str) -> Result<Machines> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Machines) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, _input: &Machines) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -186,7 +215,7 @@ mod tests {
         ];
 
         let expected: u32 = 480;
-        let got: u32 = solve_part_one(machines);
+        let got: u32 = solve_part_one(&machines);
 
         assert_eq!(got, expected);
     }
@@ -1,30 +1,10 @@
-use advent_of_code::{read_file_to_string, read_lines, Coordinate};
-use log::{debug, log_enabled, Level};
-use regex::Regex;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> i32 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(input.as_str(), 100, 101, 103),
-        _ => 0,
-    }
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
-
-    0
-}
 
 type Velocity = (i32, i32);
-struct Robot {
+pub struct Robot {
     coordinate: Coordinate,
     velocity: Velocity,
 }
@@ -38,15 +18,15 @@ impl Robot {
     }
 }
 
-type Robots = Vec<Robot>;
+pub type Robots = Vec<Robot>;
 
-fn solve_part_one(input: &str, seconds: i32, x_tiles: i32, y_tiles: i32) -> i32 {
+fn solve_part_one(robots: &Robots, seconds: i32, x_tiles: i32, y_tiles: i32) -> i32 {
     let mut quadrants: HashMap<usize, i32> = HashMap::from([(1, 0), (2, 0), (3, 0), (4, 0)]);
 
     let x_midpoint = x_tiles / 2;
     let y_midpoint = y_tiles / 2;
 
-    parse_input(input)
+    robots
         .iter()
         .map(|r| update_position(r, seconds, x_tiles, y_tiles))
         .filter(|c| c.x != x_midpoint && c.y != y_midpoint)
@@ -89,11 +69,7 @@ fn solve_part_one(input: &str, seconds: i32, x_tiles: i32, y_tiles: i32) -> i32
             }
         });
 
-    quadrants
-        .values()
-        .map(|i| i.clone())
-        .reduce(|acc, e| acc * e)
-        .unwrap()
+    quadrants.values().copied().product()
 }
 
 fn update_position(robot: &Robot, seconds: i32, x_tiles: i32, y_tiles: i32) -> Coordinate {
@@ -103,24 +79,53 @@ fn update_position(robot: &Robot, seconds: i32, x_tiles: i32, y_tiles: i32) -> C
     )
 }
 
-fn parse_input(input: &str) -> Robots {
-    let regex = Regex::new(r"(?m)p=(?<x>\d+),(?<y>\d+)\s+v=(?<vx>-?\d+),(?<vy>-?\d+)").unwrap();
-
-    regex
-        .captures_iter(input)
-        .map(|capture| {
-            Robot::new(
-                Coordinate::new(
-                    capture["x"].parse::<i32>().unwrap(),
-                    capture["y"].parse::<i32>().unwrap(),
-                ),
-                (
-                    capture["vx"].parse::<i32>().unwrap(),
-                    capture["vy"].parse::<i32>().unwrap(),
-                ),
-            )
+// Each line is a robot's position and velocity, like "p=0,4 v=3,-3". Positions
+// are read as u16s and velocities as i16s so a hundred seconds cannot overflow.
+fn parse_input(input: &str) -> Result<Robots> {
+    let robots = numbered_lines(input)
+        .map(|(line_number, line)| {
+            let mut parser = Parser::new(line_number, line);
+            parser.literal("p=")?;
+            let x = parser.number::<u16>()?;
+            parser.literal(",")?;
+            let y = parser.number::<u16>()?;
+            parser.literal("v=")?;
+            let vx = parser.number::<i16>()?;
+            parser.literal(",")?;
+            let vy = parser.number::<i16>()?;
+            parser.end()?;
+
+            Ok(Robot::new(
+                Coordinate::new(x as i32, y as i32),
+                (vx as i32, vy as i32),
+            ))
         })
-        .collect::<Robots>()
+        .collect::<Result<Robots>>()?;
+
+    if robots.is_empty() {
+        return Err(Error::parse(1, "expected at least one robot"));
+    }
+
+    Ok(robots)
+}
+
+#[derive(Args)]
+pub struct DayFourteen;
+
+impl Solution for DayFourteen {
+    type Input = Robots;
+
+    fn parse(&self, input: &str) -> Result<Robots> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Robots) -> Result<Answer> {
+        Ok(solve_part_one(input, 100, 101, 103).into())
+    }
+
+    fn part_two(&self, _input: &Robots) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -152,7 +157,7 @@ p=2,4 v=2,-3
 p=9,5 v=-3,-3";
 
         let expected: i32 = 12;
-        let got: i32 = solve_part_one(input, 100, 11, 7);
+        let got: i32 = solve_part_one(&parse_input(input).unwrap(), 100, 11, 7);
 
         assert_eq!(got, expected);
     }
@@ -1,17 +1,9 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use regex::Regex;
-use std::path::Path;
+use advent_of_code::parse::{blocks, key_list, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> String {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(input.as_str()),
-        _ => String::from(""),
-    }
-}
-
-fn solve_part_one(input: &str) -> String {
-    let mut c = parse_input(input);
+fn solve_part_one(computer: &Computer) -> String {
+    let mut c = computer.clone();
 
     c.execute_instructions();
 
@@ -27,8 +19,8 @@ type Operand = u64;
 type Instruction = u64;
 type Instructions = Vec<Instruction>;
 
-#[derive(Debug, PartialEq)]
-struct Computer {
+#[derive(Clone, Debug, PartialEq)]
+pub struct Computer {
     register_a: Register,
     register_b: Register,
     register_c: Register,
@@ -73,21 +65,19 @@ impl Computer {
                             }
                         }
                     }
-                    1 => self.register_b = self.register_b ^ o,
+                    1 => self.register_b ^= o,
                     2 => {
                         if let Some(c) = self.get_combo_operand(o) {
                             self.register_b = c.rem_euclid(8);
                         }
                     }
-                    3 => {
-                        if self.register_a != 0 {
-                            self.instruction_pointer = o as usize;
-                            instruction = self.instructions.get(self.instruction_pointer);
-                            operand = self.instructions.get(self.instruction_pointer + 1);
-                            continue;
-                        }
+                    3 if self.register_a != 0 => {
+                        self.instruction_pointer = o as usize;
+                        instruction = self.instructions.get(self.instruction_pointer);
+                        operand = self.instructions.get(self.instruction_pointer + 1);
+                        continue;
                     }
-                    4 => self.register_b = self.register_b ^ self.register_c,
+                    4 => self.register_b ^= self.register_c,
                     5 => {
                         if let Some(c) = self.get_combo_operand(o) {
                             self.output.push(c.rem_euclid(8));
@@ -105,7 +95,7 @@ impl Computer {
 
     fn get_combo_operand(&self, operand: Operand) -> Option<u64> {
         match operand {
-            0 | 1 | 2 | 3 => Some(operand as u64),
+            0..=3 => Some(operand),
             4 => Some(self.register_a),
             5 => Some(self.register_b),
             6 => Some(self.register_c),
@@ -118,26 +108,57 @@ impl Computer {
     }
 }
 
-fn parse_input(input: &str) -> Computer {
-    let regex = Regex::new(r"(?m)Register\s+A:\s+(?<a>\d+)\nRegister\s+B:\s+(?<b>\d+)\nRegister\s+C:\s+(?<c>\d+)\n\nProgram:\s+(?<program>.+)").unwrap();
-
-    let c = regex
-        .captures_iter(input)
-        .map(|capture| {
-            Computer::new(
-                capture["a"].parse().unwrap(),
-                capture["b"].parse().unwrap(),
-                capture["c"].parse().unwrap(),
-                capture["program"]
-                    .split(",")
-                    .map(|s| s.parse::<u64>().unwrap())
-                    .collect::<Vec<_>>(),
-            )
-        })
-        .nth(0)
-        .unwrap();
-
-    c
+fn parse_input(input: &str) -> Result<Computer> {
+    let blocks = blocks(input);
+    let [registers, program] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            "expected the registers and the program separated by a blank line",
+        ));
+    };
+
+    if registers.lines.len() != 3 {
+        return Err(Error::parse(
+            registers.line,
+            "expected registers A, B and C",
+        ));
+    }
+
+    let mut values: Vec<Register> = vec![];
+    for ((line_number, line), name) in registers.numbered_lines().zip(["A", "B", "C"]) {
+        let mut parser = Parser::new(line_number, line);
+        parser.literal("Register")?;
+        parser.literal(name)?;
+        parser.literal(":")?;
+        values.push(parser.number()?);
+        parser.end()?;
+    }
+
+    let (key, instructions) = key_list::<Instruction>(program.line, program.lines[0])?;
+    if key != "Program" || program.lines.len() != 1 {
+        return Err(Error::parse(program.line, "expected a single Program line"));
+    }
+
+    Ok(Computer::new(values[0], values[1], values[2], instructions))
+}
+
+#[derive(Args)]
+pub struct DaySeventeen;
+
+impl Solution for DaySeventeen {
+    type Input = Computer;
+
+    fn parse(&self, input: &str) -> Result<Computer> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Computer) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, _input: &Computer) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -154,7 +175,7 @@ Program: 0,1,5,4,3,0";
 
         let expected = Computer::new(729, 0, 0, vec![0, 1, 5, 4, 3, 0]);
 
-        let got = parse_input(input);
+        let got = parse_input(input).unwrap();
 
         assert_eq!(got, expected);
     }
@@ -167,7 +188,7 @@ Register C: 0
 
 Program: 0,1,5,4,3,0";
         let expected = "4,6,3,5,6,3,5,2,1,0";
-        let got = solve_part_one(input);
+        let got = solve_part_one(&parse_input(input).unwrap());
 
         assert_eq!(got, expected);
     }
@@ -224,15 +245,3 @@ Program: 0,1,5,4,3,0";
         assert_eq!(computer.register_b, expected_register_b);
     }
 }
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
-
-    0
-}
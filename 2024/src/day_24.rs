@@ -1,94 +1,120 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
-
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(input.as_str()),
-        _ => 0,
-    }
-}
+use advent_of_code::graph::Graph;
+use advent_of_code::memo::memoize;
+use advent_of_code::parse::{blocks, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+use std::collections::HashMap;
 
-fn solve_part_one(input: &str) -> u64 {
-    let mut wires: Wires = parse_input(input);
-    let binding = wires.clone();
-    let mut keys = binding.keys().collect::<Vec<_>>();
+fn solve_part_one(wires: &Wires) -> u64 {
+    let mut keys = wires.keys().collect::<Vec<_>>();
     keys.sort();
 
+    // Gates share inputs, so each wire's value is worked out once
+    let mut wire_value = memoize(|wire_value: &mut dyn FnMut(String) -> bool, name: String| {
+        determine_wire_value(&wires[&name], wire_value)
+    });
+
     let test = keys
         .into_iter()
         .filter(|&k| k.starts_with("z"))
-        .map(|s| s.as_str())
-        .collect::<Vec<_>>()
-        .iter()
         .rev()
-        .map(
-            |i| match determine_wire_value(String::from(*i), &mut wires) {
-                true => String::from("1"),
-                false => String::from("0"),
-            },
-        )
+        .map(|i| match wire_value.call(i.clone()) {
+            true => String::from("1"),
+            false => String::from("0"),
+        })
         .collect::<Vec<String>>()
         .join("");
 
+    // Parsing allows between 1 and 64 z wires, so the bits always fit
     u64::from_str_radix(test.as_str(), 2).unwrap()
 }
 
-fn determine_wire_value(name: String, wires: &mut Wires) -> bool {
-    let wire = wires.get_mut(&name).unwrap().clone();
-
+fn determine_wire_value(wire: &Wire, wire_value: &mut dyn FnMut(String) -> bool) -> bool {
     match wire.value {
         Some(v) => v,
         None => {
-            let left_wire_value = determine_wire_value(wire.left_operand.unwrap(), wires);
-            let right_wire_value = determine_wire_value(wire.right_operand.unwrap(), wires);
-
-            match wire.operation.unwrap() {
-                LogicOperation::AND => left_wire_value && right_wire_value,
-                LogicOperation::OR => left_wire_value || right_wire_value,
-                LogicOperation::XOR => left_wire_value ^ right_wire_value,
-                _ => false,
+            let left_wire_value = wire_value(wire.left_operand.clone().unwrap());
+            let right_wire_value = wire_value(wire.right_operand.clone().unwrap());
+
+            match wire.operation.clone().unwrap() {
+                LogicOperation::And => left_wire_value && right_wire_value,
+                LogicOperation::Or => left_wire_value || right_wire_value,
+                LogicOperation::Xor => left_wire_value ^ right_wire_value,
             }
         }
     }
 }
 
-fn parse_input(input: &str) -> Wires {
+// Initial values like "x00: 1", a blank line, then gates like
+// "x00 AND y00 -> z00". Every wire a gate reads must be defined, and no gate
+// may depend on its own output.
+fn parse_input(input: &str) -> Result<Wires> {
     let mut wires: Wires = HashMap::new();
 
-    let split_input = input.split("\n\n").collect::<Vec<_>>();
+    let blocks = blocks(input);
+    let [initial_values, gates] = &blocks[..] else {
+        return Err(Error::parse(
+            1,
+            format!(
+                "expected initial values and gates, found {} sections",
+                blocks.len()
+            ),
+        ));
+    };
+
+    let mut define = |line_number: usize, wire: Wire| {
+        if wires.contains_key(&wire.name) {
+            return Err(Error::parse(
+                line_number,
+                format!("wire {:?} is defined twice", wire.name),
+            ));
+        }
+        wires.insert(wire.name.clone(), wire);
+
+        Ok(())
+    };
 
-    split_input[0].lines().for_each(|l| {
-        let split_line = l.split(": ").collect::<Vec<_>>();
-        let name = split_line[0].to_string();
-        let value = match split_line[1] {
-            "1" => true,
-            _ => false,
+    for (line_number, line) in initial_values.numbered_lines() {
+        let mut parser = Parser::new(line_number, line);
+        let name = parser.word()?.to_string();
+        parser.literal(":")?;
+        let value = match parser.number::<u8>()? {
+            0 => false,
+            1 => true,
+            other => return Err(parser.error(format!("expected 0 or 1, found {}", other))),
         };
+        parser.end()?;
 
-        wires.insert(
-            name.clone(),
+        define(
+            line_number,
             Wire::new(name.clone(), Some(value), None, None, None),
-        );
-    });
+        )?;
+    }
 
-    split_input[1].lines().for_each(|l| {
-        let split_for_output = l.split(" -> ").collect::<Vec<_>>();
-        let name = split_for_output[1].to_string();
-        let split_for_logic = split_for_output[0].split(" ").collect::<Vec<_>>();
-
-        let left_operand = split_for_logic[0].to_string();
-        let right_operand = split_for_logic[2].to_string();
-        let operation = match split_for_logic[1] {
-            "AND" => LogicOperation::AND,
-            "OR" => LogicOperation::OR,
-            "XOR" => LogicOperation::XOR,
-            _ => LogicOperation::Unknown,
+    let mut graph = Graph::directed();
+    for (line_number, line) in gates.numbered_lines() {
+        let mut parser = Parser::new(line_number, line);
+        let left_operand = parser.word()?.to_string();
+        let operation = match parser.word()? {
+            "AND" => LogicOperation::And,
+            "OR" => LogicOperation::Or,
+            "XOR" => LogicOperation::Xor,
+            other => {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected AND, OR or XOR, found {:?}", other),
+                ))
+            }
         };
+        let right_operand = parser.word()?.to_string();
+        parser.literal("->")?;
+        let name = parser.word()?.to_string();
+        parser.end()?;
 
-        wires.insert(
-            name.clone(),
+        graph.add_edge(&left_operand, &name);
+        graph.add_edge(&right_operand, &name);
+        define(
+            line_number,
             Wire::new(
                 name.clone(),
                 None,
@@ -96,22 +122,42 @@ fn parse_input(input: &str) -> Wires {
                 Some(right_operand),
                 Some(operation),
             ),
-        );
-    });
+        )?;
+    }
+
+    for id in graph.nodes() {
+        let name = graph.name(id);
+        if !wires.contains_key(name) {
+            return Err(Error::parse(
+                gates.line,
+                format!("wire {:?} is read but never defined", name),
+            ));
+        }
+    }
+    graph
+        .topological_sort()
+        .map_err(|err| Error::parse(gates.line, err.to_string()))?;
 
-    wires
+    let outputs = wires.keys().filter(|name| name.starts_with("z")).count();
+    if outputs == 0 || outputs > 64 {
+        return Err(Error::parse(
+            gates.line,
+            format!("expected between 1 and 64 z wires, found {}", outputs),
+        ));
+    }
+
+    Ok(wires)
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone)]
 enum LogicOperation {
-    AND,
-    OR,
-    XOR,
-    Unknown,
+    And,
+    Or,
+    Xor,
 }
 
 #[derive(Hash, Eq, PartialEq, Debug, Clone)]
-struct Wire {
+pub struct Wire {
     name: String,
     value: Option<bool>,
     left_operand: Option<String>,
@@ -137,18 +183,25 @@ impl Wire {
     }
 }
 
-type Wires = HashMap<String, Wire>;
+pub type Wires = HashMap<String, Wire>;
 
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+#[derive(Args)]
+pub struct DayTwentyFour;
+
+impl Solution for DayTwentyFour {
+    type Input = Wires;
+
+    fn parse(&self, input: &str) -> Result<Wires> {
+        parse_input(input)
     }
 
-    0
+    fn part_one(&self, input: &Wires) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, _input: &Wires) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -168,7 +221,7 @@ x00 AND y00 -> z00
 x01 XOR y01 -> z01
 x02 OR y02 -> z02";
         let expected: u64 = 4;
-        let got: u64 = solve_part_one(input);
+        let got: u64 = solve_part_one(&parse_input(input).unwrap());
 
         assert_eq!(got, expected);
     }
@@ -224,7 +277,7 @@ tgd XOR rvg -> z12
 tnw OR pbm -> gnj";
 
         let expected: u64 = 2024;
-        let got: u64 = solve_part_one(input);
+        let got: u64 = solve_part_one(&parse_input(input).unwrap());
 
         assert_eq!(got, expected);
     }
@@ -1,8 +1,114 @@
-use advent_of_code::{read_file_to_string, read_lines};
-use log::{debug, log_enabled, Level};
-use std::collections::HashMap;
+use advent_of_code::parse::blocks;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::iter::zip;
-use std::path::Path;
+
+fn key_fits_lock(lock: Vec<i32>, key: Vec<i32>) -> bool {
+    zip(lock, key)
+        .map(|(l, k)| !key_code_overlaps_pin(l, k))
+        .reduce(|acc, e| acc && e)
+        .unwrap_or_default()
+}
+
+fn key_code_overlaps_pin(pin_height: i32, key_code: i32) -> bool {
+    key_code >= (pin_height - 6).abs()
+}
+
+// Keys and locks, each as the height of its five columns
+pub type Schematics = (Vec<Vec<i32>>, Vec<Vec<i32>>);
+
+// Blocks of seven rows of five '#' or '.'. Locks have a full top row and an
+// empty bottom row, keys the other way round.
+fn parse_input(input: &str) -> Result<Schematics> {
+    let mut locks: Vec<Vec<i32>> = vec![];
+    let mut keys: Vec<Vec<i32>> = vec![];
+
+    for block in blocks(input) {
+        if block.lines.len() != 7 {
+            return Err(Error::parse(
+                block.line,
+                format!("expected 7 rows, found {}", block.lines.len()),
+            ));
+        }
+
+        let mut heights = vec![0; 5];
+        for (line_number, line) in block.numbered_lines() {
+            let row = line.chars().collect::<Vec<_>>();
+            if row.len() != 5 {
+                return Err(Error::parse(
+                    line_number,
+                    format!("expected 5 columns, found {:?}", line),
+                ));
+            }
+            for (cidx, c) in row.into_iter().enumerate() {
+                match c {
+                    '#' => heights[cidx] += 1,
+                    '.' => {}
+                    _ => {
+                        return Err(Error::parse_at(
+                            line_number,
+                            cidx + 1,
+                            format!("expected '#' or '.', found {:?}", c),
+                        ))
+                    }
+                }
+            }
+        }
+
+        let (first, last) = (block.lines[0], block.lines[6]);
+        // The full row at the base of each schematic does not count
+        heights.iter_mut().for_each(|height| *height -= 1);
+        if first == "#####" && last == "....." {
+            locks.push(heights);
+        } else if first == "....." && last == "#####" {
+            keys.push(heights);
+        } else {
+            return Err(Error::parse(
+                block.line,
+                "expected a lock or a key, with one full row and one empty row at the ends",
+            ));
+        }
+    }
+
+    if locks.is_empty() && keys.is_empty() {
+        return Err(Error::parse(1, "expected at least one lock or key"));
+    }
+
+    Ok((keys, locks))
+}
+
+fn solve_part_one(schematics: &Schematics) -> u64 {
+    let (keys, locks) = schematics;
+    let mut result: u64 = 0;
+    keys.iter().for_each(|k| {
+        locks.iter().for_each(|l| {
+            if key_fits_lock(l.clone(), k.clone()) {
+                result += 1;
+            }
+        })
+    });
+
+    result
+}
+
+#[derive(Args)]
+pub struct DayTwentyFive;
+
+impl Solution for DayTwentyFive {
+    type Input = Schematics;
+
+    fn parse(&self, input: &str) -> Result<Schematics> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Schematics) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, _input: &Schematics) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -106,7 +212,7 @@ mod tests {
 
         let expected_locks = vec![vec![0, 5, 3, 4, 3], vec![1, 2, 0, 5, 3]];
 
-        let (got_keys, got_locks) = parse_input(input);
+        let (got_keys, got_locks) = parse_input(input).unwrap();
 
         assert_eq!(got_keys, expected_keys);
         assert_eq!(got_locks, expected_locks);
@@ -155,107 +261,8 @@ mod tests {
 #####";
 
         let expected: u64 = 3;
-        let got: u64 = solve_part_one(input);
+        let got: u64 = solve_part_one(&parse_input(input).unwrap());
 
         assert_eq!(got, expected);
     }
 }
-
-fn key_fits_lock(lock: Vec<i32>, key: Vec<i32>) -> bool {
-    zip(lock, key)
-        .map(|(l, k)| !key_code_overlaps_pin(l, k))
-        .reduce(|acc, e| acc && e)
-        .unwrap_or_default()
-}
-
-fn key_code_overlaps_pin(pin_height: i32, key_code: i32) -> bool {
-    key_code >= (pin_height - 6).abs()
-}
-
-fn parse_input(input: &str) -> (Vec<Vec<i32>>, Vec<Vec<i32>>) {
-    let locks_and_keys = input.split("\n\n").collect::<Vec<_>>();
-    let mut locks: Vec<Vec<i32>> = vec![];
-    let mut keys: Vec<Vec<i32>> = vec![];
-
-    let mut is_lock = false;
-    let mut working_item: HashMap<usize, i32> = HashMap::new();
-
-    locks_and_keys.iter().for_each(|l| {
-        let lines = l.lines();
-        let lines_length = lines.clone().count();
-        lines.enumerate().for_each(|(idx, line)| {
-            if idx == 0 {
-                is_lock = line == "#####";
-            } else if idx == lines_length - 1 {
-                // continue;
-            } else {
-                line.chars().enumerate().for_each(|(cidx, c)| {
-                    if c == '#' {
-                        working_item
-                            .entry(cidx)
-                            .and_modify(|counter| *counter += 1)
-                            .or_insert(1);
-                    } else {
-                        working_item.entry(cidx).or_insert(0);
-                    }
-                });
-            }
-        });
-
-        if is_lock {
-            locks.push(vec![
-                *working_item.get(&0).unwrap(),
-                *working_item.get(&1).unwrap(),
-                *working_item.get(&2).unwrap(),
-                *working_item.get(&3).unwrap(),
-                *working_item.get(&4).unwrap(),
-            ])
-        } else {
-            keys.push(vec![
-                *working_item.get(&0).unwrap(),
-                *working_item.get(&1).unwrap(),
-                *working_item.get(&2).unwrap(),
-                *working_item.get(&3).unwrap(),
-                *working_item.get(&4).unwrap(),
-            ])
-        }
-
-        working_item.clear();
-    });
-
-    (keys, locks)
-}
-
-fn solve_part_one(input: &str) -> u64 {
-    let (keys, locks) = parse_input(input);
-    let mut result: u64 = 0;
-
-    keys.iter().for_each(|k| {
-        locks.iter().for_each(|l| {
-            if key_fits_lock(l.clone(), k.clone()) {
-                result += 1;
-            }
-        })
-    });
-
-    result
-}
-
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(input.as_str()),
-        _ => 0,
-    }
-}
-
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
-    }
-
-    0
-}
@@ -1,98 +1,39 @@
-use std::cmp::Ordering;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-// read_lines from https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-pub fn read_file_to_string<P>(filename: P) -> Result<String, std::io::Error>
-where
-    P: AsRef<Path>,
-{
-    std::fs::read_to_string(filename)
-}
-
-#[derive(Clone, Copy, Ord, Eq, PartialEq, Hash, Debug)]
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Coordinate {
-    pub fn new(x: i32, y: i32) -> Coordinate {
-        Coordinate { x, y }
-    }
-
-    pub fn neighboring_cardinal_directions(&self) -> Vec<Coordinate> {
-        return Vec::from([
-            self.north_coordinate(),
-            self.south_coordinate(),
-            self.east_coordinate(),
-            self.west_coordinate(),
-        ]);
-    }
-
-    pub fn north_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x - 1, self.y)
-    }
-
-    pub fn south_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x + 1, self.y)
-    }
-
-    pub fn east_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x, self.y + 1)
-    }
-
-    pub fn west_coordinate(&self) -> Coordinate {
-        Coordinate::new(self.x, self.y - 1)
-    }
-}
-
-impl PartialOrd for Coordinate {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        if self.x == other.x {
-            return Some(self.y.cmp(&other.y));
-        }
-        Some(self.y.cmp(&other.y))
-    }
-}
-
-impl fmt::Display for Coordinate {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "x={}, y={}", self.x, self.y,)
-    }
-}
-
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
-pub enum Direction {
-    North,
-    South,
-    East,
-    West,
-    Unknown,
-}
-
-impl fmt::Display for Direction {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}",
-            match &self {
-                Direction::North => 'N',
-                Direction::South => 'S',
-                Direction::East => 'E',
-                Direction::West => 'W',
-                Direction::Unknown => '?',
-            }
-        )
-    }
+use advent_of_code::Registry;
+
+mod day_01;
+mod day_02;
+mod day_03;
+mod day_04;
+mod day_05;
+mod day_06;
+mod day_07;
+mod day_09;
+mod day_10;
+mod day_11;
+mod day_13;
+mod day_14;
+mod day_17;
+mod day_22;
+mod day_23;
+mod day_24;
+mod day_25;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<day_01::DayOne>(2024, 1);
+    registry.register::<day_02::DayTwo>(2024, 2);
+    registry.register::<day_03::DayThree>(2024, 3);
+    registry.register::<day_04::DayFour>(2024, 4);
+    registry.register::<day_05::DayFive>(2024, 5);
+    registry.register::<day_06::DaySix>(2024, 6);
+    registry.register::<day_07::DaySeven>(2024, 7);
+    registry.register::<day_09::DayNine>(2024, 9);
+    registry.register::<day_10::DayTen>(2024, 10);
+    registry.register::<day_11::DayEleven>(2024, 11);
+    registry.register::<day_13::DayThirteen>(2024, 13);
+    registry.register::<day_14::DayFourteen>(2024, 14);
+    registry.register::<day_17::DaySeventeen>(2024, 17);
+    registry.register::<day_22::DayTwentyTwo>(2024, 22);
+    registry.register::<day_23::DayTwentyThree>(2024, 23);
+    registry.register::<day_24::DayTwentyFour>(2024, 24);
+    registry.register::<day_25::DayTwentyFive>(2024, 25);
 }
@@ -1,65 +1,71 @@
-use advent_of_code::read_lines;
-use std::path::Path;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
-pub fn part_one(input_path: &Path) -> i32 {
+fn solve_part_one(rotations: &[Rotation]) -> i32 {
     let mut password = 0;
     let mut dial = Dial::new(None);
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let (dir, mut _dist) = result.split_at(1);
-                let dist;
-                if let Ok(num) = _dist.parse::<i32>() {
-                    dist = num;
-                } else {
-                    continue;
-                }
-
-                match dir {
-                    "R" => dial.rotate_right(dist),
-                    "L" => dial.rotate_left(dist),
-                    _ => continue,
-                };
-
-                if dial.index == 0 {
-                    password += 1;
-                }
-            }
+    for rotation in rotations {
+        match rotation {
+            Rotation::Right(dist) => dial.rotate_right(*dist),
+            Rotation::Left(dist) => dial.rotate_left(*dist),
+        };
+
+        if dial.index == 0 {
+            password += 1;
         }
     }
 
     password
 }
 
-pub fn part_two(input_path: &Path) -> i32 {
+fn solve_part_two(rotations: &[Rotation]) -> i32 {
     let mut password = 0;
     let mut dial = Dial::new(None);
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                let (dir, mut _dist) = result.split_at(1);
-                let dist;
-                if let Ok(num) = _dist.parse::<i32>() {
-                    dist = num;
-                } else {
-                    continue;
-                }
-                let zero_points: i32;
-
-                match dir {
-                    "R" => (_, zero_points) = dial.rotate_right(dist),
-                    "L" => (_, zero_points) = dial.rotate_left(dist),
-                    _ => continue,
-                };
-
-                password += zero_points;
-            }
-        }
+    for rotation in rotations {
+        let (_, zero_points) = match rotation {
+            Rotation::Right(dist) => dial.rotate_right(*dist),
+            Rotation::Left(dist) => dial.rotate_left(*dist),
+        };
+
+        password += zero_points;
     }
 
     password
 }
 
+fn parse_input(input: &str) -> Result<Vec<Rotation>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            if line.is_empty() {
+                return Err(Error::parse(line_number, "empty rotation"));
+            }
+
+            let (dir, dist) = line.split_at(1);
+            let dist = dist
+                .parse::<i32>()
+                .map_err(|err| Error::parse(line_number, format!("{:?}: {}", dist, err)))?;
+
+            match dir {
+                "R" => Ok(Rotation::Right(dist)),
+                "L" => Ok(Rotation::Left(dist)),
+                _ => Err(Error::parse(
+                    line_number,
+                    format!("unknown direction {:?}", dir),
+                )),
+            }
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, Eq, PartialEq, Debug)]
+pub enum Rotation {
+    Left(i32),
+    Right(i32),
+}
+
 #[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
 pub struct Dial {
     indexes: i32,
@@ -94,10 +100,8 @@ impl Dial {
             .floor()
             .abs();
 
-        if starting_index == 0 {
-            if zero_points >= 1f32 {
-                zero_points -= 1f32;
-            }
+        if starting_index == 0 && zero_points >= 1f32 {
+            zero_points -= 1f32;
         }
         self.index = ((self.index - distance) % self.indexes + self.indexes) % self.indexes;
         if self.index == 0 {
@@ -108,6 +112,25 @@ impl Dial {
     }
 }
 
+#[derive(Args)]
+pub struct DayOne;
+
+impl Solution for DayOne {
+    type Input = Vec<Rotation>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Rotation>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<Rotation>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<Rotation>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,4 +203,12 @@ mod tests {
         assert_eq!(dial.rotate_right(13), (2, 2));
         assert_eq!(dial.rotate_right(4), (0, 1));
     }
+
+    #[test]
+    fn test_parse_input_rejects_unknown_direction() {
+        assert!(matches!(
+            parse_input("L68\nU30"),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
 }
@@ -1,5 +1,9 @@
-use advent_of_code::read_file_to_string;
-use std::path::Path;
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// The first and last IDs of a range, both included
+type IdRange = (u64, u64);
 
 fn determine_invalid_ids_part_two(lower: u64, upper: u64) -> Vec<u64> {
     let mut invalid_ids: Vec<u64> = vec![];
@@ -15,7 +19,7 @@ fn determine_invalid_ids_part_two(lower: u64, upper: u64) -> Vec<u64> {
                 .filter(|s| !s.is_empty())
                 .collect::<Vec<_>>();
 
-            if remaining_strings.len() == 0 {
+            if remaining_strings.is_empty() {
                 invalid_ids.push(comp_value.parse::<u64>().unwrap());
                 break;
             }
@@ -28,11 +32,8 @@ fn determine_invalid_ids_part_two(lower: u64, upper: u64) -> Vec<u64> {
 fn determine_invalid_ids_part_one(mut lower: u64, mut upper: u64) -> Vec<u64> {
     let mut invalid_ids: Vec<u64> = vec![];
 
-    let lower_is_even = (lower.ilog10() + 1) % 2 == 0;
-    let upper_is_even = (upper.ilog10() + 1) % 2 == 0;
-
-    let upper_first_half_value: u64;
-    let lower_first_half_value: u64;
+    let lower_is_even = (lower.ilog10() + 1).is_multiple_of(2);
+    let upper_is_even = (upper.ilog10() + 1).is_multiple_of(2);
 
     if !lower_is_even && !upper_is_even {
         return invalid_ids;
@@ -56,8 +57,8 @@ fn determine_invalid_ids_part_one(mut lower: u64, mut upper: u64) -> Vec<u64> {
     let upper_string = upper.to_string();
     let (upper_first_half, upper_second_half) = upper_string.split_at(upper_string.len() / 2);
 
-    lower_first_half_value = lower_first_half.parse::<u64>().unwrap();
-    upper_first_half_value = upper_first_half.parse::<u64>().unwrap();
+    let lower_first_half_value: u64 = lower_first_half.parse::<u64>().unwrap();
+    let upper_first_half_value: u64 = upper_first_half.parse::<u64>().unwrap();
 
     let lower_second_half_value = lower_second_half.parse::<u64>().unwrap();
     let upper_second_half_value = upper_second_half.parse::<u64>().unwrap();
@@ -105,54 +106,77 @@ fn determine_invalid_ids_part_one(mut lower: u64, mut upper: u64) -> Vec<u64> {
     invalid_ids
 }
 
-fn solve_part_one(input: String) -> u64 {
+fn solve_part_one(id_ranges: &[IdRange]) -> u64 {
     let mut invalid_ids: Vec<u64> = vec![];
-    for line in input.split(",") {
-        let input_values = line
-            .split("-")
-            .map(|x| x.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
-        invalid_ids.append(&mut determine_invalid_ids_part_one(
-            input_values[0],
-            input_values[1],
-        ));
+    for &(lower, upper) in id_ranges {
+        invalid_ids.append(&mut determine_invalid_ids_part_one(lower, upper));
     }
 
     invalid_ids.iter().sum::<u64>()
 }
 
-fn solve_part_two(input: String) -> u64 {
+fn solve_part_two(id_ranges: &[IdRange]) -> u64 {
     let mut invalid_ids: Vec<u64> = vec![];
-    for line in input.split(",") {
-        let input_values = line
-            .split("-")
-            .map(|x| x.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>();
-        invalid_ids.append(&mut determine_invalid_ids_part_two(
-            input_values[0],
-            input_values[1],
-        ));
+    for &(lower, upper) in id_ranges {
+        invalid_ids.append(&mut determine_invalid_ids_part_two(lower, upper));
     }
 
     invalid_ids.iter().sum::<u64>()
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve_part_one(input)
+// Comma-separated ranges of positive IDs, like "11-22,95-115"
+fn parse_input(input: &str) -> Result<Vec<IdRange>> {
+    let mut id_ranges: Vec<IdRange> = vec![];
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let mut parser = Parser::new(line_number, line);
+        let ranges = parser.list(",", |parser| {
+            let lower: u64 = parser.number()?;
+            parser.literal("-")?;
+            let upper: u64 = parser.number()?;
+            if lower == 0 || upper < lower {
+                return Err(parser.error(format!(
+                    "expected a range of positive IDs, found {}-{}",
+                    lower, upper
+                )));
+            }
+
+            Ok((lower, upper))
+        })?;
+        parser.optional(",");
+        parser.end()?;
+
+        id_ranges.extend(ranges);
     }
 
-    result
+    if id_ranges.is_empty() {
+        return Err(Error::parse(1, "expected at least one ID range"));
+    }
+
+    Ok(id_ranges)
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve_part_two(input)
+#[derive(Args)]
+pub struct DayTwo;
+
+impl Solution for DayTwo {
+    type Input = Vec<IdRange>;
+
+    fn parse(&self, input: &str) -> Result<Vec<IdRange>> {
+        parse_input(input)
     }
 
-    result
+    fn part_one(&self, input: &Vec<IdRange>) -> Result<Answer> {
+        Ok(solve_part_one(input).into())
+    }
+
+    fn part_two(&self, input: &Vec<IdRange>) -> Result<Answer> {
+        Ok(solve_part_two(input).into())
+    }
 }
 
 #[cfg(test)]
@@ -245,7 +269,7 @@ mod tests {
     #[test]
     fn test_solve_part_one_example() {
         assert_eq!(
-            solve_part_one("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124".parse().unwrap()),
+            solve_part_one(&parse_input("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap()),
             1227775554,
         )
     }
@@ -263,7 +287,7 @@ mod tests {
     #[test]
     fn test_solve_part_two_example() {
         assert_eq!(
-            solve_part_two("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124".parse().unwrap()),
+            solve_part_two(&parse_input("11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124").unwrap()),
             4174379265,
         )
     }
@@ -1,27 +1,26 @@
-use advent_of_code::read_file_to_string;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::VecDeque;
-use std::path::Path;
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve(input, 2);
-    }
-    result
-}
-
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve(input, 12);
-    }
-    result
-}
+fn solve(banks: &[String], result_length: usize) -> Result<u64> {
+    banks
+        .iter()
+        .enumerate()
+        .map(|(index, bank)| {
+            if bank.len() < result_length {
+                return Err(Error::parse(
+                    index + 1,
+                    format!(
+                        "expected at least {} batteries, found {}",
+                        result_length,
+                        bank.len()
+                    ),
+                ));
+            }
 
-fn solve(input: String, result_length: usize) -> u64 {
-    input
-        .lines()
-        .map(|i| find_largest_joltage(i.to_string(), result_length))
+            Ok(find_largest_joltage(bank.clone(), result_length))
+        })
         .sum()
 }
 
@@ -36,14 +35,18 @@ fn find_largest_joltage(bank: String, result_length: usize) -> u64 {
     let mut max_idx: usize = 0;
 
     while result_vec.len() != result_length {
-        let mut val: u64;
         max = 0;
 
-        for i in max_idx..=bank_vec.len() - (result_length - result_vec.len()) {
-            val = bank_vec[i];
-
-            if val > max {
-                max = val;
+        let start_idx: usize = max_idx;
+        let end_idx: usize = bank_vec.len() - (result_length - result_vec.len());
+        for (i, val) in bank_vec
+            .iter()
+            .enumerate()
+            .take(end_idx + 1)
+            .skip(start_idx)
+        {
+            if *val > max {
+                max = *val;
                 max_idx = i;
             }
         }
@@ -64,6 +67,51 @@ fn find_largest_joltage(bank: String, result_length: usize) -> u64 {
         .unwrap()
 }
 
+// Each line is a bank of batteries, one digit for each battery's joltage
+fn parse_input(input: &str) -> Result<Vec<String>> {
+    let banks = numbered_lines(input)
+        .map(|(line_number, line)| {
+            if let Some((index, c)) = line.chars().enumerate().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(Error::parse_at(
+                    line_number,
+                    index + 1,
+                    format!("expected a digit, found {:?}", c),
+                ));
+            }
+            if line.is_empty() {
+                return Err(Error::parse(line_number, "expected a bank of batteries"));
+            }
+
+            Ok(line.to_string())
+        })
+        .collect::<Result<Vec<String>>>()?;
+
+    if banks.is_empty() {
+        return Err(Error::parse(1, "expected at least one bank of batteries"));
+    }
+
+    Ok(banks)
+}
+
+#[derive(Args)]
+pub struct DayThree;
+
+impl Solution for DayThree {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve(input, 2)?.into())
+    }
+
+    fn part_two(&self, input: &Vec<String>) -> Result<Answer> {
+        Ok(solve(input, 12)?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,7 +156,10 @@ mod tests {
 234234234234278
 818181911112111";
 
-        assert_eq!(solve(input.to_string(), RESULT_LENGTH), 357,)
+        assert_eq!(
+            solve(&parse_input(input).unwrap(), RESULT_LENGTH).unwrap(),
+            357,
+        )
     }
 }
 
@@ -157,6 +208,9 @@ mod tests_part_two {
 234234234234278
 818181911112111";
 
-        assert_eq!(solve(input.to_string(), RESULT_LENGTH), 3121910778619,)
+        assert_eq!(
+            solve(&parse_input(input).unwrap(), RESULT_LENGTH).unwrap(),
+            3121910778619,
+        )
     }
 }
@@ -1,6 +1,6 @@
-use advent_of_code::{read_file_to_string, Coordinate};
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
 use std::collections::HashMap;
-use std::path::Path;
 
 fn solve(diagram: &mut Diagram) -> u64 {
     let mut result: u64 = 0;
@@ -22,7 +22,7 @@ fn solve(diagram: &mut Diagram) -> u64 {
 
             if neighboring_rolls_of_paper < 4 {
                 result += 1;
-                movable_rolls.push(coordinate.clone());
+                movable_rolls.push(*coordinate);
             }
         }
     }
@@ -48,35 +48,48 @@ fn solve_part_two(diagram: &mut Diagram) -> u64 {
     result
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve(&mut input_to_diagram(input))
-    }
+pub type Diagram = HashMap<Coordinate, char>;
 
-    result
-}
+// Rolls of paper '@' and empty floor '.'
+fn input_to_diagram(input: &str) -> Result<Diagram> {
+    let mut diagram: Diagram = Diagram::new();
+    for (x, line) in input.lines().enumerate() {
+        for (y, char) in line.chars().enumerate() {
+            if char != '@' && char != '.' {
+                return Err(Error::parse_at(
+                    x + 1,
+                    y + 1,
+                    format!("expected '@' or '.', found {:?}", char),
+                ));
+            }
+            diagram.insert(Coordinate::new(x as i32, y as i32), char);
+        }
+    }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    let mut result: u64 = 0;
-    if let Ok(input) = read_file_to_string(input_path) {
-        result = solve_part_two(&mut input_to_diagram(input))
+    if diagram.is_empty() {
+        return Err(Error::parse(1, "expected a diagram of the rolls of paper"));
     }
 
-    result
+    Ok(diagram)
 }
 
-type Diagram = HashMap<Coordinate, char>;
+#[derive(Args)]
+pub struct DayFour;
 
-fn input_to_diagram(input: String) -> Diagram {
-    let mut diagram: Diagram = Diagram::new();
-    for (x, line) in input.lines().enumerate() {
-        for (y, char) in line.chars().enumerate() {
-            diagram.insert(Coordinate::new(x as i32, y as i32), char);
-        }
+impl Solution for DayFour {
+    type Input = Diagram;
+
+    fn parse(&self, input: &str) -> Result<Diagram> {
+        input_to_diagram(input)
     }
 
-    diagram
+    fn part_one(&self, input: &Diagram) -> Result<Answer> {
+        Ok(solve(&mut input.clone()).into())
+    }
+
+    fn part_two(&self, input: &Diagram) -> Result<Answer> {
+        Ok(solve_part_two(&mut input.clone()).into())
+    }
 }
 
 #[cfg(test)]
@@ -97,7 +110,7 @@ mod part_one_tests {
 @.@.@@@.@."
             .to_string();
 
-        assert_eq!(solve(&mut input_to_diagram(input)), 13,)
+        assert_eq!(solve(&mut input_to_diagram(&input).unwrap()), 13,)
     }
 }
 
@@ -119,6 +132,6 @@ mod part_two_tests {
 @.@.@@@.@."
             .to_string();
 
-        assert_eq!(solve_part_two(&mut input_to_diagram(input)), 43,)
+        assert_eq!(solve_part_two(&mut input_to_diagram(&input).unwrap()), 43,)
     }
 }
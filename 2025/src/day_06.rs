@@ -1,106 +1,150 @@
-use advent_of_code::read_file_to_string;
-use std::collections::VecDeque;
-use std::path::Path;
-
-fn solve(input: Vec<Problem>) -> u64 {
-    input.iter().map(|x| x.fold()).sum()
+use advent_of_code::parse::{numbered_lines, Parser};
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
+
+// The problems read across the rows and read down the columns, with the line
+// of the operators for errors about the answers
+pub struct Worksheet {
+    line: usize,
+    rows: Vec<Problem>,
+    columns: Vec<Problem>,
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve(parse_input_part_one(input)),
-        Err(_) => 0,
-    }
+fn solve(input: &[Problem]) -> Option<u64> {
+    input
+        .iter()
+        .try_fold(0u64, |total, x| total.checked_add(x.fold()?))
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve(parse_input_part_two(input)),
-        Err(_) => 0,
+// The numbered lines above the operators
+type NumberLines<'a> = Vec<(usize, &'a str)>;
+
+// Splits off the last line, which holds the operators, from the numbers above
+fn split_worksheet(input: &str) -> Result<(usize, Vec<String>, NumberLines<'_>)> {
+    let mut lines: Vec<(usize, &str)> = numbered_lines(input).collect();
+    let Some((operator_line, operator_text)) = lines.pop().filter(|_| !lines.is_empty()) else {
+        return Err(Error::parse(
+            1,
+            "expected rows of numbers followed by a row of operators",
+        ));
+    };
+
+    let mut parser = Parser::new(operator_line, operator_text);
+    let mut operators: Vec<String> = vec![];
+    while !parser.rest().trim().is_empty() {
+        if parser.optional("*") {
+            operators.push("*".to_string());
+        } else if parser.optional("+") {
+            operators.push("+".to_string());
+        } else {
+            return Err(parser.error(format!(
+                "expected '*' or '+', found {:?}",
+                parser.rest().trim()
+            )));
+        }
     }
-}
 
-fn parse_input_part_one(input: String) -> Vec<Problem> {
-    let mut result: Vec<Problem> = vec![];
-    let mut input_as_single_vec: Vec<_> = vec![];
-    let line_count_zero_indexed: usize = input.lines().count() - 1;
+    Ok((operator_line, operators, lines))
+}
 
-    input
-        .lines()
-        .for_each(|x| input_as_single_vec.append(&mut x.split_whitespace().collect::<Vec<_>>()));
-    let line_length_zero_indexed: usize = input_as_single_vec
-        .iter()
-        .filter(|x| !x.parse::<u64>().is_ok())
-        .count();
+fn parse_input_part_one(input: &str) -> Result<Vec<Problem>> {
+    let (_, operators, lines) = split_worksheet(input)?;
 
-    let mut operands: Vec<u64> = vec![];
-    let mut operator: String = String::new();
-
-    for i in 0..line_length_zero_indexed {
-        operands.clear();
-        for j in 0..=line_count_zero_indexed {
-            let idx = i + (j * (line_length_zero_indexed));
-            let val = input_as_single_vec[idx];
-
-            if j == line_count_zero_indexed {
-                operator = val.to_string();
-            } else {
-                operands.push(val.parse().unwrap());
-            }
+    let mut rows: Vec<Vec<u64>> = vec![];
+    for (line_number, line) in lines {
+        let mut parser = Parser::new(line_number, line);
+        let mut row: Vec<u64> = vec![];
+        while !parser.rest().trim().is_empty() {
+            row.push(parser.number()?);
         }
-
-        result.push(Problem::new(operands.clone(), operator.clone()));
+        if row.len() != operators.len() {
+            return Err(Error::parse(
+                line_number,
+                format!(
+                    "expected {} numbers to match the operators, found {}",
+                    operators.len(),
+                    row.len()
+                ),
+            ));
+        }
+        rows.push(row);
     }
 
-    result
+    Ok(operators
+        .into_iter()
+        .enumerate()
+        .map(|(i, operator)| Problem::new(rows.iter().map(|row| row[i]).collect(), operator))
+        .collect())
 }
-fn parse_input_part_two(input: String) -> Vec<Problem> {
-    let mut result: Vec<Problem> = vec![];
-    let lines: Vec<&str> = input.lines().collect();
-    let mut operators: VecDeque<String> = lines
-        .last()
-        .unwrap()
-        .split_whitespace()
-        .map(|x| x.to_string())
-        .collect();
-    let char_count: usize = lines.first().unwrap().len();
 
+fn parse_input_part_two(input: &str) -> Result<Vec<Problem>> {
+    let (operator_line, operators, lines) = split_worksheet(input)?;
+
+    let mut digits: Vec<Vec<char>> = vec![];
+    for (line_number, line) in lines {
+        if let Some((index, c)) = line
+            .chars()
+            .enumerate()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+        {
+            return Err(Error::parse_at(
+                line_number,
+                index + 1,
+                format!("expected a digit or a space, found {:?}", c),
+            ));
+        }
+        digits.push(line.chars().collect());
+    }
+    let char_count: usize = digits.iter().map(|line| line.len()).max().unwrap_or(0);
+
+    let mut problems: Vec<Vec<u64>> = vec![];
     let mut operands: Vec<u64> = vec![];
     let mut operand: Vec<char> = vec![];
 
-    for j in 0..char_count {
-        for i in 0..(lines.len() - 1) {
-            let chars: Vec<char> = lines[i].chars().collect();
-
-            if chars[j].is_numeric() {
-                operand.push(chars[j]);
+    // Lines may be cut short where they end in spaces
+    for j in 0..=char_count {
+        for line in &digits {
+            if let Some(&c) = line.get(j).filter(|c| c.is_ascii_digit()) {
+                operand.push(c);
             }
         }
 
         if !operand.is_empty() {
-            operands.push(operand.iter().collect::<String>().parse::<u64>().unwrap())
-        } else {
-            result.push(Problem::new(
-                operands.clone(),
-                operators.pop_front().unwrap(),
-            ));
+            let text = operand.iter().collect::<String>();
+            operands.push(text.parse::<u64>().map_err(|err| {
+                Error::parse_at(
+                    operator_line - digits.len(),
+                    j + 1,
+                    format!("{:?}: {}", text, err),
+                )
+            })?);
+        } else if !operands.is_empty() {
+            problems.push(operands.clone());
             operands.clear();
         }
         operand.clear();
     }
 
-    if !operands.is_empty() {
-        result.push(Problem::new(
-            operands.clone(),
-            operators.pop_front().unwrap(),
+    if problems.len() != operators.len() {
+        return Err(Error::parse(
+            operator_line,
+            format!(
+                "expected an operator for each of {} problems, found {}",
+                problems.len(),
+                operators.len()
+            ),
         ));
     }
 
-    result
+    Ok(problems
+        .into_iter()
+        .zip(operators)
+        .map(|(operands, operator)| Problem::new(operands, operator))
+        .collect())
 }
 
 #[derive(PartialEq, Debug)]
-struct Problem {
+pub struct Problem {
     operands: Vec<u64>,
     operator: String,
 }
@@ -110,15 +154,46 @@ impl Problem {
         Problem { operands, operator }
     }
 
-    pub fn fold(&self) -> u64 {
+    // None if the answer does not fit in a u64
+    pub fn fold(&self) -> Option<u64> {
+        let mut operands = self.operands.iter().copied();
         match self.operator.as_str() {
-            "*" => self.operands.iter().fold(1, |acc, x| acc * x),
-            "+" => self.operands.iter().fold(0, |acc, x| acc + x),
-            _ => 0,
+            "*" => operands.try_fold(1u64, u64::checked_mul),
+            "+" => operands.try_fold(0u64, u64::checked_add),
+            _ => Some(0),
         }
     }
 }
 
+#[derive(Args)]
+pub struct DaySix;
+
+impl Solution for DaySix {
+    type Input = Worksheet;
+
+    fn parse(&self, input: &str) -> Result<Worksheet> {
+        let (line, _, _) = split_worksheet(input)?;
+
+        Ok(Worksheet {
+            line,
+            rows: parse_input_part_one(input)?,
+            columns: parse_input_part_two(input)?,
+        })
+    }
+
+    fn part_one(&self, input: &Worksheet) -> Result<Answer> {
+        solve(&input.rows)
+            .map(Answer::from)
+            .ok_or_else(|| Error::parse(input.line, "the grand total overflows"))
+    }
+
+    fn part_two(&self, input: &Worksheet) -> Result<Answer> {
+        solve(&input.columns)
+            .map(Answer::from)
+            .ok_or_else(|| Error::parse(input.line, "the grand total overflows"))
+    }
+}
+
 #[cfg(test)]
 mod test_part_one {
     use super::*;
@@ -135,15 +210,16 @@ mod test_part_one {
     #[test]
     fn test_parse_input() {
         let result: Vec<Problem> = parse_input_part_one(
-            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ".to_string(),
-        );
+            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ",
+        )
+        .unwrap();
 
         assert_eq!(expected_input(), result);
     }
 
     #[test]
     fn test_solve_part_one_example() {
-        assert_eq!(solve(expected_input()), 4277556);
+        assert_eq!(solve(&expected_input()), Some(4277556));
     }
 }
 
@@ -162,13 +238,14 @@ mod test_part_two {
     #[test]
     fn test_parse_input() {
         let result: Vec<Problem> = parse_input_part_two(
-            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ".to_string(),
-        );
+            "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ",
+        )
+        .unwrap();
         assert_eq!(expected_input(), result);
     }
 
     #[test]
     fn test_solve_example() {
-        assert_eq!(solve(expected_input()), 3263827);
+        assert_eq!(solve(&expected_input()), Some(3263827));
     }
 }
@@ -1,5 +1,7 @@
-use advent_of_code::read_file_to_string;
-use std::path::Path;
+use advent_of_code::memo::memoize;
+use advent_of_code::parse::numbered_lines;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 
 fn solve_part_one(input: &mut Vec<char>, start: usize, offset: usize) -> u64 {
     match input.get(start) {
@@ -23,64 +25,77 @@ fn solve_part_one(input: &mut Vec<char>, start: usize, offset: usize) -> u64 {
     }
 }
 
-fn solve_part_two(input: &mut Vec<char>, start: usize, offset: usize) -> u64 {
-    0
-    // Too slow
-    // match input.get(start) {
-    //     Some(c) => {
-    //         match c {
-    //             '.' => {
-    //                 solve_part_two(input, start + offset, offset)
-    //             }
-    //             'S' => {
-    //                 solve_part_two(input, start + offset, offset)
-    //             }
-    //             '^' => {
-    //                 solve_part_two(input, start - 1, offset) + solve_part_two(input, start + 1, offset)
-    //             }
-    //             _ => {
-    //                 0
-    //             }
-    //         }
-    //     }
-    //     None => {
-    //         1
-    //     }
-    // }
+fn solve_part_two(input: &[char], start: usize, offset: usize) -> u64 {
+    // Counts the timelines a beam at a position splits into on its way down
+    let mut count_timelines = memoize(
+        |count_timelines: &mut dyn FnMut(usize) -> u64, start: usize| match input.get(start) {
+            Some('^') => count_timelines(start - 1) + count_timelines(start + 1),
+            Some(_) => count_timelines(start + offset),
+            None => 1,
+        },
+    );
+
+    count_timelines.call(start)
 }
 
-fn parse_input(input: String) -> (Vec<char>, usize, usize) {
+// A rectangle of empty space '.', splitters '^' and a single start 'S'.
+// Splitters cannot sit on the edges, where a split beam would leave the grid.
+fn parse_input(input: String) -> Result<(Vec<char>, usize, usize)> {
     let offset: usize = match input.lines().last() {
         Some(l) => l.len(),
         None => 0,
     };
-    let result: Vec<char> = input
-        .lines()
-        .map(|l| l.chars())
-        .flatten()
-        .collect::<Vec<char>>();
-    let start: usize = result.iter().position(|c| *c == 'S').unwrap_or_else(|| 0);
-
-    (result, start, offset)
-}
+    if offset == 0 {
+        return Err(Error::parse(1, "expected a diagram of the manifold"));
+    }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => {
-            let (mut input_vec, start, offset) = parse_input(input);
-            solve_part_one(&mut input_vec, start, offset)
+    for (line_number, line) in numbered_lines(&input) {
+        if line.len() != offset {
+            return Err(Error::parse(
+                line_number,
+                format!("expected {} columns, found {}", offset, line.len()),
+            ));
+        }
+        for (index, c) in line.chars().enumerate() {
+            let message = match c {
+                '^' if index == 0 || index == offset - 1 => {
+                    "a splitter cannot sit on the edge".to_string()
+                }
+                '.' | 'S' | '^' => continue,
+                _ => format!("expected '.', 'S' or '^', found {:?}", c),
+            };
+
+            return Err(Error::parse_at(line_number, index + 1, message));
         }
-        Err(_) => 0,
     }
+
+    let result: Vec<char> = input.lines().flat_map(|l| l.chars()).collect::<Vec<char>>();
+    let start: usize = result
+        .iter()
+        .position(|c| *c == 'S')
+        .ok_or_else(|| Error::parse(1, "missing the starting point 'S'"))?;
+
+    Ok((result, start, offset))
 }
 
-pub fn part_two(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => {
-            let (mut input_vec, start, offset) = parse_input(input);
-            solve_part_two(&mut input_vec, start, offset)
-        }
-        Err(_) => 0,
+#[derive(Args)]
+pub struct DaySeven;
+
+impl Solution for DaySeven {
+    type Input = (Vec<char>, usize, usize);
+
+    fn parse(&self, input: &str) -> Result<(Vec<char>, usize, usize)> {
+        parse_input(input.to_string())
+    }
+
+    fn part_one(&self, input: &(Vec<char>, usize, usize)) -> Result<Answer> {
+        let (input_vec, start, offset) = input;
+        Ok(solve_part_one(&mut input_vec.clone(), *start, *offset).into())
+    }
+
+    fn part_two(&self, input: &(Vec<char>, usize, usize)) -> Result<Answer> {
+        let (input_vec, start, offset) = input;
+        Ok(solve_part_two(input_vec, *start, *offset).into())
     }
 }
 
@@ -131,7 +146,7 @@ mod test {
         assert_eq!(result, expected,)
     }
 
-    fn test_solve_part_two(input: &mut Vec<char>, start: usize, offset: usize, expected: u64) {
+    fn test_solve_part_two(input: &[char], start: usize, offset: usize, expected: u64) {
         let result = solve_part_two(input, start, offset);
 
         assert_eq!(result, expected,)
@@ -140,7 +155,7 @@ mod test {
     #[test]
     fn test_parse_input() {
         assert_eq!(
-            parse_input(EXAMPLE_AS_STRING.to_string()),
+            parse_input(EXAMPLE_AS_STRING.to_string()).unwrap(),
             (example_as_vec(), 7, 15),
         )
     }
@@ -171,20 +186,21 @@ mod test {
 .....^.^.^.....
 ..............."
                 .to_string(),
-        );
+        )
+        .unwrap();
 
         test_solve_part_one(&mut input, start, offset, 6)
     }
 
     #[test]
     fn test_solve_part_one_example() {
-        let (mut input, start, offset) = parse_input(EXAMPLE_AS_STRING.to_string());
+        let (mut input, start, offset) = parse_input(EXAMPLE_AS_STRING.to_string()).unwrap();
         test_solve_part_one(&mut input, start, offset, 21)
     }
 
     #[test]
     fn test_solve_part_two_example() {
-        let (mut input, start, offset) = parse_input(EXAMPLE_AS_STRING.to_string());
-        test_solve_part_two(&mut input, start, offset, 40)
+        let (input, start, offset) = parse_input(EXAMPLE_AS_STRING.to_string()).unwrap();
+        test_solve_part_two(&input, start, offset, 40)
     }
 }
@@ -1,9 +1,8 @@
-use advent_of_code::{read_file_to_string, read_lines, Coordinate};
-use log::{debug, log_enabled, Level};
-use std::path::Path;
+use advent_of_code::{Answer, Coordinate, Error, Result, Solution};
+use clap::Args;
 
-fn solve_part_one(coordinates: &mut Vec<Coordinate>) -> u64 {
-    coordinates.sort();
+fn solve_part_one(coordinates: &mut [Coordinate]) -> u64 {
+    Coordinate::sort_reading_order(coordinates);
     let mut max_area: u64 = 0;
 
     for i in 0..coordinates.len() {
@@ -23,36 +22,43 @@ fn solve_part_one(coordinates: &mut Vec<Coordinate>) -> u64 {
     max_area
 }
 
-fn parse_input(input: String) -> Vec<Coordinate> {
+fn parse_input(input: String) -> Result<Vec<Coordinate>> {
     input
         .lines()
-        .map(|l| {
-            let split = l
-                .split(",")
-                .filter_map(|x| x.parse::<i32>().ok())
-                .collect::<Vec<i32>>();
-            Coordinate::new(split[0], split[1])
+        .enumerate()
+        .map(|(index, l)| {
+            let line_number = index + 1;
+            let (x, y) = l.split_once(",").ok_or_else(|| {
+                Error::parse(line_number, format!("expected `x,y`, found {:?}", l))
+            })?;
+            let parse = |value: &str| {
+                value
+                    .parse::<i32>()
+                    .map_err(|err| Error::parse(line_number, format!("{:?}: {}", value, err)))
+            };
+
+            Ok(Coordinate::new(parse(x)?, parse(y)?))
         })
-        .collect::<Vec<Coordinate>>()
+        .collect::<Result<Vec<Coordinate>>>()
 }
 
-pub fn part_one(input_path: &Path) -> u64 {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(&mut parse_input(input)),
-        Err(_) => 0,
+#[derive(Args)]
+pub struct DayNine;
+
+impl Solution for DayNine {
+    type Input = Vec<Coordinate>;
+
+    fn parse(&self, input: &str) -> Result<Vec<Coordinate>> {
+        parse_input(input.to_string())
     }
-}
 
-pub fn part_two(input_path: &Path) -> i32 {
-    if let Ok(lines) = read_lines(input_path) {
-        for line in lines {
-            if let Ok(result) = line {
-                debug!("{}", result);
-            }
-        }
+    fn part_one(&self, input: &Vec<Coordinate>) -> Result<Answer> {
+        Ok(solve_part_one(&mut input.clone()).into())
     }
 
-    0
+    fn part_two(&self, _input: &Vec<Coordinate>) -> Result<Answer> {
+        Err(Error::Unsolved)
+    }
 }
 
 #[cfg(test)]
@@ -84,7 +90,7 @@ mod tests {
     #[test]
     fn test_parse_input() {
         assert_eq!(
-            parse_input(EXAMPLE_INPUT.to_string()),
+            parse_input(EXAMPLE_INPUT.to_string()).unwrap(),
             example_coordinates(),
         )
     }
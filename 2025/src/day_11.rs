@@ -1,64 +1,48 @@
-use advent_of_code::read_file_to_string;
+use advent_of_code::graph::Graph;
+use advent_of_code::{Answer, Error, Result, Solution};
+use clap::Args;
 use std::collections::{HashMap, VecDeque};
-use std::path::Path;
-
-fn solve_part_one(
-    start: Option<String>,
-    thing: &mut HashMap<String, VecDeque<String>>,
-    visited: &mut VecDeque<String>,
-) -> VecDeque<VecDeque<String>> {
-    let mut result: VecDeque<VecDeque<String>> = VecDeque::new();
-
-    let _start = start.unwrap_or_else(|| "you".to_string());
-    if visited.is_empty() {
-        visited.push_back(_start.clone());
-    }
 
-    match _start == "out" {
-        true => {
-            let mut blah = VecDeque::new();
-            blah.push_back(visited.clone());
-            blah
-        }
-        false => {
-            let mut foo = thing.clone();
-            let start_vec = foo.get_mut(&_start).unwrap();
-
-            while !start_vec.is_empty() {
-                match start_vec.pop_front() {
-                    Some(s) => {
-                        if !visited.contains(&s) || _start != *s {
-                            visited.push_back(s.clone());
-                            result.append(&mut solve_part_one(Some(s.clone()), thing, visited));
-                            visited.pop_back();
-                        }
-                    }
-                    None => {}
-                }
-            }
-
-            result
+fn build_graph(devices: &HashMap<String, VecDeque<String>>) -> Graph {
+    let mut graph = Graph::directed();
+    for (device, outputs) in devices {
+        for output in outputs {
+            graph.add_edge(device, output);
         }
     }
+
+    graph
 }
 
-fn solve_part_two(
-    thing: &mut HashMap<String, VecDeque<String>>,
-    visited: &mut VecDeque<String>,
-) -> usize {
-    // Too slow
-    // solve_part_one(Some("svr".to_string()), thing, visited).iter().filter(|&m| m.contains(&"dac".to_string()) && m.contains(&"fft".to_string())).count()
-    0
+// Paths from `from` to "out" passing through every device in `via`
+fn count_paths(devices: &HashMap<String, VecDeque<String>>, from: &str, via: &[&str]) -> u64 {
+    let graph = build_graph(devices);
+    let (Some(from), Some(out)) = (graph.id(from), graph.id("out")) else {
+        return 0;
+    };
+    let Some(via) = via
+        .iter()
+        .map(|name| graph.id(name))
+        .collect::<Option<Vec<_>>>()
+    else {
+        return 0;
+    };
+
+    graph.count_paths_via(from, out, &via)
 }
 
-fn parse_input(input: String) -> HashMap<String, VecDeque<String>> {
+fn parse_input(input: String) -> Result<HashMap<String, VecDeque<String>>> {
     let mut result: HashMap<String, VecDeque<String>> = HashMap::new();
 
-    for line in input.lines() {
-        let split_line: Vec<&str> = line.split(":").collect();
-        let device = split_line[0].to_string();
-        let outputs = split_line[1]
-            .trim()
+    for (index, line) in input.lines().enumerate() {
+        let (device, outputs) = line.split_once(':').ok_or_else(|| {
+            Error::parse(
+                index + 1,
+                format!("expected `device: outputs`, found {:?}", line),
+            )
+        })?;
+        let device = device.to_string();
+        let outputs = outputs
             .split_whitespace()
             .map(|s| s.to_string())
             .collect::<VecDeque<String>>();
@@ -66,20 +50,25 @@ fn parse_input(input: String) -> HashMap<String, VecDeque<String>> {
         result.insert(device, outputs);
     }
 
-    result
+    Ok(result)
 }
 
-pub fn part_one(input_path: &Path) -> usize {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_one(None, &mut parse_input(input), &mut VecDeque::new()).len(),
-        Err(_) => 0,
+#[derive(Args)]
+pub struct DayEleven;
+
+impl Solution for DayEleven {
+    type Input = HashMap<String, VecDeque<String>>;
+
+    fn parse(&self, input: &str) -> Result<HashMap<String, VecDeque<String>>> {
+        parse_input(input.to_string())
+    }
+
+    fn part_one(&self, input: &HashMap<String, VecDeque<String>>) -> Result<Answer> {
+        Ok(count_paths(input, "you", &[]).into())
     }
-}
 
-pub fn part_two(input_path: &Path) -> usize {
-    match read_file_to_string(input_path) {
-        Ok(input) => solve_part_two(&mut parse_input(input), &mut VecDeque::new()),
-        Err(_) => 0,
+    fn part_two(&self, input: &HashMap<String, VecDeque<String>>) -> Result<Answer> {
+        Ok(count_paths(input, "svr", &["dac", "fft"]).into())
     }
 }
 
@@ -87,60 +76,11 @@ pub fn part_two(input_path: &Path) -> usize {
 mod tests {
     use super::*;
 
-    const EXAMPLE_INPUT_PART_ONE: &str = "aaa: you hhh
-you: bbb ccc
-bbb: ddd eee
-ccc: ddd eee fff
-ddd: ggg
-eee: out
-fff: out
-ggg: out
-hhh: ccc fff iii
-iii: out";
-
-    const EXAMPLE_INPUT_PART_TWO: &str = "svr: aaa bbb
-aaa: fft
-fft: ccc
-bbb: tty
-tty: ccc
-ccc: ddd eee
-ddd: hub
-hub: fff
-eee: dac
-dac: fff
-fff: ggg hhh
-ggg: out
-hhh: out";
-
-    #[test]
-    fn test_solve_part_one() {
-        assert_eq!(
-            5,
-            solve_part_one(
-                None,
-                &mut parse_input(EXAMPLE_INPUT_PART_ONE.to_string()),
-                &mut VecDeque::new()
-            )
-            .len()
-        )
-    }
-
-    // #[test]
-    fn test_solve_part_two() {
-        assert_eq!(
-            2,
-            solve_part_two(
-                &mut parse_input(EXAMPLE_INPUT_PART_TWO.to_string()),
-                &mut VecDeque::new()
-            )
-        )
-    }
-
     #[test]
     fn test_parse_input_example() {
         assert_eq!(
             expected_input(),
-            parse_input(EXAMPLE_INPUT_PART_ONE.to_string())
+            parse_input(include_str!("../../examples/2025/11/part_one.txt").to_string()).unwrap()
         )
     }
 
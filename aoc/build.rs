@@ -0,0 +1,62 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Turns every examples/<YEAR>/<DD>/<NAME>.txt fixture into a #[test] that
+// tests/examples.rs includes, so adding an example needs no Rust code
+fn main() {
+    let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../examples");
+    println!("cargo:rerun-if-changed={}", examples_dir.display());
+
+    let mut tests = String::new();
+    for (year, day, name) in fixtures(&examples_dir) {
+        let identifier: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        tests.push_str(&format!(
+            "#[test]\nfn example_{}_{:02}_{}() {{\n    check_example({}, {}, {:?});\n}}\n\n",
+            year, day, identifier, year, day, name
+        ));
+    }
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out_dir.join("examples.rs"), tests).unwrap();
+}
+
+fn sorted_entries(dir: &Path) -> Vec<PathBuf> {
+    let mut entries: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries.map_while(Result::ok).map(|e| e.path()).collect(),
+        Err(_) => Vec::new(),
+    };
+    entries.sort();
+
+    entries
+}
+
+fn fixtures(examples_dir: &Path) -> Vec<(u16, u8, String)> {
+    let mut fixtures = Vec::new();
+
+    for year_dir in sorted_entries(examples_dir) {
+        let Some(year) = file_name(&year_dir).and_then(|n| n.parse::<u16>().ok()) else {
+            continue;
+        };
+        for day_dir in sorted_entries(&year_dir) {
+            let Some(day) = file_name(&day_dir).and_then(|n| n.parse::<u8>().ok()) else {
+                continue;
+            };
+            for input in sorted_entries(&day_dir) {
+                if input.extension().is_some_and(|e| e == "txt") {
+                    let name = input.file_stem().unwrap().to_string_lossy().to_string();
+                    fixtures.push((year, day, name));
+                }
+            }
+        }
+    }
+
+    fixtures
+}
+
+fn file_name(path: &Path) -> Option<String> {
+    path.file_name().map(|n| n.to_string_lossy().to_string())
+}
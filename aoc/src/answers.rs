@@ -0,0 +1,46 @@
+use advent_of_code::Answer;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ExpectedAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_one: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<Answer>,
+}
+
+impl ExpectedAnswers {
+    pub fn part_mut(&mut self, part: u8) -> &mut Option<Answer> {
+        match part {
+            1 => &mut self.part_one,
+            _ => &mut self.part_two,
+        }
+    }
+}
+
+pub type AnswersFile = BTreeMap<u8, ExpectedAnswers>;
+
+// The JSON files kept beside a year's inputs start out absent, so a missing
+// file loads as empty; an unreadable or malformed one is an error.
+pub fn load_json<T: DeserializeOwned + Default>(path: &Path) -> Result<T, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map_err(|err| format!("could not parse {}: {}", path.display(), err)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(T::default()),
+        Err(err) => Err(format!("could not read {}: {}", path.display(), err)),
+    }
+}
+
+pub fn save_json<T: Serialize>(path: &Path, value: &T) -> Result<(), String> {
+    let contents = serde_json::to_string_pretty(value).map_err(|err| err.to_string())?;
+
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents + "\n"))
+        .map_err(|err| format!("could not write {}: {}", path.display(), err))
+}
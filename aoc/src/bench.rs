@@ -0,0 +1,204 @@
+use crate::answers::{load_json, save_json};
+use crate::inputs::{baselines_path, read_day_input};
+use crate::report::{format_duration, print_table, timed};
+use advent_of_code::{Error, Registry, Result, Runnable};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::Duration;
+
+const HEADER: [&str; 5] = ["Day", "Stage", "Median", "Baseline", "Change"];
+
+// Median nanoseconds per stage, keyed by day and then stage name
+type Baselines = BTreeMap<u8, BTreeMap<String, u64>>;
+
+pub struct Settings {
+    pub samples: usize,
+    // Percentage a stage may slow down by before it counts as a regression
+    pub threshold: f64,
+    pub save_baseline: bool,
+}
+
+// The median shrugs off the odd sample slowed by the rest of the machine
+fn median(mut samples: Vec<Duration>) -> Duration {
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+fn sample<T, F: FnMut() -> Result<T>>(samples: usize, mut f: F) -> Result<Duration> {
+    // Warms up caches and stops on errors before anything is timed
+    f()?;
+
+    let durations = (0..samples).map(|_| timed(&mut f).elapsed).collect();
+    Ok(median(durations))
+}
+
+fn bench_day(
+    solution: &dyn Runnable,
+    puzzle_input: &str,
+    samples: usize,
+) -> Vec<(&'static str, Result<Duration>)> {
+    let parsed = match solution.parse(puzzle_input) {
+        Ok(parsed) => parsed,
+        Err(err) => return vec![("parse", Err(err))],
+    };
+
+    vec![
+        ("parse", sample(samples, || solution.parse(puzzle_input))),
+        (
+            "part_one",
+            sample(samples, || solution.part_one(parsed.as_ref())),
+        ),
+        (
+            "part_two",
+            sample(samples, || solution.part_two(parsed.as_ref())),
+        ),
+    ]
+}
+
+enum Change {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+fn compare(median: Duration, baseline: u64, threshold: f64) -> (f64, Change) {
+    let percent = (median.as_nanos() as f64 / baseline.max(1) as f64 - 1.0) * 100.0;
+    let change = if percent > threshold {
+        Change::Regressed
+    } else if percent < -threshold {
+        Change::Improved
+    } else {
+        Change::Unchanged
+    };
+
+    (percent, change)
+}
+
+fn message_row(day: u8, stage: &str, message: String) -> [String; 5] {
+    [
+        day.to_string(),
+        stage.to_string(),
+        message,
+        String::new(),
+        String::new(),
+    ]
+}
+
+pub fn bench(
+    registry: &Registry,
+    year: u16,
+    days: &[u8],
+    input_dir: &Path,
+    settings: &Settings,
+) -> ExitCode {
+    let path = baselines_path(input_dir, year);
+    let mut baselines: Baselines = match load_json(&path) {
+        Ok(baselines) => baselines,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut rows: Vec<[String; 5]> = Vec::new();
+    let mut regressions = 0;
+    let mut failed = false;
+
+    for &day in days {
+        // Like run --all, every day is timed with its default options
+        let solution = match registry.solution(year, day, &[]) {
+            Some(Ok(solution)) => solution,
+            Some(Err(err)) => {
+                failed = true;
+                rows.push(message_row(day, "", format!("error: {}", err.kind())));
+                continue;
+            }
+            None => {
+                failed = true;
+                rows.push(message_row(day, "", "error: no registered solution".into()));
+                continue;
+            }
+        };
+        let puzzle_input = match read_day_input(input_dir, year, day) {
+            Ok(Some(puzzle_input)) => puzzle_input,
+            Ok(None) => {
+                rows.push(message_row(day, "", "missing input".to_string()));
+                continue;
+            }
+            Err(err) => {
+                failed = true;
+                rows.push(message_row(day, "", format!("error: {}", err)));
+                continue;
+            }
+        };
+
+        for (stage, measured) in bench_day(solution.as_ref(), &puzzle_input, settings.samples) {
+            let median = match measured {
+                Ok(median) => median,
+                Err(Error::Unsolved) => {
+                    rows.push(message_row(day, stage, "unsolved".to_string()));
+                    continue;
+                }
+                Err(err) => {
+                    failed = true;
+                    rows.push(message_row(day, stage, format!("error: {}", err)));
+                    continue;
+                }
+            };
+
+            let day_baselines = baselines.entry(day).or_default();
+            let (baseline, change) = match day_baselines.get(stage) {
+                Some(&baseline) => {
+                    let (percent, change) = compare(median, baseline, settings.threshold);
+                    let verdict = match change {
+                        Change::Regressed => {
+                            regressions += 1;
+                            " regressed"
+                        }
+                        Change::Improved => " improved",
+                        Change::Unchanged => "",
+                    };
+                    (
+                        format_duration(Duration::from_nanos(baseline)),
+                        format!("{:+.1}%{}", percent, verdict),
+                    )
+                }
+                None => (String::new(), String::new()),
+            };
+            rows.push([
+                day.to_string(),
+                stage.to_string(),
+                format_duration(median),
+                baseline,
+                change,
+            ]);
+
+            if settings.save_baseline {
+                day_baselines.insert(stage.to_string(), median.as_nanos() as u64);
+            }
+        }
+    }
+
+    print_table(HEADER, &rows);
+
+    if settings.save_baseline {
+        if let Err(err) = save_json(&path, &baselines) {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("\nSaved baseline to {}", path.display());
+    } else if regressions > 0 {
+        println!(
+            "\n{} stage(s) regressed by more than {}%",
+            regressions, settings.threshold
+        );
+        failed = true;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
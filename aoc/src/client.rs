@@ -0,0 +1,93 @@
+use crate::config::{session_path, session_token};
+use std::fmt;
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status { code: u16, body: String },
+    Transport(String),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => match session_path() {
+                Some(path) => write!(
+                    f,
+                    "no session token, set AOC_SESSION or write it to {}",
+                    path.display()
+                ),
+                None => write!(f, "no session token, set AOC_SESSION"),
+            },
+            ClientError::Status { code, body } => {
+                write!(f, "server responded with {}: {}", code, body.trim())
+            }
+            ClientError::Transport(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(err: ureq::Error) -> ClientError {
+        match err {
+            ureq::Error::Status(code, response) => ClientError::Status {
+                code,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str) -> Result<Client, ClientError> {
+        let session = session_token().ok_or(ClientError::MissingSession)?;
+
+        Ok(Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        })
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let response = self.agent.get(&url).set("Cookie", &self.cookie()).call()?;
+
+        response
+            .into_string()
+            .map_err(|err| ClientError::Transport(err.to_string()))
+    }
+
+    // Returns the response page, whose wording carries the verdict
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        response
+            .into_string()
+            .map_err(|err| ClientError::Transport(err.to_string()))
+    }
+}
@@ -0,0 +1,30 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// The session cookie from a logged in browser. AOC_SESSION wins over the file
+// so a one-off token doesn't require editing the config. Blank values count as
+// unset, so an empty AOC_SESSION falls back to the file.
+pub fn session_token() -> Option<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .and_then(non_blank)
+        .or_else(|| fs::read_to_string(session_path()?).ok().and_then(non_blank))
+}
+
+fn non_blank(token: String) -> Option<String> {
+    let token = token.trim();
+    (!token.is_empty()).then(|| token.to_string())
+}
+
+// $XDG_CONFIG_HOME/aoc/session, falling back to ~/.config/aoc/session
+pub fn session_path() -> Option<PathBuf> {
+    let config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::from(env::var_os("HOME")?).join(".config"),
+    };
+
+    Some(config_dir.join("aoc").join("session"))
+}
@@ -0,0 +1,37 @@
+use crate::client::Client;
+use crate::inputs::input_path;
+use std::fs;
+use std::path::Path;
+use std::process::ExitCode;
+
+pub fn fetch(year: u16, day: u8, input_dir: &Path, base_url: &str) -> ExitCode {
+    let path = input_path(input_dir, year, day);
+    // Inputs never change once released, so a cached copy is never refreshed
+    if path.exists() {
+        println!("Input already cached at {}", path.display());
+        return ExitCode::SUCCESS;
+    }
+
+    let input = match Client::new(base_url).and_then(|client| client.input(year, day)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: could not fetch {} day {}: {}", year, day, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let written = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, input));
+    match written {
+        Ok(()) => {
+            println!("Saved input to {}", path.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: could not write {}: {}", path.display(), err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use advent_of_code::{read_file_to_string, Error, Result};
+use std::io::{self, ErrorKind, Read};
+use std::path::{Path, PathBuf};
+
+// Where a single run reads its puzzle input from
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    // An --input of `-` means stdin, mirroring most command line tools
+    pub fn from_args(input: Option<&Path>, input_string: Option<&str>, default: PathBuf) -> Self {
+        match (input, input_string) {
+            (_, Some(input_string)) => InputSource::Inline(input_string.to_string()),
+            (Some(input), _) if input == Path::new("-") => InputSource::Stdin,
+            (Some(input), _) => InputSource::File(input.to_path_buf()),
+            (None, None) => InputSource::File(default),
+        }
+    }
+
+    pub fn read(self) -> Result<String> {
+        match self {
+            InputSource::File(path) => read_file_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| Error::InputNotFound {
+                        path: PathBuf::from("-"),
+                        source,
+                    })?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input),
+        }
+    }
+}
+
+// Inputs live at <input dir>/<year>/day_<DD>.txt, mirroring the day_<DD>.rs
+// source files
+pub fn input_path(input_dir: &Path, year: u16, day: u8) -> PathBuf {
+    input_dir
+        .join(year.to_string())
+        .join(format!("day_{:02}.txt", day))
+}
+
+// A day's input for commands that cover many days, where an input that was
+// never fetched is expected and is `None`. Any other read error is returned.
+pub fn read_day_input(input_dir: &Path, year: u16, day: u8) -> Result<Option<String>> {
+    match read_file_to_string(input_path(input_dir, year, day)) {
+        Ok(puzzle_input) => Ok(Some(puzzle_input)),
+        Err(Error::InputNotFound { source, .. }) if source.kind() == ErrorKind::NotFound => {
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+// Known-correct answers sit beside the year's inputs as a JSON object keyed by
+// day, e.g. {"1": {"part_one": 3, "part_two": "abc"}}
+pub fn answers_path(input_dir: &Path, year: u16) -> PathBuf {
+    input_dir.join(year.to_string()).join("answers.json")
+}
+
+// Every submission's verdict for the year, so known-wrong answers aren't sent
+// twice
+pub fn submissions_path(input_dir: &Path, year: u16) -> PathBuf {
+    input_dir.join(year.to_string()).join("submissions.json")
+}
+
+// Benchmark medians for the year, which only mean anything on the machine that
+// recorded them
+pub fn baselines_path(input_dir: &Path, year: u16) -> PathBuf {
+    input_dir.join(year.to_string()).join("bench.json")
+}
@@ -0,0 +1,13 @@
+use advent_of_code::Registry;
+
+// Every year's solutions, shared by the CLI and the example tests
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+    advent_of_code_2015::register(&mut registry);
+    advent_of_code_2022::register(&mut registry);
+    advent_of_code_2023::register(&mut registry);
+    advent_of_code_2024::register(&mut registry);
+    advent_of_code_2025::register(&mut registry);
+
+    registry
+}
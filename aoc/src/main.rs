@@ -0,0 +1,339 @@
+use advent_of_code::{Answer, Result};
+use aoc::registry;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use report::{DayRun, Format, Record};
+use std::env;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::thread;
+
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod fetch;
+mod inputs;
+mod new;
+mod report;
+mod run_all;
+mod schedule;
+mod submit;
+mod verify;
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None)]
+struct Cli {
+    /// Turn debugging information on
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    debug: u8,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Runs both parts of a single day, or of every day in a year with --all
+    Run {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(long, required_unless_present = "all")]
+        day: Option<u8>,
+
+        /// Runs every registered day of the year with its default options and prints a timing table
+        #[arg(long, conflicts_with_all = ["day", "input", "input_string", "options"])]
+        all: bool,
+
+        /// Reads the puzzle input from this file instead of the input directory, or from stdin when `-`
+        #[arg(short, long, value_name = "FILE")]
+        input: Option<PathBuf>,
+
+        /// Uses this string as the puzzle input instead of a file
+        #[arg(long, conflicts_with = "input")]
+        input_string: Option<String>,
+
+        /// Directory holding inputs as <YEAR>/day_<DD>.txt
+        #[arg(long, value_name = "DIR", default_value = "inputs")]
+        input_dir: PathBuf,
+
+        /// Worker threads for --all, defaulting to one per CPU
+        #[arg(short, long, requires = "all", value_parser = clap::value_parser!(u32).range(1..))]
+        jobs: Option<u32>,
+
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Options for the selected day, passed after `--`
+        #[arg(last = true)]
+        options: Vec<String>,
+    },
+    /// Times parse and both parts of each day, with its default options, against its cached input
+    Bench {
+        #[arg(short, long)]
+        year: u16,
+
+        /// Only benchmarks this day instead of every registered day
+        #[arg(long)]
+        day: Option<u8>,
+
+        /// Directory holding inputs and baselines as <YEAR>/day_<DD>.txt and <YEAR>/bench.json
+        #[arg(long, value_name = "DIR", default_value = "inputs")]
+        input_dir: PathBuf,
+
+        /// Timed runs per stage, after one warm-up run
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        samples: u32,
+
+        /// Percentage slowdown against the baseline that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+
+        /// Records this run as the new baseline instead of failing on regressions
+        #[arg(long)]
+        save_baseline: bool,
+    },
+    /// Downloads a day's input into the input directory unless it is already cached
+    Fetch {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Directory holding inputs as <YEAR>/day_<DD>.txt
+        #[arg(long, value_name = "DIR", default_value = "inputs")]
+        input_dir: PathBuf,
+
+        /// Server to download from, e.g. a local stub while testing
+        #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = config::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Creates a day module from the year's day_00.rs template and registers it
+    New {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Workspace to scaffold into, by default the one holding the current directory
+        #[arg(long, value_name = "DIR")]
+        workspace_dir: Option<PathBuf>,
+    },
+    /// Computes one part's answer from the cached input and submits it
+    Submit {
+        #[arg(short, long)]
+        year: u16,
+
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Directory holding inputs, answers and submission history under <YEAR>/
+        #[arg(long, value_name = "DIR", default_value = "inputs")]
+        input_dir: PathBuf,
+
+        /// Server to submit to, e.g. a local stub while testing
+        #[arg(long, value_name = "URL", env = "AOC_BASE_URL", default_value = config::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Options for the selected day, passed after `--`
+        #[arg(last = true)]
+        options: Vec<String>,
+    },
+    /// Checks solutions, with their default options, against the known answers in <YEAR>/answers.json
+    Verify {
+        /// Only verifies this year instead of every registered year
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Only verifies this day of the year
+        #[arg(long, requires = "year")]
+        day: Option<u8>,
+
+        /// Directory holding inputs and answers as <YEAR>/day_<DD>.txt and <YEAR>/answers.json
+        #[arg(long, value_name = "DIR", default_value = "inputs")]
+        input_dir: PathBuf,
+    },
+}
+
+// Returns whether the part produced an answer so the caller can pick the exit code
+fn print_answer(part: &str, answer: &Result<Answer>) -> bool {
+    match answer {
+        Ok(answer) if answer.is_multi_line() => println!("Part {} answer:\n{}", part, answer),
+        Ok(answer) => println!("Part {} answer: {}", part, answer),
+        Err(err) => eprintln!("error: part {}: {}", part, err),
+    }
+
+    answer.is_ok()
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    env_logger::init();
+
+    match &cli.command {
+        Commands::Run {
+            year,
+            all: true,
+            input_dir,
+            format,
+            jobs,
+            ..
+        } => {
+            let jobs = match jobs {
+                Some(jobs) => *jobs as usize,
+                None => thread::available_parallelism().map_or(1, usize::from),
+            };
+
+            run_all::run_all(&registry(), *year, input_dir, *format, jobs)
+        }
+        Commands::Run {
+            year,
+            day,
+            input,
+            input_string,
+            input_dir,
+            format,
+            options,
+            ..
+        } => {
+            // clap requires --day whenever --all is absent
+            let day = day.expect("--day is required without --all");
+            let solution = match registry().solution(*year, day, options) {
+                Some(Ok(solution)) => solution,
+                Some(Err(err)) => err.exit(),
+                None => Cli::command()
+                    .error(
+                        ErrorKind::InvalidValue,
+                        format!("no solution registered for {} day {}", year, day),
+                    )
+                    .exit(),
+            };
+
+            let source = inputs::InputSource::from_args(
+                input.as_deref(),
+                input_string.as_deref(),
+                inputs::input_path(input_dir, *year, day),
+            );
+            let puzzle_input = match source.read() {
+                Ok(puzzle_input) => puzzle_input,
+                Err(err) => {
+                    eprintln!("error: {}", err);
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let run = report::run_day(solution.as_ref(), &puzzle_input);
+            let (part_one, part_two) = match &run {
+                DayRun::Ran {
+                    part_one, part_two, ..
+                } => (&part_one.value, &part_two.value),
+                DayRun::Failed(err) => {
+                    eprintln!("error: {}", err);
+                    return ExitCode::FAILURE;
+                }
+                DayRun::MissingInput => unreachable!("the input was read above"),
+            };
+
+            let solved = match format {
+                Format::Text => {
+                    let part_one_solved = print_answer("one", part_one);
+                    let part_two_solved = print_answer("two", part_two);
+                    part_one_solved && part_two_solved
+                }
+                Format::Json => {
+                    Record::for_day(*year, day, &run)
+                        .iter()
+                        .for_each(Record::print);
+                    part_one.is_ok() && part_two.is_ok()
+                }
+            };
+            if solved {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            }
+        }
+        Commands::Bench {
+            year,
+            day,
+            input_dir,
+            samples,
+            threshold,
+            save_baseline,
+        } => {
+            let registry = registry();
+            let days = match day {
+                Some(day) => vec![*day],
+                None => registry.days(*year),
+            };
+            let settings = bench::Settings {
+                samples: *samples as usize,
+                threshold: *threshold,
+                save_baseline: *save_baseline,
+            };
+
+            bench::bench(&registry, *year, &days, input_dir, &settings)
+        }
+        Commands::Fetch {
+            year,
+            day,
+            input_dir,
+            base_url,
+        } => fetch::fetch(*year, *day, input_dir, base_url),
+        Commands::New {
+            year,
+            day,
+            workspace_dir,
+        } => {
+            let workspace_dir = match workspace_dir {
+                Some(workspace_dir) => Some(workspace_dir.clone()),
+                None => env::current_dir()
+                    .ok()
+                    .and_then(|dir| new::workspace_root(&dir)),
+            };
+            match workspace_dir {
+                Some(workspace_dir) => new::new(&workspace_dir, *year, *day),
+                None => {
+                    eprintln!("error: not inside a workspace, pass --workspace-dir");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Commands::Submit {
+            year,
+            day,
+            part,
+            input_dir,
+            base_url,
+            options,
+        } => submit::submit(
+            &registry(),
+            *year,
+            *day,
+            *part,
+            options,
+            input_dir,
+            base_url,
+        ),
+        Commands::Verify {
+            year,
+            day,
+            input_dir,
+        } => {
+            let registry = registry();
+            let years = match year {
+                Some(year) => vec![*year],
+                None => registry.years(),
+            };
+
+            verify::verify(&registry, &years, *day, input_dir)
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+const NAMES: [&str; 26] = [
+    "Zero",
+    "One",
+    "Two",
+    "Three",
+    "Four",
+    "Five",
+    "Six",
+    "Seven",
+    "Eight",
+    "Nine",
+    "Ten",
+    "Eleven",
+    "Twelve",
+    "Thirteen",
+    "Fourteen",
+    "Fifteen",
+    "Sixteen",
+    "Seventeen",
+    "Eighteen",
+    "Nineteen",
+    "Twenty",
+    "TwentyOne",
+    "TwentyTwo",
+    "TwentyThree",
+    "TwentyFour",
+    "TwentyFive",
+];
+
+// The example test fails on an empty input or an answers file without
+// answers, so a scaffolded day stays red until its example is filled in
+const EXAMPLE_ANSWERS: &str = "{ \"part_one\": null, \"part_two\": null }\n";
+
+// The nearest directory at or above `start` whose Cargo.toml declares the
+// workspace, so scaffolding works from anywhere inside the repository
+pub fn workspace_root(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.contains("[workspace]"))
+        })
+        .map(Path::to_path_buf)
+}
+
+// A year's sources live at <workspace>/<year>/src, beside its day_00.rs template
+fn source_dir(workspace_dir: &Path, year: u16) -> PathBuf {
+    workspace_dir.join(year.to_string()).join("src")
+}
+
+// Examples live at <workspace>/examples/<year>/<DD>, where build.rs finds them
+fn example_dir(workspace_dir: &Path, year: u16, day: u8) -> PathBuf {
+    workspace_dir
+        .join("examples")
+        .join(year.to_string())
+        .join(format!("{:02}", day))
+}
+
+// Inserts `line` among the lines starting with `prefix`, keeping them sorted.
+// The day's lines are zero padded, so string order is day order.
+fn insert_sorted(source: &str, prefix: &str, line: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let existing: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].trim_start().starts_with(prefix))
+        .collect();
+
+    let position = match existing.iter().find(|&&i| lines[i] > line) {
+        Some(&i) => i,
+        None => existing.last()? + 1,
+    };
+    lines.insert(position, line);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn register(lib_path: &Path, year: u16, day: u8) -> io::Result<()> {
+    let module = format!("day_{:02}", day);
+    let source = fs::read_to_string(lib_path)?;
+
+    let not_generated = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "{} has no mod or register lines to follow",
+                lib_path.display()
+            ),
+        )
+    };
+    let source = insert_sorted(&source, "mod day_", &format!("mod {};", module))
+        .ok_or_else(not_generated)?;
+    let source = insert_sorted(
+        &source,
+        "registry.register::<day_",
+        &format!(
+            "    registry.register::<{}::Day{}>({}, {});",
+            module, NAMES[day as usize], year, day
+        ),
+    )
+    .ok_or_else(not_generated)?;
+
+    fs::write(lib_path, source)
+}
+
+fn scaffold(workspace_dir: &Path, year: u16, day: u8) -> io::Result<()> {
+    let source_dir = source_dir(workspace_dir, year);
+    let module_path = source_dir.join(format!("day_{:02}.rs", day));
+    if module_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module_path.display()),
+        ));
+    }
+
+    // Each year keeps its own template, so new days follow that year's style
+    let template = fs::read_to_string(source_dir.join("day_00.rs"))?;
+    fs::write(
+        &module_path,
+        template.replace("DayZero", &format!("Day{}", NAMES[day as usize])),
+    )?;
+    println!("Created {}", module_path.display());
+
+    let lib_path = source_dir.join("lib.rs");
+    register(&lib_path, year, day)?;
+    println!("Registered day {} in {}", day, lib_path.display());
+
+    let example_dir = example_dir(workspace_dir, year, day);
+    fs::create_dir_all(&example_dir)?;
+    fs::write(example_dir.join("example.txt"), "")?;
+    fs::write(example_dir.join("example.json"), EXAMPLE_ANSWERS)?;
+    println!("Created an empty example in {}", example_dir.display());
+
+    Ok(())
+}
+
+pub fn new(workspace_dir: &Path, year: u16, day: u8) -> ExitCode {
+    match scaffold(workspace_dir, year, day) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: could not create {} day {}: {}", year, day, err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use advent_of_code::{Answer, Error, Result, Runnable};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable lines, or a table with --all
+    #[default]
+    Text,
+    /// One JSON record per part and line
+    Json,
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.2?}", duration)
+}
+
+// Left-aligned columns sized to their widest cell, under a dashed header rule
+pub fn print_table<const N: usize>(header: [&str; N], rows: &[[String; N]]) {
+    let mut widths: [usize; N] = header.map(str::len);
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |cells: [&str; N]| {
+        let line = cells
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<String>>()
+            .join("  ");
+        println!("{}", line.trim_end());
+    };
+
+    print_row(header);
+    print_row(
+        widths
+            .map(|width| "-".repeat(width))
+            .each_ref()
+            .map(String::as_str),
+    );
+    for row in rows {
+        print_row(row.each_ref().map(String::as_str));
+    }
+}
+
+pub struct Timed<T> {
+    pub value: T,
+    pub elapsed: Duration,
+}
+
+pub fn timed<T, F: FnOnce() -> T>(f: F) -> Timed<T> {
+    let start = Instant::now();
+    let value = f();
+
+    Timed {
+        value,
+        elapsed: start.elapsed(),
+    }
+}
+
+pub enum DayRun {
+    // The day never reached its parts, e.g. bad options or an unparsable input
+    Failed(String),
+    MissingInput,
+    Ran {
+        parse: Duration,
+        part_one: Timed<Result<Answer>>,
+        part_two: Timed<Result<Answer>>,
+    },
+}
+
+pub fn run_day(solution: &dyn Runnable, puzzle_input: &str) -> DayRun {
+    let parsed = timed(|| solution.parse(puzzle_input));
+    let input = match parsed.value {
+        Ok(input) => input,
+        Err(err) => return DayRun::Failed(format!("could not parse input: {}", err)),
+    };
+
+    DayRun::Ran {
+        parse: parsed.elapsed,
+        part_one: timed(|| solution.part_one(input.as_ref())),
+        part_two: timed(|| solution.part_two(input.as_ref())),
+    }
+}
+
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    MissingInput,
+    Error,
+}
+
+#[derive(Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ms: f64,
+}
+
+impl Record {
+    fn part(year: u16, day: u8, part: u8, result: &Timed<Result<Answer>>) -> Record {
+        let (status, answer, error) = match &result.value {
+            Ok(value) => (Status::Solved, Some(value.clone()), None),
+            Err(Error::Unsolved) => (Status::Unsolved, None, None),
+            Err(err) => (Status::Error, None, Some(err.to_string())),
+        };
+
+        Record {
+            year,
+            day,
+            part,
+            status,
+            answer,
+            error,
+            duration_ms: milliseconds(result.elapsed),
+        }
+    }
+
+    // Both parts of a day, with a day that never ran reported against each
+    pub fn for_day(year: u16, day: u8, run: &DayRun) -> Vec<Record> {
+        let unrun = |status: Status, error: Option<&String>| {
+            (1..=2)
+                .map(|part| Record {
+                    year,
+                    day,
+                    part,
+                    status,
+                    answer: None,
+                    error: error.cloned(),
+                    duration_ms: 0.0,
+                })
+                .collect()
+        };
+
+        match run {
+            DayRun::Failed(err) => unrun(Status::Error, Some(err)),
+            DayRun::MissingInput => unrun(Status::MissingInput, None),
+            DayRun::Ran {
+                part_one, part_two, ..
+            } => vec![
+                Record::part(year, day, 1, part_one),
+                Record::part(year, day, 2, part_two),
+            ],
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string(self).expect("records always serialize")
+        );
+    }
+}
+
+fn milliseconds(elapsed: Duration) -> f64 {
+    elapsed.as_secs_f64() * 1000.0
+}
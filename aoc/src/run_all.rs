@@ -0,0 +1,124 @@
+use crate::report::{format_duration, print_table, DayRun, Format, Record};
+use crate::schedule::run_days;
+use advent_of_code::{Answer, Error, Registry, Result};
+use std::fmt;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+const HEADER: [&str; 6] = ["Day", "Parse", "Part one", "Time", "Part two", "Time"];
+
+fn format_answer(answer: &Result<Answer>) -> String {
+    match answer {
+        Ok(Answer::Lines(lines)) => format!("({} lines)", lines.len()),
+        Ok(answer) => answer.to_string(),
+        Err(err) => format!("error: {}", err),
+    }
+}
+
+// Unsolved parts are expected while a year is in progress, so they are shown
+// in the table without failing the run
+fn is_failure(answer: &Result<Answer>) -> bool {
+    matches!(answer, Err(err) if !matches!(err, Error::Unsolved))
+}
+
+pub fn run_all(
+    registry: &Registry,
+    year: u16,
+    input_dir: &Path,
+    format: Format,
+    jobs: usize,
+) -> ExitCode {
+    let days = registry.days(year);
+    if days.is_empty() {
+        eprintln!("error: no solutions registered for {}", year);
+        return ExitCode::FAILURE;
+    }
+
+    let start = Instant::now();
+    let runs = run_days(registry, year, &days, input_dir, jobs);
+    let wall_time = start.elapsed();
+
+    let failed = runs.iter().any(|(_, run)| match run {
+        DayRun::Failed(_) => true,
+        DayRun::MissingInput => false,
+        DayRun::Ran {
+            part_one, part_two, ..
+        } => is_failure(&part_one.value) || is_failure(&part_two.value),
+    });
+
+    match format {
+        Format::Text => print_summary(&runs, wall_time),
+        Format::Json => {
+            for (day, run) in &runs {
+                Record::for_day(year, *day, run)
+                    .iter()
+                    .for_each(Record::print);
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// With several jobs the days overlap, so the total is the wall-clock time of
+// the whole run and the per-day times are summed separately
+fn print_summary(runs: &[(u8, DayRun)], wall_time: Duration) {
+    let mut rows: Vec<[String; 6]> = Vec::with_capacity(runs.len() + 1);
+    let mut totals: [Duration; 3] = [Duration::ZERO; 3];
+
+    for (day, run) in runs {
+        match run {
+            DayRun::Failed(err) => rows.push(message_row(*day, err)),
+            DayRun::MissingInput => rows.push(message_row(*day, "missing input")),
+            DayRun::Ran {
+                parse,
+                part_one,
+                part_two,
+            } => {
+                totals[0] += *parse;
+                totals[1] += part_one.elapsed;
+                totals[2] += part_two.elapsed;
+                rows.push([
+                    day.to_string(),
+                    format_duration(*parse),
+                    format_answer(&part_one.value),
+                    format_duration(part_one.elapsed),
+                    format_answer(&part_two.value),
+                    format_duration(part_two.elapsed),
+                ]);
+            }
+        }
+    }
+
+    rows.push([
+        "Total".to_string(),
+        format_duration(totals[0]),
+        String::new(),
+        format_duration(totals[1]),
+        String::new(),
+        format_duration(totals[2]),
+    ]);
+    print_table(HEADER, &rows);
+    println!("\nTotal time: {}", format_duration(wall_time));
+    println!(
+        "Summed day time: {}",
+        format_duration(totals.iter().sum::<Duration>())
+    );
+}
+
+// A day that never reached its parts gets the reason in the part one column
+fn message_row<E: fmt::Display>(day: u8, err: E) -> [String; 6] {
+    [
+        day.to_string(),
+        String::new(),
+        format!("error: {}", err),
+        String::new(),
+        String::new(),
+        String::new(),
+    ]
+}
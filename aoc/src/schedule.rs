@@ -0,0 +1,207 @@
+use crate::inputs::read_day_input;
+use crate::report::{timed, DayRun, Timed};
+use advent_of_code::{Answer, Registry, Result, Runnable};
+use std::any::Any;
+use std::collections::{BTreeMap, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use std::time::Duration;
+
+type Input = Arc<dyn Any + Send + Sync>;
+
+// Parsing a day queues its two parts, so both parts of a slow day can run
+// beside each other and beside other days
+enum Task {
+    Day(u8),
+    Part(u8, u8, Arc<dyn Runnable>, Input),
+}
+
+impl Task {
+    fn day(&self) -> u8 {
+        match self {
+            Task::Day(day) | Task::Part(day, ..) => *day,
+        }
+    }
+}
+
+struct Queue {
+    tasks: VecDeque<Task>,
+    // Tasks queued or still running, since a running day may queue more
+    pending: usize,
+}
+
+// What has come back for a day so far, in whatever order the workers finish
+#[derive(Default)]
+struct Slot {
+    outcome: Option<DayRun>,
+    parse: Duration,
+    parts: [Option<Timed<Result<Answer>>>; 2],
+}
+
+impl Slot {
+    fn into_run(self) -> DayRun {
+        if let Some(outcome) = self.outcome {
+            return outcome;
+        }
+
+        let [part_one, part_two] = self.parts;
+        DayRun::Ran {
+            parse: self.parse,
+            part_one: part_one.expect("part one ran"),
+            part_two: part_two.expect("part two ran"),
+        }
+    }
+}
+
+struct Scheduler<'a> {
+    registry: &'a Registry,
+    year: u16,
+    input_dir: &'a Path,
+    queue: Mutex<Queue>,
+    ready: Condvar,
+    slots: Mutex<BTreeMap<u8, Slot>>,
+}
+
+impl Scheduler<'_> {
+    fn next(&self) -> Option<Task> {
+        let mut queue = self.queue.lock().unwrap();
+        loop {
+            if let Some(task) = queue.tasks.pop_front() {
+                return Some(task);
+            }
+            if queue.pending == 0 {
+                return None;
+            }
+            queue = self.ready.wait(queue).unwrap();
+        }
+    }
+
+    fn finish(&self, follow_ups: Vec<Task>) {
+        let mut queue = self.queue.lock().unwrap();
+        queue.pending = queue.pending + follow_ups.len() - 1;
+        // A day's parts go to the front, so a single worker still runs each
+        // day to completion before parsing the next
+        for task in follow_ups.into_iter().rev() {
+            queue.tasks.push_front(task);
+        }
+        drop(queue);
+
+        self.ready.notify_all();
+    }
+
+    fn settle(&self, day: u8, outcome: DayRun) {
+        self.slots.lock().unwrap().entry(day).or_default().outcome = Some(outcome);
+    }
+
+    fn run(&self, task: Task) -> Vec<Task> {
+        match task {
+            Task::Day(day) => self.run_day(day),
+            Task::Part(day, part, solution, input) => {
+                let answer = timed(|| match part {
+                    1 => solution.part_one(input.as_ref()),
+                    _ => solution.part_two(input.as_ref()),
+                });
+                self.slots.lock().unwrap().entry(day).or_default().parts[part as usize - 1] =
+                    Some(answer);
+
+                Vec::new()
+            }
+        }
+    }
+
+    fn run_day(&self, day: u8) -> Vec<Task> {
+        // Every day gets its default options, since a whole year has no way
+        // to pass options to one day
+        let solution = match self.registry.solution(self.year, day, &[]) {
+            Some(Ok(solution)) => solution,
+            Some(Err(err)) => {
+                self.settle(day, DayRun::Failed(err.kind().to_string()));
+                return Vec::new();
+            }
+            None => return Vec::new(),
+        };
+        let puzzle_input = match read_day_input(self.input_dir, self.year, day) {
+            Ok(Some(puzzle_input)) => puzzle_input,
+            Ok(None) => {
+                self.settle(day, DayRun::MissingInput);
+                return Vec::new();
+            }
+            Err(err) => {
+                self.settle(day, DayRun::Failed(err.to_string()));
+                return Vec::new();
+            }
+        };
+
+        let parsed = timed(|| solution.parse(&puzzle_input));
+        let input: Input = match parsed.value {
+            Ok(input) => Arc::from(input),
+            Err(err) => {
+                self.settle(
+                    day,
+                    DayRun::Failed(format!("could not parse input: {}", err)),
+                );
+                return Vec::new();
+            }
+        };
+        self.slots.lock().unwrap().entry(day).or_default().parse = parsed.elapsed;
+
+        let solution: Arc<dyn Runnable> = Arc::from(solution);
+        vec![
+            Task::Part(day, 1, Arc::clone(&solution), Arc::clone(&input)),
+            Task::Part(day, 2, solution, input),
+        ]
+    }
+
+    fn work(&self) {
+        while let Some(task) = self.next() {
+            let day = task.day();
+            // A panicking day is reported as failed rather than leaving the
+            // other workers waiting on it forever
+            let follow_ups = match panic::catch_unwind(AssertUnwindSafe(|| self.run(task))) {
+                Ok(follow_ups) => follow_ups,
+                Err(_) => {
+                    self.settle(day, DayRun::Failed("panicked".to_string()));
+                    Vec::new()
+                }
+            };
+            self.finish(follow_ups);
+        }
+    }
+}
+
+// Runs the days on `jobs` worker threads and returns them in day order
+pub fn run_days(
+    registry: &Registry,
+    year: u16,
+    days: &[u8],
+    input_dir: &Path,
+    jobs: usize,
+) -> Vec<(u8, DayRun)> {
+    let scheduler = Scheduler {
+        registry,
+        year,
+        input_dir,
+        queue: Mutex::new(Queue {
+            tasks: days.iter().map(|&day| Task::Day(day)).collect(),
+            pending: days.len(),
+        }),
+        ready: Condvar::new(),
+        slots: Mutex::new(BTreeMap::new()),
+    };
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.max(1) {
+            scope.spawn(|| scheduler.work());
+        }
+    });
+
+    scheduler
+        .slots
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|(day, slot)| (day, slot.into_run()))
+        .collect()
+}
@@ -0,0 +1,296 @@
+use crate::answers::{load_json, save_json, AnswersFile};
+use crate::client::Client;
+use crate::inputs::{answers_path, input_path, submissions_path};
+use advent_of_code::{read_file_to_string, Answer, Registry};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// How long to hold off when the server says to wait but the page can't be read
+const DEFAULT_WAIT_SECONDS: u64 = 60;
+
+enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    Wait(u64),
+    AlreadySolved,
+    Unknown,
+}
+
+// The server answers with a full page, so the verdict is read off its wording
+fn parse_verdict(page: &str) -> Verdict {
+    if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::Wait(parse_wait_seconds(page).unwrap_or(DEFAULT_WAIT_SECONDS))
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        }
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else {
+        Verdict::Unknown
+    }
+}
+
+// Reads "You have 1m 23s left to wait" or "You have 45s left to wait"
+fn parse_wait_seconds(page: &str) -> Option<u64> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+
+    page[start..end]
+        .split_whitespace()
+        .try_fold(0, |seconds, amount| {
+            // Split on a char boundary so stray non-ASCII text can't panic
+            let (value, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "m" => Some(seconds + value * 60),
+                "s" => Some(seconds + value),
+                _ => None,
+            }
+        })
+}
+
+// A wrong answer locks out every submission for a while, e.g. "Please wait
+// one minute before trying again" or "please wait 5 minutes before trying again"
+fn parse_penalty_seconds(page: &str) -> Option<u64> {
+    // ASCII lowercasing keeps byte offsets, so they index `page` too
+    let lowercase = page.to_ascii_lowercase();
+    let start = lowercase.find("please wait ")? + "please wait ".len();
+    let end = start + lowercase[start..].find(" before trying again")?;
+
+    let mut words = page[start..end].split_whitespace();
+    let count: u64 = match words.next()? {
+        "one" => 1,
+        count => count.parse().ok()?,
+    };
+    match words.next()? {
+        "minute" | "minutes" => Some(count * 60),
+        "second" | "seconds" => Some(count),
+        _ => None,
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct PartHistory {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    incorrect: Vec<String>,
+    // The smallest answer known to be too high and the largest known to be too
+    // low, which bound where the right answer can be
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_high: Option<i128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    too_low: Option<i128>,
+}
+
+impl PartHistory {
+    // Why the answer is already known to be wrong, if it is
+    fn rejection(&self, answer: &Answer) -> Option<String> {
+        let text = answer.to_string();
+        if self.incorrect.contains(&text) {
+            return Some(format!("{} was already submitted and is incorrect", text));
+        }
+
+        if let Answer::Number(number) = answer {
+            if let Some(too_high) = self.too_high.filter(|too_high| number >= too_high) {
+                return Some(format!(
+                    "{} is not below {}, which is too high",
+                    number, too_high
+                ));
+            }
+            if let Some(too_low) = self.too_low.filter(|too_low| number <= too_low) {
+                return Some(format!(
+                    "{} is not above {}, which is too low",
+                    number, too_low
+                ));
+            }
+        }
+
+        None
+    }
+
+    fn record(&mut self, answer: &Answer, verdict: &Verdict) {
+        match (verdict, answer) {
+            (Verdict::TooHigh, Answer::Number(number)) => {
+                self.too_high = Some(self.too_high.map_or(*number, |high| high.min(*number)));
+            }
+            (Verdict::TooLow, Answer::Number(number)) => {
+                self.too_low = Some(self.too_low.map_or(*number, |low| low.max(*number)));
+            }
+            _ => {}
+        }
+        self.incorrect.push(answer.to_string());
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct DayHistory {
+    #[serde(default)]
+    part_one: PartHistory,
+    #[serde(default)]
+    part_two: PartHistory,
+}
+
+impl DayHistory {
+    fn part_mut(&mut self, part: u8) -> &mut PartHistory {
+        match part {
+            1 => &mut self.part_one,
+            _ => &mut self.part_two,
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct SubmissionHistory {
+    // Unix time before which the server rejects every submission
+    #[serde(skip_serializing_if = "Option::is_none")]
+    wait_until: Option<u64>,
+    #[serde(default)]
+    days: BTreeMap<u8, DayHistory>,
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+fn compute_answer(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    part: u8,
+    options: &[String],
+    input_dir: &Path,
+) -> Result<Answer, String> {
+    let solution = match registry.solution(year, day, options) {
+        Some(Ok(solution)) => solution,
+        Some(Err(err)) => err.exit(),
+        None => return Err(format!("no solution registered for {} day {}", year, day)),
+    };
+    let puzzle_input =
+        read_file_to_string(input_path(input_dir, year, day)).map_err(|err| err.to_string())?;
+    let parsed = solution
+        .parse(&puzzle_input)
+        .map_err(|err| format!("could not parse input: {}", err))?;
+
+    let answer = match part {
+        1 => solution.part_one(parsed.as_ref()),
+        _ => solution.part_two(parsed.as_ref()),
+    };
+    answer.map_err(|err| format!("part {}: {}", part, err))
+}
+
+// Returns whether the answer is known to be correct
+fn try_submit(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    part: u8,
+    options: &[String],
+    input_dir: &Path,
+    base_url: &str,
+) -> Result<bool, String> {
+    let history_path = submissions_path(input_dir, year);
+    let mut history: SubmissionHistory = load_json(&history_path)?;
+    if let Some(wait_until) = history.wait_until.filter(|&wait_until| wait_until > now()) {
+        return Err(format!(
+            "submitted too recently, wait {}s before trying again",
+            wait_until - now()
+        ));
+    }
+
+    let answers_path = answers_path(input_dir, year);
+    let mut answers: AnswersFile = load_json(&answers_path)?;
+    if let Some(known) = answers.entry(day).or_default().part_mut(part) {
+        println!("Already solved with {}", known);
+        return Ok(true);
+    }
+
+    let answer = compute_answer(registry, year, day, part, options, input_dir)?;
+    if answer.is_multi_line() {
+        return Err("multi-line answers have to be read and submitted by hand".to_string());
+    }
+    let day_history = history.days.entry(day).or_default();
+    if let Some(rejection) = day_history.part_mut(part).rejection(&answer) {
+        return Err(format!("not submitting, {}", rejection));
+    }
+
+    println!(
+        "Submitting {} for {} day {} part {}",
+        answer, year, day, part
+    );
+    let page = Client::new(base_url)
+        .and_then(|client| client.submit(year, day, part, &answer.to_string()))
+        .map_err(|err| format!("could not submit: {}", err))?;
+
+    let verdict = parse_verdict(&page);
+    let correct = match verdict {
+        Verdict::Correct => {
+            println!("That's the right answer!");
+            *answers.entry(day).or_default().part_mut(part) = Some(answer);
+            save_json(&answers_path, &answers)?;
+            true
+        }
+        Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow => {
+            let hint = match verdict {
+                Verdict::TooHigh => ", it is too high",
+                Verdict::TooLow => ", it is too low",
+                _ => "",
+            };
+            println!("That's not the right answer{}", hint);
+            day_history.part_mut(part).record(&answer, &verdict);
+            if let Some(seconds) = parse_penalty_seconds(&page) {
+                println!("Wait {}s before trying again", seconds);
+                history.wait_until = Some(now() + seconds);
+            }
+            false
+        }
+        Verdict::Wait(seconds) => {
+            println!(
+                "Submitted too recently, wait {}s before trying again",
+                seconds
+            );
+            history.wait_until = Some(now() + seconds);
+            false
+        }
+        Verdict::AlreadySolved => {
+            println!("This part is already solved or not unlocked yet");
+            false
+        }
+        Verdict::Unknown => return Err("could not read the verdict from the response".to_string()),
+    };
+
+    save_json(&history_path, &history)?;
+
+    Ok(correct)
+}
+
+pub fn submit(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    part: u8,
+    options: &[String],
+    input_dir: &Path,
+    base_url: &str,
+) -> ExitCode {
+    match try_submit(registry, year, day, part, options, input_dir, base_url) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::answers::{load_json, AnswersFile, ExpectedAnswers};
+use crate::inputs::{answers_path, read_day_input};
+use advent_of_code::{Answer, Registry, Result};
+use std::path::Path;
+use std::process::ExitCode;
+
+enum Outcome {
+    Pass,
+    Fail(String),
+    Missing(String),
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+impl Summary {
+    fn report(&mut self, year: u16, day: u8, part: &str, outcome: Outcome) {
+        let label = format!("{} day {:02} part {}", year, day, part);
+        match outcome {
+            Outcome::Pass => {
+                self.passed += 1;
+                println!("{}: pass", label);
+            }
+            Outcome::Fail(reason) => {
+                self.failed += 1;
+                println!("{}: FAIL ({})", label, reason);
+            }
+            Outcome::Missing(reason) => {
+                self.missing += 1;
+                println!("{}: missing ({})", label, reason);
+            }
+        }
+    }
+}
+
+// The puzzle checks submitted text, so an answer file may store a numeric
+// answer as either a number or a string
+fn compare(expected: &Option<Answer>, got: Result<Answer>) -> Outcome {
+    match (expected, got) {
+        (Some(expected), Ok(got)) if expected.to_string() == got.to_string() => Outcome::Pass,
+        (Some(expected), Ok(got)) => Outcome::Fail(format!("expected {}, got {}", expected, got)),
+        (Some(expected), Err(err)) => Outcome::Fail(format!("expected {}, got {}", expected, err)),
+        (None, Ok(got)) => Outcome::Missing(format!("no expected answer, got {}", got)),
+        (None, Err(err)) => Outcome::Missing(format!("no expected answer, got {}", err)),
+    }
+}
+
+fn verify_day(
+    registry: &Registry,
+    year: u16,
+    day: u8,
+    input_dir: &Path,
+    expected: &ExpectedAnswers,
+    summary: &mut Summary,
+) {
+    let expected_parts = [("one", &expected.part_one), ("two", &expected.part_two)];
+
+    // Every part shares the same fate when the day cannot be run at all
+    let report_all = |summary: &mut Summary, reason: String| {
+        for (part, expected) in expected_parts {
+            let outcome = match expected {
+                Some(_) => Outcome::Fail(reason.clone()),
+                None => Outcome::Missing(reason.clone()),
+            };
+            summary.report(year, day, part, outcome);
+        }
+    };
+
+    // Answers are checked with each day's default options, like run --all
+    let solution = match registry.solution(year, day, &[]) {
+        Some(Ok(solution)) => solution,
+        Some(Err(err)) => return report_all(summary, err.kind().to_string()),
+        None => return report_all(summary, "no registered solution".to_string()),
+    };
+
+    let puzzle_input = match read_day_input(input_dir, year, day) {
+        Ok(Some(puzzle_input)) => puzzle_input,
+        Err(err) => return report_all(summary, err.to_string()),
+        Ok(None) => {
+            // Without an input there is nothing to compare against
+            for (part, _) in expected_parts {
+                summary.report(year, day, part, Outcome::Missing("no input".to_string()));
+            }
+            return;
+        }
+    };
+
+    let input = match solution.parse(&puzzle_input) {
+        Ok(input) => input,
+        Err(err) => return report_all(summary, format!("could not parse input: {}", err)),
+    };
+
+    summary.report(
+        year,
+        day,
+        "one",
+        compare(&expected.part_one, solution.part_one(input.as_ref())),
+    );
+    summary.report(
+        year,
+        day,
+        "two",
+        compare(&expected.part_two, solution.part_two(input.as_ref())),
+    );
+}
+
+pub fn verify(registry: &Registry, years: &[u16], day: Option<u8>, input_dir: &Path) -> ExitCode {
+    let mut summary = Summary::default();
+    let unanswered = ExpectedAnswers::default();
+
+    for &year in years {
+        let answers = match load_json::<AnswersFile>(&answers_path(input_dir, year)) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("error: {}", err);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let days: Vec<u8> = match day {
+            Some(day) => vec![day],
+            None => registry.days(year),
+        };
+        for day in days {
+            let expected = answers.get(&day).unwrap_or(&unanswered);
+            verify_day(registry, year, day, input_dir, expected, &mut summary);
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        summary.passed, summary.failed, summary.missing
+    );
+
+    if summary.failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,46 @@
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+fn bench(input_dir: &Path, extra: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["bench", "--year", "2025", "--day", "1", "--samples", "3"])
+        .arg("--input-dir")
+        .arg(input_dir)
+        .args(extra)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn bench_saves_a_baseline_and_flags_regressions_against_it() {
+    let input_dir = temp_dir("bench-baseline");
+    fs::create_dir_all(input_dir.join("2025")).unwrap();
+    fs::write(
+        input_dir.join("2025").join("day_01.txt"),
+        include_str!("../../examples/2025/01/example.txt"),
+    )
+    .unwrap();
+    let baselines = input_dir.join("2025").join("bench.json");
+
+    assert!(bench(&input_dir, &["--save-baseline"]).status.success());
+    let saved: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&baselines).unwrap()).unwrap();
+    for stage in ["parse", "part_one", "part_two"] {
+        assert!(saved["1"][stage].is_u64());
+    }
+
+    // Nothing runs in a nanosecond, so every stage is now a regression
+    fs::write(
+        &baselines,
+        r#"{"1": {"parse": 1, "part_one": 1, "part_two": 1}}"#,
+    )
+    .unwrap();
+    let output = bench(&input_dir, &[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("3 stage(s) regressed"));
+}
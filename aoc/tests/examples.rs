@@ -0,0 +1,58 @@
+use advent_of_code::Answer;
+use aoc::registry;
+use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+
+// Expected answers for one example input. A part without an answer isn't
+// checked, since many examples only apply to one part.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    #[serde(default)]
+    options: Vec<String>,
+    part_one: Option<Answer>,
+    part_two: Option<Answer>,
+}
+
+fn check_example(year: u16, day: u8, name: &str) {
+    let day_dir: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "..",
+        "examples",
+        &year.to_string(),
+        &format!("{:02}", day),
+    ]
+    .iter()
+    .collect();
+
+    let input = fs::read_to_string(day_dir.join(format!("{}.txt", name))).unwrap();
+    let fixture: Fixture = match fs::read_to_string(day_dir.join(format!("{}.json", name))) {
+        Ok(contents) => serde_json::from_str(&contents).unwrap(),
+        Err(_) => Fixture::default(),
+    };
+    // A freshly scaffolded example checks nothing, so it fails until filled in
+    assert!(!input.trim().is_empty(), "{}.txt is empty", name);
+    assert!(
+        fixture.part_one.is_some() || fixture.part_two.is_some(),
+        "{}.json has no expected answers",
+        name
+    );
+
+    let solution = registry()
+        .solution(year, day, &fixture.options)
+        .expect("no solution registered for the example's day")
+        .unwrap();
+    let parsed = solution.parse(&input).unwrap();
+
+    if let Some(expected) = fixture.part_one {
+        let got = solution.part_one(parsed.as_ref()).unwrap();
+        assert_eq!(got.to_string(), expected.to_string(), "part one");
+    }
+    if let Some(expected) = fixture.part_two {
+        let got = solution.part_two(parsed.as_ref()).unwrap();
+        assert_eq!(got.to_string(), expected.to_string(), "part two");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
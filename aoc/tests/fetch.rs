@@ -0,0 +1,122 @@
+use std::fs;
+use std::process::Command;
+
+mod common;
+
+use common::{temp_dir, StubServer};
+
+fn aoc() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    command.env_remove("AOC_BASE_URL");
+
+    command
+}
+
+#[test]
+fn fetch_downloads_once_and_then_uses_the_cache() {
+    let server = StubServer::serve(vec![(200, "1\n2\n")]);
+    let input_dir = temp_dir("fetch-cache");
+    let fetch = || {
+        aoc()
+            .args(["fetch", "--year", "2022", "--day", "1", "--base-url"])
+            .arg(&server.base_url)
+            .arg("--input-dir")
+            .arg(&input_dir)
+            .env("AOC_SESSION", "secret")
+            .output()
+            .unwrap()
+    };
+
+    assert!(fetch().status.success());
+    assert_eq!(
+        fs::read_to_string(input_dir.join("2022").join("day_01.txt")).unwrap(),
+        "1\n2\n"
+    );
+
+    let cached = fetch();
+    assert!(cached.status.success());
+    assert!(String::from_utf8_lossy(&cached.stdout).contains("already cached"));
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("GET /2022/day/1/input "));
+    assert!(requests[0]
+        .to_lowercase()
+        .contains("cookie: session=secret"));
+}
+
+#[test]
+fn fetch_without_a_session_fails() {
+    let input_dir = temp_dir("fetch-no-session");
+    let output = aoc()
+        .args(["fetch", "--year", "2022", "--day", "1", "--base-url"])
+        .arg("http://127.0.0.1:9")
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .env_remove("AOC_SESSION")
+        .env("XDG_CONFIG_HOME", &input_dir)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_SESSION"));
+}
+
+#[test]
+fn fetch_treats_a_blank_session_as_missing() {
+    let input_dir = temp_dir("fetch-blank-session");
+    fs::create_dir_all(input_dir.join("aoc")).unwrap();
+    fs::write(input_dir.join("aoc").join("session"), " \n").unwrap();
+    let output = aoc()
+        .args(["fetch", "--year", "2022", "--day", "1", "--base-url"])
+        .arg("http://127.0.0.1:9")
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .env("AOC_SESSION", "  ")
+        .env("XDG_CONFIG_HOME", &input_dir)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("AOC_SESSION"));
+}
+
+#[test]
+fn fetch_falls_back_to_the_session_file_when_the_variable_is_blank() {
+    let server = StubServer::serve(vec![(200, "1\n2\n")]);
+    let input_dir = temp_dir("fetch-session-file");
+    fs::create_dir_all(input_dir.join("aoc")).unwrap();
+    fs::write(input_dir.join("aoc").join("session"), "from-file\n").unwrap();
+    let output = aoc()
+        .args(["fetch", "--year", "2022", "--day", "1", "--base-url"])
+        .arg(&server.base_url)
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .env("AOC_SESSION", "")
+        .env("XDG_CONFIG_HOME", &input_dir)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(server.requests()[0]
+        .to_lowercase()
+        .contains("cookie: session=from-file"));
+}
+
+#[test]
+fn fetch_reports_server_errors() {
+    let server = StubServer::serve(vec![(404, "Not found")]);
+    let input_dir = temp_dir("fetch-404");
+    let output = aoc()
+        .args(["fetch", "--year", "2022", "--day", "1", "--base-url"])
+        .arg(&server.base_url)
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .env("AOC_SESSION", "secret")
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("404"));
+    assert!(!input_dir.join("2022").join("day_01.txt").exists());
+}
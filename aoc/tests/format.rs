@@ -0,0 +1,178 @@
+use serde_json::Value;
+use std::fs;
+use std::process::Command;
+
+mod common;
+
+use common::temp_dir;
+
+const EXAMPLE: &str = include_str!("../../examples/2025/01/example.txt");
+
+fn records(stdout: &[u8]) -> Vec<Value> {
+    String::from_utf8_lossy(stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect()
+}
+
+#[test]
+fn run_prints_a_json_record_per_part() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--year", "2025", "--day", "1", "--format", "json"])
+        .args(["--input-string", EXAMPLE])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let records = records(&output.stdout);
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0]["year"], 2025);
+    assert_eq!(records[0]["day"], 1);
+    assert_eq!(records[0]["part"], 1);
+    assert_eq!(records[0]["status"], "solved");
+    assert_eq!(records[0]["answer"], 3);
+    assert!(records[0]["duration_ms"].is_f64());
+    assert_eq!(records[1]["answer"], 6);
+}
+
+#[test]
+fn run_all_reports_missing_inputs_as_json() {
+    let input_dir = temp_dir("format-all");
+    fs::create_dir_all(input_dir.join("2025")).unwrap();
+    fs::write(input_dir.join("2025").join("day_01.txt"), EXAMPLE).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--year", "2025", "--all", "--format", "json"])
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let records = records(&output.stdout);
+    assert_eq!(records[0]["answer"], 3);
+    assert!(records[2..]
+        .iter()
+        .all(|record| record["status"] == "missing_input"));
+}
+
+#[test]
+fn run_all_keeps_day_order_across_jobs() {
+    let input_dir = temp_dir("format-jobs");
+    fs::create_dir_all(input_dir.join("2025")).unwrap();
+    for day in [1, 5, 9] {
+        fs::copy(
+            format!("../examples/2025/{:02}/example.txt", day),
+            input_dir.join("2025").join(format!("day_{:02}.txt", day)),
+        )
+        .unwrap();
+    }
+
+    let answers = |jobs: &str| {
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", "--year", "2025", "--all", "--format", "json"])
+            .args(["--jobs", jobs, "--input-dir"])
+            .arg(&input_dir)
+            .output()
+            .unwrap();
+        records(&output.stdout)
+            .into_iter()
+            .map(|record| {
+                (
+                    record["day"].clone(),
+                    record["part"].clone(),
+                    record["answer"].clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let sequential = answers("1");
+    assert_eq!(sequential[0].2, 3);
+    assert_eq!(sequential, answers("4"));
+}
+
+const RUCKSACKS: &str = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw
+";
+
+const ROCK_PATHS: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9
+";
+
+#[test]
+fn run_all_prints_only_json_even_with_debug_logging() {
+    let input_dir = temp_dir("format-json-lines");
+    let write = |year: &str, day: u8, input: &str| {
+        fs::create_dir_all(input_dir.join(year)).unwrap();
+        fs::write(
+            input_dir.join(year).join(format!("day_{:02}.txt", day)),
+            input,
+        )
+        .unwrap();
+    };
+    // Days whose debugging output used to go to stdout
+    write("2022", 3, RUCKSACKS);
+    write(
+        "2022",
+        11,
+        &fs::read_to_string("../examples/2022/11/example.txt").unwrap(),
+    );
+    write("2022", 14, ROCK_PATHS);
+    write(
+        "2023",
+        21,
+        &fs::read_to_string("../examples/2023/21/example.txt").unwrap(),
+    );
+
+    for year in ["2022", "2023"] {
+        let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+            .args(["run", "--year", year, "--all", "--format", "json"])
+            .arg("--input-dir")
+            .arg(&input_dir)
+            .env("RUST_LOG", "debug")
+            .output()
+            .unwrap();
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for line in stdout.lines() {
+            assert!(
+                serde_json::from_str::<Value>(line).is_ok(),
+                "{} printed a line that is not JSON: {:?}",
+                year,
+                line
+            );
+        }
+        assert!(records(&output.stdout)
+            .iter()
+            .any(|record| record["status"] == "solved"));
+    }
+}
+
+#[test]
+fn run_all_reports_an_unreadable_input_as_an_error() {
+    let input_dir = temp_dir("format-unreadable");
+    // A directory where the input should be can't be read, but isn't missing
+    fs::create_dir_all(input_dir.join("2025").join("day_01.txt")).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--year", "2025", "--all", "--format", "json"])
+        .arg("--input-dir")
+        .arg(&input_dir)
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let records = records(&output.stdout);
+    assert_eq!(records[0]["day"], 1);
+    assert_eq!(records[0]["status"], "error");
+    assert!(records[0]["error"]
+        .as_str()
+        .unwrap()
+        .starts_with("could not read input"));
+    assert_eq!(records[2]["status"], "missing_input");
+}
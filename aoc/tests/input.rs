@@ -0,0 +1,95 @@
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const EXAMPLE: &str = include_str!("../../examples/2025/01/example.txt");
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    run_day("2025", "1", args, stdin)
+}
+
+fn run_day(year: &str, day: &str, args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--year", year, "--day", day])
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn run_reads_the_input_from_stdin() {
+    let output = run(&["--input", "-"], EXAMPLE);
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Part one answer: 3"));
+    assert!(stdout.contains("Part two answer: 6"));
+}
+
+#[test]
+fn run_reads_an_inline_input() {
+    let output = run(&["--input-string", EXAMPLE], "");
+
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Part one answer: 3"));
+}
+
+// Every registered day must turn bad input into a parse error on the first
+// line rather than a panic, which exits with 101, or a made-up answer
+#[test]
+fn run_rejects_malformed_input_for_every_day() {
+    let registry = aoc::registry();
+
+    for year in registry.years() {
+        for day in registry.days(year) {
+            let (year, day) = (year.to_string(), day.to_string());
+            let output = run_day(&year, &day, &["--input-string", "~"], "");
+
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            assert!(!output.status.success(), "{} day {}", year, day);
+            assert_ne!(output.status.code(), Some(101), "{} day {}", year, day);
+            assert!(
+                !stderr.contains("panicked"),
+                "{} day {}: {}",
+                year,
+                day,
+                stderr
+            );
+            assert!(
+                stderr.contains("could not parse input: line 1"),
+                "{} day {}: {}",
+                year,
+                day,
+                stderr
+            );
+        }
+    }
+}
+
+#[test]
+fn run_rejects_an_odd_number_of_seeds_in_part_two() {
+    let output = run_day(
+        "2023",
+        "5",
+        &[
+            "--input-string",
+            "seeds: 79 14 55\n\nseed-to-soil map:\n50 98 2\n",
+        ],
+        "",
+    );
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Part one answer: 14"));
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .contains("part two: line 1: expected pairs of a start and a length, found 3 numbers"));
+}
@@ -0,0 +1,104 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+const LIB: &str = "use advent_of_code::Registry;
+
+mod day_01;
+mod day_05;
+
+pub fn register(registry: &mut Registry) {
+    registry.register::<day_01::DayOne>(2030, 1);
+    registry.register::<day_05::DayFive>(2030, 5);
+}
+";
+
+// A workspace with one year holding days 1 and 5 and the day template
+fn workspace(name: &str) -> PathBuf {
+    let workspace_dir = temp_dir(name);
+    let source_dir = workspace_dir.join("2030").join("src");
+    fs::create_dir_all(&source_dir).unwrap();
+    fs::write(workspace_dir.join("Cargo.toml"), "[workspace]\n").unwrap();
+    fs::write(source_dir.join("lib.rs"), LIB).unwrap();
+    fs::write(source_dir.join("day_00.rs"), "pub struct DayZero;\n").unwrap();
+
+    workspace_dir
+}
+
+fn new(current_dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "--year", "2030", "--day", "3"])
+        .args(args)
+        .current_dir(current_dir)
+        .output()
+        .unwrap()
+}
+
+fn assert_scaffolded(workspace_dir: &Path) {
+    let source_dir = workspace_dir.join("2030").join("src");
+    assert_eq!(
+        fs::read_to_string(source_dir.join("day_03.rs")).unwrap(),
+        "pub struct DayThree;\n"
+    );
+
+    let lib = fs::read_to_string(source_dir.join("lib.rs")).unwrap();
+    assert!(lib.contains("mod day_01;\nmod day_03;\nmod day_05;\n"));
+    assert!(lib.contains(
+        "    registry.register::<day_01::DayOne>(2030, 1);
+    registry.register::<day_03::DayThree>(2030, 3);
+    registry.register::<day_05::DayFive>(2030, 5);
+"
+    ));
+
+    let example_dir = workspace_dir.join("examples").join("2030").join("03");
+    assert_eq!(
+        fs::read_to_string(example_dir.join("example.txt")).unwrap(),
+        ""
+    );
+    assert!(example_dir.join("example.json").exists());
+}
+
+#[test]
+fn new_scaffolds_into_the_given_workspace() {
+    let workspace_dir = workspace("new-workspace-dir");
+
+    let output = new(
+        &env::temp_dir(),
+        &["--workspace-dir", workspace_dir.to_str().unwrap()],
+    );
+
+    assert!(output.status.success());
+    assert_scaffolded(&workspace_dir);
+}
+
+#[test]
+fn new_finds_the_workspace_from_a_subdirectory() {
+    let workspace_dir = workspace("new-subdirectory");
+
+    let output = new(&workspace_dir.join("2030").join("src"), &[]);
+
+    assert!(output.status.success());
+    assert_scaffolded(&workspace_dir);
+    assert!(!workspace_dir.join("2030").join("src").join("2030").exists());
+}
+
+#[test]
+fn new_refuses_to_overwrite_a_day() {
+    let workspace_dir = workspace("new-existing");
+    let day_path = workspace_dir.join("2030").join("src").join("day_03.rs");
+    fs::write(&day_path, "// solved\n").unwrap();
+
+    let output = new(&workspace_dir, &[]);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(fs::read_to_string(&day_path).unwrap(), "// solved\n");
+    assert_eq!(
+        fs::read_to_string(workspace_dir.join("2030").join("src").join("lib.rs")).unwrap(),
+        LIB
+    );
+}
@@ -0,0 +1,115 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+mod common;
+
+use common::{temp_dir, StubServer};
+
+const EXAMPLE: &str = include_str!("../../examples/2025/01/example.txt");
+
+fn input_dir_with_example(name: &str) -> PathBuf {
+    let input_dir = temp_dir(name);
+    fs::create_dir_all(input_dir.join("2025")).unwrap();
+    fs::write(input_dir.join("2025").join("day_01.txt"), EXAMPLE).unwrap();
+
+    input_dir
+}
+
+fn submit(server: &StubServer, input_dir: &Path, part: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["submit", "--year", "2025", "--day", "1", "--part", part])
+        .arg("--base-url")
+        .arg(&server.base_url)
+        .arg("--input-dir")
+        .arg(input_dir)
+        .env("AOC_SESSION", "secret")
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn submit_records_a_correct_answer() {
+    let server = StubServer::serve(vec![(200, "<p>That's the right answer!</p>")]);
+    let input_dir = input_dir_with_example("submit-correct");
+
+    assert!(submit(&server, &input_dir, "1").status.success());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert!(requests[0].starts_with("POST /2025/day/1/answer "));
+    assert!(requests[0].ends_with("level=1&answer=3"));
+
+    let answers = fs::read_to_string(input_dir.join("2025").join("answers.json")).unwrap();
+    assert!(answers.contains("\"part_one\": 3"));
+
+    // Already known to be correct, so nothing is sent again
+    assert!(submit(&server, &input_dir, "1").status.success());
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_does_not_resend_an_answer_known_to_be_too_high() {
+    let server = StubServer::serve(vec![(
+        200,
+        "<p>That's not the right answer; your answer is too high.</p>",
+    )]);
+    let input_dir = input_dir_with_example("submit-too-high");
+
+    assert!(!submit(&server, &input_dir, "2").status.success());
+    let history = fs::read_to_string(input_dir.join("2025").join("submissions.json")).unwrap();
+    assert!(history.contains("\"too_high\": 6"));
+
+    let refused = submit(&server, &input_dir, "2");
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("already submitted"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_waits_out_the_rate_limit() {
+    let server = StubServer::serve(vec![(
+        200,
+        "<p>You gave an answer too recently. You have 1m 23s left to wait.</p>",
+    )]);
+    let input_dir = input_dir_with_example("submit-wait");
+
+    assert!(!submit(&server, &input_dir, "1").status.success());
+
+    let refused = submit(&server, &input_dir, "1");
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("too recently"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_waits_out_the_penalty_for_a_wrong_answer() {
+    let server = StubServer::serve(vec![(
+        200,
+        "<p>That's not the right answer. Please wait one minute before trying again.</p>",
+    )]);
+    let input_dir = input_dir_with_example("submit-penalty");
+
+    assert!(!submit(&server, &input_dir, "1").status.success());
+    let history = fs::read_to_string(input_dir.join("2025").join("submissions.json")).unwrap();
+    assert!(history.contains("\"wait_until\""));
+
+    // The lockout covers every part, not only the one answered wrongly
+    let refused = submit(&server, &input_dir, "2");
+    assert!(!refused.status.success());
+    assert!(String::from_utf8_lossy(&refused.stderr).contains("too recently"));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn submit_falls_back_to_the_default_wait_on_unreadable_text() {
+    let server = StubServer::serve(vec![(
+        200,
+        "<p>You gave an answer too recently. You have 1m 2é left to wait.</p>",
+    )]);
+    let input_dir = input_dir_with_example("submit-unreadable-wait");
+
+    let output = submit(&server, &input_dir, "1");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("wait 60s"));
+}
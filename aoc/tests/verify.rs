@@ -0,0 +1,59 @@
+use std::fs;
+use std::process::{Command, Output};
+
+mod common;
+
+use common::temp_dir;
+
+const EXAMPLE: &str = include_str!("../../examples/2025/01/example.txt");
+
+// Verifies 2025 day 1 against the example, whose answers are 3 and 6
+fn verify(name: &str, answers: &str) -> Output {
+    let input_dir = temp_dir(name);
+    fs::create_dir_all(input_dir.join("2025")).unwrap();
+    fs::write(input_dir.join("2025").join("day_01.txt"), EXAMPLE).unwrap();
+    fs::write(input_dir.join("2025").join("answers.json"), answers).unwrap();
+
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["verify", "--year", "2025", "--day", "1", "--input-dir"])
+        .arg(&input_dir)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn verify_passes_matching_answers() {
+    let output = verify(
+        "verify-pass",
+        r#"{ "1": { "part_one": 3, "part_two": "6" } }"#,
+    );
+
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("2025 day 01 part one: pass"));
+    assert!(stdout.contains("2025 day 01 part two: pass"));
+    assert!(stdout.contains("2 passed, 0 failed, 0 missing"));
+}
+
+#[test]
+fn verify_fails_a_mismatched_answer() {
+    let output = verify(
+        "verify-fail",
+        r#"{ "1": { "part_one": 4, "part_two": 6 } }"#,
+    );
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("2025 day 01 part one: FAIL (expected 4, got 3)"));
+    assert!(stdout.contains("1 passed, 1 failed, 0 missing"));
+}
+
+#[test]
+fn verify_reports_a_missing_answer_without_failing() {
+    let output = verify("verify-missing", r#"{ "1": { "part_one": 3 } }"#);
+
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("2025 day 01 part two: missing (no expected answer, got 6)"));
+    assert!(stdout.contains("1 passed, 0 failed, 1 missing"));
+}
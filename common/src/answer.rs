@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// The result of a single puzzle part. Every integer type a day happens to
+// compute with fits in `Number`, so answers compare equal regardless of the
+// width the solver used. Serialized untagged, so a number, a string or an array
+// of lines.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged, from = "AnswerRepr")]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    // Answers read off a rendered grid, e.g. letters drawn on a CRT
+    Lines(Vec<String>),
+}
+
+// Untagged enums can't buffer an i128, so numbers are read as whichever of the
+// 64-bit types fits
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum AnswerRepr {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+    Lines(Vec<String>),
+}
+
+impl From<AnswerRepr> for Answer {
+    fn from(repr: AnswerRepr) -> Answer {
+        match repr {
+            AnswerRepr::Signed(number) => number.into(),
+            AnswerRepr::Unsigned(number) => number.into(),
+            AnswerRepr::Text(text) => Answer::Text(text),
+            AnswerRepr::Lines(lines) => Answer::Lines(lines),
+        }
+    }
+}
+
+impl Answer {
+    pub fn is_multi_line(&self) -> bool {
+        matches!(self, Answer::Lines(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Lines(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Answer {
+                    Answer::Number(number as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for Answer {
+    fn from(lines: Vec<String>) -> Answer {
+        Answer::Lines(lines)
+    }
+}
@@ -0,0 +1,198 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Where a simulation starts repeating. Step `start` is the first step of the
+// cycle and step `start + length` is the first one equal to an earlier step,
+// counting the initial state as step 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The earliest step whose state matches the one at `step`, which is always
+    // below `start + length`
+    pub fn equivalent_step(&self, step: u64) -> usize {
+        let start = self.start as u64;
+        if step < start {
+            return step as usize;
+        }
+
+        (start + (step - start) % self.length as u64) as usize
+    }
+
+    // Replays from `initial` to the state at step `target`, taking at most
+    // `start + length` steps
+    pub fn state_at<S, FS>(&self, mut initial: S, mut step: FS, target: u64) -> S
+    where
+        FS: FnMut(&mut S),
+    {
+        for _ in 0..self.equivalent_step(target) {
+            step(&mut initial);
+        }
+
+        initial
+    }
+
+    // A metric at any step, for metrics like a tower's height that grow by the
+    // same amount every time round the cycle. `value_at` is only asked for
+    // steps up to `start + length`.
+    pub fn extrapolate<F>(&self, step: u64, mut value_at: F) -> i64
+    where
+        F: FnMut(usize) -> i64,
+    {
+        let start = self.start as u64;
+        if step < start {
+            return value_at(step as usize);
+        }
+
+        let cycles = ((step - start) / self.length as u64) as i64;
+        let per_cycle = value_at(self.start + self.length) - value_at(self.start);
+
+        value_at(self.equivalent_step(step)) + cycles * per_cycle
+    }
+}
+
+// Steps `state` in place until its key repeats, remembering the key of every
+// step. Leaves `state` at step `start + length`, so anything the state records
+// along the way covers the whole cycle. Never returns if no key repeats.
+pub fn find_cycle<S, K, FS, FK>(state: &mut S, mut step: FS, mut key: FK) -> Cycle
+where
+    K: Hash + Eq,
+    FS: FnMut(&mut S),
+    FK: FnMut(&S) -> K,
+{
+    let mut seen: HashMap<K, usize> = HashMap::new();
+
+    for index in 0.. {
+        if let Some(start) = seen.insert(key(state), index) {
+            return Cycle {
+                start,
+                length: index - start,
+            };
+        }
+        step(state);
+    }
+
+    unreachable!("a step count cannot run out")
+}
+
+// Brent's algorithm, which finds the same cycle as `find_cycle` while keeping
+// only two states and no keys, at the cost of stepping more than once through
+// the cycle. Suits small states whose cycles are too long to remember.
+pub fn brent<S, K, FS, FK>(initial: &S, mut step: FS, mut key: FK) -> Cycle
+where
+    S: Clone,
+    K: PartialEq,
+    FS: FnMut(&mut S),
+    FK: FnMut(&S) -> K,
+{
+    // Find the length by letting the hare run ahead in doubling stretches
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    step(&mut hare);
+    while key(&tortoise) != key(&hare) {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        step(&mut hare);
+        length += 1;
+    }
+
+    // Then find the start by walking two states `length` steps apart
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ... which repeats from step 3 every 5 steps
+    fn step(value: &mut u32) {
+        *value = if *value == 7 { 3 } else { *value + 1 };
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let mut state = 0;
+        let cycle = find_cycle(&mut state, step, |&value| value);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                length: 5
+            }
+        );
+        assert_eq!(state, 3);
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(
+            brent(&0, step, |&value| value),
+            Cycle {
+                start: 3,
+                length: 5
+            }
+        );
+        assert_eq!(
+            brent(&3, step, |&value| value),
+            Cycle {
+                start: 0,
+                length: 5
+            }
+        );
+    }
+
+    #[test]
+    fn test_state_at() {
+        let cycle = Cycle {
+            start: 3,
+            length: 5,
+        };
+        assert_eq!(cycle.equivalent_step(2), 2);
+        assert_eq!(cycle.equivalent_step(8), 3);
+        assert_eq!(cycle.equivalent_step(1_000_000_000), 5);
+        assert_eq!(cycle.state_at(0, step, 1_000_000_000), 5);
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        // A total that gains the state's value at every step
+        let mut totals = vec![0];
+        let mut state = 0;
+        let cycle = find_cycle(
+            &mut state,
+            |value| {
+                step(value);
+                totals.push(totals.last().unwrap() + *value as i64);
+            },
+            |&value| value,
+        );
+
+        let mut simulated = (0, 0);
+        for _ in 0..1000 {
+            step(&mut simulated.0);
+            simulated.1 += simulated.0 as i64;
+        }
+        assert_eq!(cycle.extrapolate(1000, |step| totals[step]), simulated.1);
+        assert_eq!(cycle.extrapolate(2, |step| totals[step]), 3);
+    }
+}
@@ -0,0 +1,68 @@
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[derive(Debug)]
+pub enum Error {
+    InputNotFound {
+        path: PathBuf,
+        source: io::Error,
+    },
+    // Line and column numbers are 1-based so they match what an editor shows
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        message: String,
+    },
+    Unsolved,
+}
+
+impl Error {
+    pub fn parse<M: Into<String>>(line: usize, message: M) -> Error {
+        Error::Parse {
+            line,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn parse_at<M: Into<String>>(line: usize, column: usize, message: M) -> Error {
+        Error::Parse {
+            line,
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::InputNotFound { path, source } => {
+                write!(f, "could not read input {}: {}", path.display(), source)
+            }
+            Error::Parse {
+                line,
+                column: None,
+                message,
+            } => write!(f, "line {}: {}", line, message),
+            Error::Parse {
+                line,
+                column: Some(column),
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::Unsolved => write!(f, "not solved yet"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::InputNotFound { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use crate::pathfinding::count_paths;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+// Nodes are numbered densely in the order they were first named
+pub type NodeId = usize;
+
+// Hands out one id per distinct name and maps ids back to names
+#[derive(Clone, Debug, Default)]
+pub struct Interner {
+    ids: HashMap<String, NodeId>,
+    names: Vec<String>,
+}
+
+impl Interner {
+    pub fn new() -> Interner {
+        Interner::default()
+    }
+
+    pub fn intern(&mut self, name: &str) -> NodeId {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        &self.names[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+// Returned by a topological sort when some nodes depend on each other
+#[derive(Debug, PartialEq, Eq)]
+pub struct CycleError {
+    // Every node that is on a cycle or can only be reached through one
+    pub nodes: Vec<NodeId>,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the graph has a cycle; {} nodes cannot be ordered",
+            self.nodes.len()
+        )
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+// A graph over named nodes. Undirected graphs store every edge both ways.
+#[derive(Clone, Debug)]
+pub struct Graph {
+    directed: bool,
+    interner: Interner,
+    adjacency: Vec<BTreeSet<NodeId>>,
+}
+
+impl Graph {
+    pub fn directed() -> Graph {
+        Graph {
+            directed: true,
+            interner: Interner::new(),
+            adjacency: Vec::new(),
+        }
+    }
+
+    pub fn undirected() -> Graph {
+        Graph {
+            directed: false,
+            ..Graph::directed()
+        }
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn add_node(&mut self, name: &str) -> NodeId {
+        let id = self.interner.intern(name);
+        if id == self.adjacency.len() {
+            self.adjacency.push(BTreeSet::new());
+        }
+
+        id
+    }
+
+    // Adds both nodes if they are new. Adding an edge twice has no effect.
+    pub fn add_edge(&mut self, from: &str, to: &str) -> (NodeId, NodeId) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.adjacency[from].insert(to);
+        if !self.directed {
+            self.adjacency[to].insert(from);
+        }
+
+        (from, to)
+    }
+
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.interner.id(name)
+    }
+
+    pub fn name(&self, id: NodeId) -> &str {
+        self.interner.name(id)
+    }
+
+    pub fn names<I: IntoIterator<Item = NodeId>>(&self, ids: I) -> Vec<&str> {
+        ids.into_iter().map(|id| self.name(id)).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.adjacency.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.adjacency.is_empty()
+    }
+
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        0..self.adjacency.len()
+    }
+
+    // The nodes an edge leads to from `id`, in id order
+    pub fn neighbors(&self, id: NodeId) -> &BTreeSet<NodeId> {
+        &self.adjacency[id]
+    }
+
+    pub fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        self.adjacency[from].contains(&to)
+    }
+
+    // Orders the nodes so every edge points forwards, preferring lower ids
+    // when there is a choice, using Kahn's algorithm
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, CycleError> {
+        let mut incoming = vec![0; self.len()];
+        for to in self.adjacency.iter().flatten() {
+            incoming[*to] += 1;
+        }
+
+        let mut ready: BTreeSet<NodeId> = self.nodes().filter(|&id| incoming[id] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop_first() {
+            order.push(id);
+            for &to in &self.adjacency[id] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.insert(to);
+                }
+            }
+        }
+
+        if order.len() < self.len() {
+            return Err(CycleError {
+                nodes: self.nodes().filter(|&id| incoming[id] > 0).collect(),
+            });
+        }
+
+        Ok(order)
+    }
+
+    // Tarjan's algorithm, iterative so deep graphs cannot overflow the stack.
+    // Components come out in reverse topological order, each sorted by id.
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        let mut index: Vec<Option<usize>> = vec![None; self.len()];
+        let mut low_link = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack: Vec<NodeId> = Vec::new();
+        let mut components: Vec<Vec<NodeId>> = Vec::new();
+        let mut next_index = 0;
+
+        for root in self.nodes() {
+            if index[root].is_some() {
+                continue;
+            }
+
+            // Each frame is a node and the neighbors it still has to visit
+            let mut frames = vec![(root, self.adjacency[root].iter())];
+            index[root] = Some(next_index);
+            low_link[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+
+            while let Some((id, neighbors)) = frames.last_mut() {
+                let id = *id;
+                if let Some(&next) = neighbors.next() {
+                    match index[next] {
+                        None => {
+                            index[next] = Some(next_index);
+                            low_link[next] = next_index;
+                            next_index += 1;
+                            stack.push(next);
+                            on_stack[next] = true;
+                            frames.push((next, self.adjacency[next].iter()));
+                        }
+                        Some(order) if on_stack[next] => {
+                            low_link[id] = low_link[id].min(order);
+                        }
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                frames.pop();
+                if let Some((parent, _)) = frames.last() {
+                    low_link[*parent] = low_link[*parent].min(low_link[id]);
+                }
+                if Some(low_link[id]) == index[id] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == id {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component);
+                }
+            }
+        }
+
+        components
+    }
+
+    // Every clique that no other node could join, found with Bron–Kerbosch
+    // and pivoting. Only meaningful for undirected graphs.
+    pub fn maximal_cliques(&self) -> Vec<Vec<NodeId>> {
+        let mut cliques = Vec::new();
+        self.bron_kerbosch(
+            &mut Vec::new(),
+            self.nodes().collect(),
+            BTreeSet::new(),
+            &mut cliques,
+        );
+
+        cliques
+    }
+
+    pub fn maximum_clique(&self) -> Vec<NodeId> {
+        self.maximal_cliques()
+            .into_iter()
+            .max_by_key(Vec::len)
+            .unwrap_or_default()
+    }
+
+    fn bron_kerbosch(
+        &self,
+        clique: &mut Vec<NodeId>,
+        mut candidates: BTreeSet<NodeId>,
+        mut excluded: BTreeSet<NodeId>,
+        cliques: &mut Vec<Vec<NodeId>>,
+    ) {
+        if candidates.is_empty() {
+            if excluded.is_empty() {
+                let mut found = clique.clone();
+                found.sort_unstable();
+                cliques.push(found);
+            }
+            return;
+        }
+
+        // Any maximal clique holds the pivot or one of its non-neighbors
+        let pivot = *candidates
+            .union(&excluded)
+            .max_by_key(|&&id| self.adjacency[id].intersection(&candidates).count())
+            .unwrap();
+        let branches: Vec<NodeId> = candidates
+            .difference(&self.adjacency[pivot])
+            .copied()
+            .collect();
+
+        for id in branches {
+            let neighbors = &self.adjacency[id];
+            clique.push(id);
+            self.bron_kerbosch(
+                clique,
+                candidates.intersection(neighbors).copied().collect(),
+                excluded.intersection(neighbors).copied().collect(),
+                cliques,
+            );
+            clique.pop();
+
+            candidates.remove(&id);
+            excluded.insert(id);
+        }
+    }
+
+    // The number of distinct paths from `from` to `to`. The graph must have no
+    // cycle reachable from `from`.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> u64 {
+        self.count_paths_via(from, to, &[])
+    }
+
+    // Like `count_paths`, but only paths passing through every node in `via`,
+    // in any order
+    pub fn count_paths_via(&self, from: NodeId, to: NodeId, via: &[NodeId]) -> u64 {
+        assert!(via.len() <= 64, "at most 64 via nodes are supported");
+
+        let seen = |mask: u64, id: NodeId| match via.iter().position(|&v| v == id) {
+            Some(bit) => mask | 1 << bit,
+            None => mask,
+        };
+        let all_seen = if via.len() == 64 {
+            u64::MAX
+        } else {
+            (1 << via.len()) - 1
+        };
+
+        count_paths(
+            (from, seen(0, from)),
+            // A path ends at `to`, even if edges lead on from it
+            |&(id, mask)| {
+                if id == to {
+                    Vec::new()
+                } else {
+                    self.adjacency[id]
+                        .iter()
+                        .map(|&next| (next, seen(mask, next)))
+                        .collect()
+                }
+            },
+            |&(id, mask)| id == to && mask == all_seen,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn directed(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::directed();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = Graph::undirected();
+        let (a, b) = graph.add_edge("a", "b");
+        graph.add_edge("b", "a");
+
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph.id("b"), Some(b));
+        assert_eq!(graph.name(a), "a");
+        assert!(graph.has_edge(b, a));
+        assert_eq!(graph.neighbors(a).len(), 1);
+        assert_eq!(graph.id("c"), None);
+    }
+
+    #[test]
+    fn test_topological_sort() {
+        let graph = directed(&[("shirt", "tie"), ("tie", "jacket"), ("socks", "shoes")]);
+        let order = graph.topological_sort().unwrap();
+        assert_eq!(
+            graph.names(order),
+            vec!["shirt", "tie", "jacket", "socks", "shoes"]
+        );
+
+        let cyclic = directed(&[("a", "b"), ("b", "c"), ("c", "a"), ("c", "d")]);
+        let err = cyclic.topological_sort().unwrap_err();
+        assert_eq!(cyclic.names(err.nodes), vec!["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = directed(&[
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+            ("f", "f"),
+        ]);
+        let components: Vec<Vec<&str>> = graph
+            .strongly_connected_components()
+            .into_iter()
+            .map(|component| graph.names(component))
+            .collect();
+
+        assert_eq!(
+            components,
+            vec![vec!["d", "e"], vec!["a", "b", "c"], vec!["f"]]
+        );
+    }
+
+    #[test]
+    fn test_maximal_cliques() {
+        let mut graph = Graph::undirected();
+        for (a, b) in [
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "c"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "f"),
+            ("d", "f"),
+            ("c", "e"),
+        ] {
+            graph.add_edge(a, b);
+        }
+
+        let mut cliques: Vec<Vec<&str>> = graph
+            .maximal_cliques()
+            .into_iter()
+            .map(|clique| graph.names(clique))
+            .collect();
+        cliques.sort();
+        assert_eq!(
+            cliques,
+            vec![
+                vec!["a", "b", "c"],
+                vec!["c", "d", "e"],
+                vec!["d", "e", "f"]
+            ]
+        );
+        assert_eq!(graph.maximum_clique().len(), 3);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Two ways from a to c, each followed by two ways from c to f
+        let graph = directed(&[
+            ("a", "b"),
+            ("a", "c"),
+            ("b", "c"),
+            ("c", "d"),
+            ("c", "e"),
+            ("d", "f"),
+            ("e", "f"),
+        ]);
+        let id = |name| graph.id(name).unwrap();
+
+        assert_eq!(graph.count_paths(id("a"), id("f")), 4);
+        assert_eq!(graph.count_paths_via(id("a"), id("f"), &[id("b")]), 2);
+        assert_eq!(
+            graph.count_paths_via(id("a"), id("f"), &[id("b"), id("e")]),
+            1
+        );
+        assert_eq!(graph.count_paths_via(id("a"), id("f"), &[id("a")]), 4);
+        assert_eq!(graph.count_paths(id("f"), id("a")), 0);
+    }
+}
@@ -0,0 +1,401 @@
+use crate::{Coordinate, Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+
+// A dense 2D map stored row by row. Coordinates follow the rest of the crate:
+// `x` is the row, growing southwards, and `y` is the column, growing eastwards.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // Maps every character of a rectangular block of lines. A character the
+    // closure rejects, or a line of the wrong length, is a parse error.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let line_width = line.chars().count();
+            match width {
+                None => width = Some(line_width),
+                Some(width) if width != line_width => {
+                    return Err(Error::parse(
+                        i + 1,
+                        format!("expected {} columns, found {}", width, line_width),
+                    ));
+                }
+                Some(_) => {}
+            }
+
+            for c in line.chars() {
+                match cell(c) {
+                    Some(value) => cells.push(value),
+                    None => return Err(Error::parse(i + 1, format!("unexpected {:?}", c))),
+                }
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of(&self, coordinate: Coordinate) -> Option<usize> {
+        let row = usize::try_from(coordinate.x).ok()?;
+        let column = usize::try_from(coordinate.y).ok()?;
+
+        (row < self.height && column < self.width).then_some(row * self.width + column)
+    }
+
+    fn coordinate_of(&self, index: usize) -> Coordinate {
+        Coordinate::new((index / self.width) as i32, (index % self.width) as i32)
+    }
+
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        self.index_of(coordinate).is_some()
+    }
+
+    pub fn get(&self, coordinate: Coordinate) -> Option<&T> {
+        self.index_of(coordinate).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, coordinate: Coordinate) -> Option<&mut T> {
+        self.index_of(coordinate).map(|i| &mut self.cells[i])
+    }
+
+    // Returns whether the coordinate was inside the grid
+    pub fn set(&mut self, coordinate: Coordinate, value: T) -> bool {
+        match self.get_mut(coordinate) {
+            Some(cell) => {
+                *cell = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> + '_ {
+        (0..self.cells.len()).map(|i| self.coordinate_of(i))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| (self.coordinate_of(i), cell))
+    }
+
+    // The north, south, east and west neighbors that are inside the grid
+    pub fn neighbors(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        coordinate
+            .neighboring_cardinal_directions()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    // Like `neighbors`, with the four diagonals as well
+    pub fn all_neighbors(&self, coordinate: Coordinate) -> impl Iterator<Item = Coordinate> + '_ {
+        coordinate
+            .all_neighboring_directions()
+            .into_iter()
+            .filter(|neighbor| self.contains(*neighbor))
+    }
+
+    // Empty when `row` is past the bottom of the grid
+    pub fn row(&self, row: usize) -> &[T] {
+        if row >= self.height {
+            return &[];
+        }
+
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on zero, and a zero width grid has no cells anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // Empty when `column` is past the right edge, rather than wrapping round
+    // into the next row
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        let cells = match column < self.width {
+            true => &self.cells[column..],
+            false => &[],
+        };
+        // step_by panics on zero, and a zero width grid has no cells anyway
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    // Walks from `start` by (`dx`, `dy`) until it leaves the grid. Panics if
+    // both steps are zero, since the walk would never leave.
+    pub fn ray(
+        &self,
+        start: Coordinate,
+        dx: i32,
+        dy: i32,
+    ) -> impl Iterator<Item = (Coordinate, &T)> {
+        assert!(dx != 0 || dy != 0, "a ray needs a non-zero step");
+
+        std::iter::successors(Some(start), move |c| {
+            Some(Coordinate::new(c.x + dx, c.y + dy))
+        })
+        .map_while(|c| self.get(c).map(|cell| (c, cell)))
+    }
+
+    // Every line running south east, starting from the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let starts = (0..self.height as i32)
+            .rev()
+            .map(|x| Coordinate::new(x, 0))
+            .chain((1..self.width as i32).map(|y| Coordinate::new(0, y)));
+
+        starts.map(|start| self.ray(start, 1, 1).map(|(_, cell)| cell).collect())
+    }
+
+    // Every line running south west, starting from the top left corner
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let last_column = self.width as i32 - 1;
+        let starts = (0..self.width as i32)
+            .map(|y| Coordinate::new(0, y))
+            .chain((1..self.height as i32).map(move |x| Coordinate::new(x, last_column)));
+
+        starts.map(|start| self.ray(start, 1, -1).map(|(_, cell)| cell).collect())
+    }
+
+    pub fn find(&self, value: &T) -> Option<Coordinate>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .map(|i| self.coordinate_of(i))
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coordinate> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(coordinate, _)| coordinate)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    // Builds a grid of the given shape from a function of each new coordinate
+    fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Grid<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let cells = (0..width * height)
+            .map(|i| cell(i / width.max(1), i % width.max(1)))
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |row, column| {
+            self.cells[column * self.width + row].clone()
+        })
+    }
+
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |row, column| {
+            self.cells[(self.height - 1 - column) * self.width + row].clone()
+        })
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.height, self.width, |row, column| {
+            self.cells[column * self.width + self.width - 1 - row].clone()
+        })
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &T {
+        self.get(coordinate)
+            .unwrap_or_else(|| panic!("{} is outside the grid", coordinate))
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut T {
+        self.get_mut(coordinate)
+            .unwrap_or_else(|| panic!("{} is outside the grid", coordinate))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = Error;
+
+    fn from_str(input: &str) -> Result<Grid<char>> {
+        Grid::parse_with(input, Some)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        "abc\ndef".parse().unwrap()
+    }
+
+    #[test]
+    fn test_from_str() {
+        let grid = grid();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[Coordinate::new(1, 2)], 'f');
+        assert_eq!(grid.get(Coordinate::new(2, 0)), None);
+        assert_eq!(grid.get(Coordinate::new(0, -1)), None);
+
+        assert!(matches!(
+            Grid::parse_with("ab\nc", Some),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            Grid::parse_with("..\n.#", |c| (c == '.').then_some(0)),
+            Err(Error::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn test_set_and_find() {
+        let mut grid = grid();
+        assert!(grid.set(Coordinate::new(0, 0), 'f'));
+        assert!(!grid.set(Coordinate::new(5, 0), 'z'));
+        assert_eq!(grid.find(&'f'), Some(Coordinate::new(0, 0)));
+        assert_eq!(grid.find_all(&'f').count(), 2);
+        assert_eq!(grid.find(&'z'), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = grid();
+        assert_eq!(grid.neighbors(Coordinate::new(0, 0)).count(), 2);
+        assert_eq!(grid.all_neighbors(Coordinate::new(0, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = grid();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert!(grid.row(2).is_empty());
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.column(3).count(), 0);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, ["ad", "be", "cf"]);
+
+        let empty = Grid::new(0, 3, '.');
+        assert_eq!(empty.column(0).count(), 0);
+        assert_eq!(empty.columns().count(), 0);
+        assert_eq!(empty.rows().count(), 0);
+
+        let diagonals: Vec<String> = grid
+            .diagonals()
+            .map(|cells| cells.into_iter().collect())
+            .collect();
+        assert_eq!(diagonals, ["d", "ae", "bf", "c"]);
+        let anti_diagonals: Vec<String> = grid
+            .anti_diagonals()
+            .map(|cells| cells.into_iter().collect())
+            .collect();
+        assert_eq!(anti_diagonals, ["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = grid();
+        let cells: String = grid
+            .ray(Coordinate::new(1, 0), -1, 1)
+            .map(|(_, cell)| cell)
+            .collect();
+        assert_eq!(cells, "db");
+        assert_eq!(grid.ray(Coordinate::new(2, 0), 1, 0).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero step")]
+    fn test_ray_rejects_a_zero_step() {
+        let _ = grid().ray(Coordinate::new(0, 0), 0, 0);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+    }
+}
@@ -0,0 +1,380 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead};
+use std::ops::{Add, AddAssign};
+use std::path::Path;
+
+mod answer;
+pub mod cycle;
+mod error;
+pub mod graph;
+mod grid;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod pathfinding;
+mod point;
+mod range_set;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, Result};
+pub use grid::Grid;
+pub use point::Point;
+pub use range_set::{RangeMap, RangeSet};
+pub use solution::{Registry, Runnable, Solution};
+
+// read_lines from https://doc.rust-lang.org/rust-by-example/std_misc/file/read_lines.html
+pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
+where
+    P: AsRef<Path>,
+{
+    let file = File::open(filename)?;
+    Ok(io::BufReader::new(file).lines())
+}
+
+pub fn read_file_to_string<P>(filename: P) -> Result<String>
+where
+    P: AsRef<Path>,
+{
+    std::fs::read_to_string(&filename).map_err(|source| Error::InputNotFound {
+        path: filename.as_ref().to_path_buf(),
+        source,
+    })
+}
+
+// The derived ordering compares `x` and then `y`, which is row-major order:
+// top to bottom, then left to right, the way the puzzle text reads
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Coordinate {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Coordinate {
+    pub fn new(x: i32, y: i32) -> Coordinate {
+        Coordinate { x, y }
+    }
+
+    // The same as `cmp`, for call sites that want the order spelled out
+    pub fn row_major_cmp(&self, other: &Coordinate) -> Ordering {
+        (self.x, self.y).cmp(&(other.x, other.y))
+    }
+
+    // Left to right, then top to bottom
+    pub fn column_major_cmp(&self, other: &Coordinate) -> Ordering {
+        (self.y, self.x).cmp(&(other.y, other.x))
+    }
+
+    pub fn sort_reading_order(coordinates: &mut [Coordinate]) {
+        coordinates.sort_by(Coordinate::row_major_cmp);
+    }
+
+    pub fn sort_column_major(coordinates: &mut [Coordinate]) {
+        coordinates.sort_by(Coordinate::column_major_cmp);
+    }
+
+    pub fn manhattan_distance(&self, other: &Coordinate) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn distance(&self, other: Coordinate) -> i32 {
+        (((self.x - other.x).abs().pow(2) + (self.y - other.y).abs().pow(2)) as f32).sqrt() as i32
+    }
+
+    pub fn neighboring_cardinal_directions(&self) -> Vec<Coordinate> {
+        Vec::from([
+            self.north_coordinate(),
+            self.south_coordinate(),
+            self.east_coordinate(),
+            self.west_coordinate(),
+        ])
+    }
+
+    pub fn all_neighboring_directions(&self) -> Vec<Coordinate> {
+        let mut neighboring_directions = Vec::from([
+            Coordinate::new(self.x + 1, self.y + 1),
+            Coordinate::new(self.x - 1, self.y + 1),
+            Coordinate::new(self.x + 1, self.y - 1),
+            Coordinate::new(self.x - 1, self.y - 1),
+        ]);
+        neighboring_directions.append(&mut self.neighboring_cardinal_directions());
+
+        neighboring_directions
+    }
+
+    pub fn north_coordinate(&self) -> Coordinate {
+        Coordinate::new(self.x - 1, self.y)
+    }
+
+    pub fn south_coordinate(&self) -> Coordinate {
+        Coordinate::new(self.x + 1, self.y)
+    }
+
+    pub fn east_coordinate(&self) -> Coordinate {
+        Coordinate::new(self.x, self.y + 1)
+    }
+
+    pub fn west_coordinate(&self) -> Coordinate {
+        Coordinate::new(self.x, self.y - 1)
+    }
+}
+
+impl fmt::Display for Coordinate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x={}, y={}", self.x, self.y,)
+    }
+}
+
+#[derive(Clone, Copy, Ord, PartialOrd, Eq, PartialEq, Hash, Debug)]
+pub struct Coordinate3D {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Coordinate3D {
+    pub fn new(x: i32, y: i32, z: i32) -> Coordinate3D {
+        Coordinate3D { x, y, z }
+    }
+
+    pub fn neighboring_coordinates(&self) -> Vec<Coordinate3D> {
+        Vec::from([
+            Coordinate3D::new(self.x + 1, self.y, self.z),
+            Coordinate3D::new(self.x - 1, self.y, self.z),
+            Coordinate3D::new(self.x, self.y + 1, self.z),
+            Coordinate3D::new(self.x, self.y - 1, self.z),
+            Coordinate3D::new(self.x, self.y, self.z + 1),
+            Coordinate3D::new(self.x, self.y, self.z - 1),
+        ])
+    }
+}
+
+impl fmt::Display for Coordinate3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "x={}, y={}, z={}", self.x, self.y, self.z,)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    // Clockwise from north, so turning is a step around the array
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    // Turns clockwise by `eighths` of a full turn
+    fn turn(self, eighths: usize) -> Direction {
+        Direction::ALL[(self.index() + eighths) % 8]
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn turn_right_45(self) -> Direction {
+        self.turn(1)
+    }
+
+    pub fn turn_left_45(self) -> Direction {
+        self.turn(7)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self.index() % 2 == 1
+    }
+
+    // The (x, y) change of one step, where north is towards smaller x
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        }
+    }
+
+    // The direction of a single step, so only offsets of -1, 0 and 1 match
+    pub fn from_offset(x: i32, y: i32) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.offset() == (x, y))
+    }
+
+    // Reads the arrow (^v<>), compass (NSEW) and relative (UDLR) spellings
+    // puzzles use for the four cardinal directions
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'N' | 'U' => Some(Direction::North),
+            'v' | 'S' | 'D' => Some(Direction::South),
+            '>' | 'E' | 'R' => Some(Direction::East),
+            '<' | 'W' | 'L' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+impl Add<Direction> for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, direction: Direction) -> Coordinate {
+        let (x, y) = direction.offset();
+        Coordinate::new(self.x + x, self.y + y)
+    }
+}
+
+impl AddAssign<Direction> for Coordinate {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match &self {
+                Direction::North => "N",
+                Direction::NorthEast => "NE",
+                Direction::East => "E",
+                Direction::SouthEast => "SE",
+                Direction::South => "S",
+                Direction::SouthWest => "SW",
+                Direction::West => "W",
+                Direction::NorthWest => "NW",
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coordinate_ordering() {
+        let mut coordinates = vec![
+            Coordinate::new(1, 0),
+            Coordinate::new(0, 2),
+            Coordinate::new(0, 1),
+            Coordinate::new(1, -1),
+        ];
+
+        Coordinate::sort_reading_order(&mut coordinates);
+        assert_eq!(
+            coordinates,
+            [
+                Coordinate::new(0, 1),
+                Coordinate::new(0, 2),
+                Coordinate::new(1, -1),
+                Coordinate::new(1, 0),
+            ]
+        );
+        for pair in coordinates.windows(2) {
+            assert_eq!(pair[0].cmp(&pair[1]), Ordering::Less);
+            assert_eq!(pair[0].partial_cmp(&pair[1]), Some(Ordering::Less));
+        }
+
+        Coordinate::sort_column_major(&mut coordinates);
+        assert_eq!(
+            coordinates,
+            [
+                Coordinate::new(1, -1),
+                Coordinate::new(1, 0),
+                Coordinate::new(0, 1),
+                Coordinate::new(0, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::West.turn_right(), Direction::North);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        assert_eq!(Direction::NorthWest.turn_right_45(), Direction::North);
+        assert_eq!(Direction::North.turn_left_45(), Direction::NorthWest);
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().reverse(), direction);
+        }
+    }
+
+    #[test]
+    fn test_direction_steps() {
+        let start = Coordinate::new(3, 3);
+        assert_eq!(start + Direction::North, start.north_coordinate());
+        assert_eq!(start + Direction::South, start.south_coordinate());
+        assert_eq!(start + Direction::East, start.east_coordinate());
+        assert_eq!(start + Direction::West, start.west_coordinate());
+        assert_eq!(start + Direction::SouthWest, Coordinate::new(4, 2));
+
+        let mut cursor = start;
+        cursor += Direction::NorthEast;
+        cursor += Direction::NorthEast.reverse();
+        assert_eq!(cursor, start);
+
+        assert_eq!(Direction::from_offset(1, -1), Some(Direction::SouthWest));
+        assert_eq!(Direction::from_offset(0, 0), None);
+        assert_eq!(Direction::from_offset(2, 0), None);
+    }
+
+    #[test]
+    fn test_direction_from_char() {
+        for (chars, direction) in [
+            ("^NU", Direction::North),
+            ("vSD", Direction::South),
+            (">ER", Direction::East),
+            ("<WL", Direction::West),
+        ] {
+            for c in chars.chars() {
+                assert_eq!(Direction::from_char(c), Some(direction));
+            }
+        }
+        assert_eq!(Direction::from_char('x'), None);
+    }
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+
+// Why a system of congruences could not be solved
+#[derive(Debug, PartialEq, Eq)]
+pub enum CrtError {
+    // Two congruences contradict each other, such as x = 1 (mod 2) and x = 0 (mod 4)
+    NoSolution,
+    // The combined modulus does not fit the integer type
+    Overflow,
+}
+
+impl fmt::Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrtError::NoSolution => write!(f, "the congruences have no common solution"),
+            CrtError::Overflow => write!(f, "the combined modulus overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+// Always non-negative, and gcd(0, 0) is 0. Panics when the result is 2^63,
+// which only happens for i64::MIN with 0 or i64::MIN; checked_gcd does not.
+pub fn gcd(a: i64, b: i64) -> i64 {
+    narrow(checked_gcd(a.into(), b.into()), "gcd")
+}
+
+// Always non-negative, and zero if either value is. Panics when the result does
+// not fit an i64; use checked_lcm for values that might get that large.
+pub fn lcm(a: i64, b: i64) -> i64 {
+    narrow(checked_lcm(a.into(), b.into()), "lcm")
+}
+
+// The gcd of every value, or 0 when there are none. Panics like gcd.
+pub fn gcd_all<I: IntoIterator<Item = i64>>(values: I) -> i64 {
+    values.into_iter().fold(0, gcd)
+}
+
+// The lcm of every value, or 1 when there are none. Combines cycle lengths
+// into the length of the cycle they share. Panics when the result does not
+// fit an i64; checked_lcm_all returns None instead.
+pub fn lcm_all<I: IntoIterator<Item = i64>>(values: I) -> i64 {
+    narrow(checked_lcm_all(values.into_iter().map(i128::from)), "lcm")
+}
+
+// Returns (g, x, y) such that a * x + b * y = g, where g is gcd(a, b). Panics
+// like gcd when any of them does not fit an i64.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_i128(a.into(), b.into());
+    (
+        narrow(Some(g), "extended_gcd"),
+        narrow(Some(x), "extended_gcd"),
+        narrow(Some(y), "extended_gcd"),
+    )
+}
+
+// The x in 0..modulus with a * x = 1 (mod modulus), if a and modulus are
+// coprime. Panics if the modulus is not positive.
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    checked_mod_inverse(a.into(), modulus.into()).map(|x| narrow(Some(x), "mod_inverse"))
+}
+
+// base^exponent reduced into 0..modulus, which always fits an i64. Panics if
+// the modulus is not positive.
+pub fn mod_pow(base: i64, exponent: u64, modulus: i64) -> i64 {
+    // Residues below 2^63 multiply without overflowing an i128
+    narrow(
+        checked_mod_pow(base.into(), exponent, modulus.into()),
+        "mod_pow",
+    )
+}
+
+// Solves x = residue (mod modulus) for every (residue, modulus) pair, returning
+// the smallest non-negative x and the modulus of the combined congruence. The
+// moduli need not be coprime.
+pub fn crt<I: IntoIterator<Item = (i64, i64)>>(congruences: I) -> Result<(i64, i64), CrtError> {
+    let (x, modulus) = checked_crt(
+        congruences
+            .into_iter()
+            .map(|(residue, modulus)| (residue.into(), modulus.into())),
+    )?;
+
+    match (x.try_into(), modulus.try_into()) {
+        (Ok(x), Ok(modulus)) => Ok((x, modulus)),
+        _ => Err(CrtError::Overflow),
+    }
+}
+
+// Brings an i128 result back to i64, panicking if it overflowed on the way or
+// does not fit
+fn narrow(value: Option<i128>, operation: &str) -> i64 {
+    value
+        .and_then(|value| value.try_into().ok())
+        .unwrap_or_else(|| panic!("{} overflowed i64", operation))
+}
+
+// The i128 variants below return None, or CrtError::Overflow, instead of
+// overflowing
+
+pub fn checked_gcd(a: i128, b: i128) -> Option<i128> {
+    let (mut a, mut b) = (a.checked_abs()?, b.checked_abs()?);
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    Some(a)
+}
+
+pub fn checked_lcm(a: i128, b: i128) -> Option<i128> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a.checked_abs()? / checked_gcd(a, b)?).checked_mul(b.checked_abs()?)
+}
+
+pub fn checked_lcm_all<I: IntoIterator<Item = i128>>(values: I) -> Option<i128> {
+    values.into_iter().try_fold(1, checked_lcm)
+}
+
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+pub fn checked_mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive, got {}", modulus);
+
+    let (g, x, _) = extended_gcd_i128(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+pub fn checked_mod_pow(base: i128, mut exponent: u64, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "modulus must be positive, got {}", modulus);
+
+    let mut base = base.rem_euclid(modulus);
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result.checked_mul(base)? % modulus;
+        }
+        base = base.checked_mul(base)? % modulus;
+        exponent >>= 1;
+    }
+
+    Some(result)
+}
+
+pub fn checked_crt<I: IntoIterator<Item = (i128, i128)>>(
+    congruences: I,
+) -> Result<(i128, i128), CrtError> {
+    let (mut x, mut modulus): (i128, i128) = (0, 1);
+
+    for (residue, next_modulus) in congruences {
+        assert!(
+            next_modulus > 0,
+            "modulus must be positive, got {}",
+            next_modulus
+        );
+        let residue = residue.rem_euclid(next_modulus);
+
+        // Find k with x + modulus * k = residue (mod next_modulus)
+        let (g, inverse, _) = extended_gcd_i128(modulus, next_modulus);
+        let difference = residue - x;
+        if difference % g != 0 {
+            return Err(CrtError::NoSolution);
+        }
+
+        let step = next_modulus / g;
+        let k = (difference / g)
+            .rem_euclid(step)
+            .checked_mul(inverse.rem_euclid(step))
+            .ok_or(CrtError::Overflow)?
+            % step;
+        let combined = (modulus / g)
+            .checked_mul(next_modulus)
+            .ok_or(CrtError::Overflow)?;
+
+        x = modulus
+            .checked_mul(k)
+            .and_then(|offset| offset.checked_add(x))
+            .ok_or(CrtError::Overflow)?
+            % combined;
+        modulus = combined;
+    }
+
+    Ok((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        assert_eq!(gcd_all([24, 36, 60]), 12);
+        assert_eq!(gcd_all([]), 0);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[test]
+    fn test_extended_gcd() {
+        for (a, b) in [(240, 46), (17, 5), (-8, 12), (0, 7)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(g, gcd(a, b));
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_modular_arithmetic() {
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(4, 8), None);
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(-2, 3, 5), 2);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        assert_eq!(mod_pow(i64::MAX - 1, u64::MAX, i64::MAX), i64::MAX - 1);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Ok((23, 105)));
+        // Moduli sharing a factor still combine when they agree on it
+        assert_eq!(crt([(1, 4), (3, 6)]), Ok((9, 12)));
+        assert_eq!(crt([(1, 2), (0, 4)]), Err(CrtError::NoSolution));
+        assert_eq!(crt([]), Ok((0, 1)));
+    }
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(checked_lcm(i128::MAX, 2), None);
+        assert_eq!(checked_lcm_all([i128::MAX, 3, 2]), None);
+        assert_eq!(checked_mod_pow(i128::MAX - 1, 2, i128::MAX), None);
+        assert_eq!(
+            crt([(0, 4_000_000_007), (0, 4_000_000_009), (0, 4_000_000_037)]),
+            Err(CrtError::Overflow)
+        );
+        assert_eq!(
+            checked_crt([(0, 4_000_000_007), (1, 4_000_000_009)]),
+            Ok((8_000_000_030_000_000_028, 16_000_000_064_000_000_063))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "lcm overflowed i64")]
+    fn test_lcm_panics_on_overflow() {
+        lcm(i64::MAX, 2);
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+// How well a cache did, for judging whether a key captures enough of the state
+// to be shared
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} entries, {} hits, {} misses",
+            self.entries, self.hits, self.misses
+        )
+    }
+}
+
+// A cache of computed values by key that counts its hits and misses
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Memo<K, V> {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    // The cached value for `key`, or the one `compute` returns, which is
+    // cached. `compute` gets the memo back so a recursive function can look up
+    // its smaller cases while computing this one.
+    pub fn get_or_compute<F>(&mut self, key: K, compute: F) -> V
+    where
+        F: FnOnce(&mut Memo<K, V>) -> V,
+    {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    pub fn stats(&self) -> Stats {
+        Stats {
+            entries: self.cache.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> Default for Memo<K, V> {
+    fn default() -> Memo<K, V> {
+        Memo::new()
+    }
+}
+
+// A recursive function with its own memo, made by `memoize`
+pub struct Memoized<K, V, F> {
+    memo: Memo<K, V>,
+    function: F,
+}
+
+// Wraps a recursive function so every argument is computed once. The function
+// is handed a `recurse` callback to use in place of calling itself:
+//
+//     let mut fibonacci = memoize(|recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+//         if n < 2 { n } else { recurse(n - 1) + recurse(n - 2) }
+//     });
+//     fibonacci.call(90);
+pub fn memoize<K, V, F>(function: F) -> Memoized<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memoized {
+        memo: Memo::new(),
+        function,
+    }
+}
+
+impl<K, V, F> Memoized<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    pub fn call(&mut self, key: K) -> V {
+        evaluate(&self.function, &mut self.memo, key)
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.memo.stats()
+    }
+}
+
+fn evaluate<K, V, F>(function: &F, memo: &mut Memo<K, V>, key: K) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    memo.get_or_compute(key.clone(), |memo| {
+        function(&mut |next| evaluate(function, memo, next), key)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memoize() {
+        let mut fibonacci = memoize(|recurse: &mut dyn FnMut(u64) -> u64, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                recurse(n - 1) + recurse(n - 2)
+            }
+        });
+
+        assert_eq!(fibonacci.call(90), 2_880_067_194_370_816_120);
+        assert_eq!(
+            fibonacci.stats(),
+            Stats {
+                entries: 91,
+                hits: 88,
+                misses: 91
+            }
+        );
+
+        fibonacci.call(50);
+        assert_eq!(fibonacci.stats().hits, 89);
+    }
+
+    #[test]
+    fn test_get_or_compute() {
+        // Ways to climb n stairs taking one or two at a time
+        fn climb(memo: &mut Memo<u32, u64>, n: u32) -> u64 {
+            memo.get_or_compute(n, |memo| match n {
+                0 | 1 => 1,
+                _ => climb(memo, n - 1) + climb(memo, n - 2),
+            })
+        }
+
+        let mut memo = Memo::new();
+        assert_eq!(climb(&mut memo, 40), 165_580_141);
+        assert_eq!(memo.len(), 41);
+        assert_eq!(memo.stats().to_string(), "41 entries, 38 hits, 41 misses");
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!(memo.stats(), Stats::default());
+    }
+}
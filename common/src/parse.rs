@@ -0,0 +1,373 @@
+use crate::{Error, Result};
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Every line with its 1-based line number, for parsers that report errors
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+// A run of lines between blank lines. `line` is the number of its first line
+// in the whole input, so errors inside a block still point at the right line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Block<'a> {
+    pub line: usize,
+    pub lines: Vec<&'a str>,
+}
+
+impl<'a> Block<'a> {
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &'a str)> + '_ {
+        self.lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| (self.line + index, *line))
+    }
+}
+
+// Splits the input into paragraphs. Lines holding only whitespace count as
+// blank, and `lines` already drops the '\r' of CRLF endings.
+pub fn blocks(input: &str) -> Vec<Block<'_>> {
+    let mut blocks: Vec<Block> = Vec::new();
+    let mut current: Option<Block> = None;
+
+    for (line_number, line) in numbered_lines(input) {
+        if line.trim().is_empty() {
+            blocks.extend(current.take());
+        } else {
+            current
+                .get_or_insert_with(|| Block {
+                    line: line_number,
+                    lines: Vec::new(),
+                })
+                .lines
+                .push(line);
+        }
+    }
+    blocks.extend(current);
+
+    blocks
+}
+
+// The byte offset and text of every integer, with a leading '-' kept unless it
+// follows a letter or digit, so "x=-3" holds -3 but "3-5" holds 3 and 5
+fn integer_tokens(text: &str) -> Vec<(usize, &str)> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let negative = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+        if !negative && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        tokens.push((start, &text[start..index]));
+    }
+
+    tokens
+}
+
+// Every integer in the text that `T` can hold. This is the lenient reader:
+// integers that overflow `T` are skipped, and so are negative integers for an
+// unsigned `T`, so "-3" never turns into 3.
+pub fn ints<T: FromStr>(text: &str) -> Vec<T> {
+    integer_tokens(text)
+        .into_iter()
+        .filter_map(|(_, token)| token.parse().ok())
+        .collect()
+}
+
+// Like `ints`, but strict: an integer that `T` cannot hold, including a
+// negative one for an unsigned `T`, is an error at its column
+pub fn try_ints<T>(line: usize, text: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    integer_tokens(text)
+        .into_iter()
+        .map(|(offset, token)| {
+            token.parse().map_err(|err| {
+                Error::parse_at(line, column(text, offset), format!("{:?}: {}", token, err))
+            })
+        })
+        .collect()
+}
+
+fn column(text: &str, offset: usize) -> usize {
+    text[..offset].chars().count() + 1
+}
+
+// Reads one line from left to right. Every method skips leading spaces and
+// reports errors at the column where it got stuck.
+pub struct Parser<'a> {
+    line: usize,
+    text: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(line: usize, text: &'a str) -> Parser<'a> {
+        Parser {
+            line,
+            text,
+            position: 0,
+        }
+    }
+
+    pub fn error<M: Into<String>>(&self, message: M) -> Error {
+        Error::parse_at(self.line, column(self.text, self.position), message)
+    }
+
+    fn skip_spaces(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    // Everything not yet read
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.position..]
+    }
+
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        self.skip_spaces();
+        if !self.rest().starts_with(literal) {
+            return Err(self.error(format!("expected {:?}, found {:?}", literal, self.rest())));
+        }
+        self.position += literal.len();
+
+        Ok(())
+    }
+
+    // Consumes `literal` if it comes next
+    pub fn optional(&mut self, literal: &str) -> bool {
+        self.skip_spaces();
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.position += literal.len();
+        }
+
+        found
+    }
+
+    // A run of letters, digits and underscores
+    pub fn word(&mut self) -> Result<&'a str> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let length = rest
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        if length == 0 {
+            return Err(self.error(format!("expected a word, found {:?}", rest)));
+        }
+        self.position += length;
+
+        Ok(&rest[..length])
+    }
+
+    pub fn number<T>(&mut self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.skip_spaces();
+        let rest = self.rest();
+        let length = match integer_tokens(rest).first() {
+            Some(&(0, token)) => token.len(),
+            _ => return Err(self.error(format!("expected a number, found {:?}", rest))),
+        };
+        let number = rest[..length]
+            .parse()
+            .map_err(|err| self.error(format!("{:?}: {}", &rest[..length], err)))?;
+        self.position += length;
+
+        Ok(number)
+    }
+
+    // The trimmed text before `delimiter`, which is consumed too
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str> {
+        self.skip_spaces();
+        let rest = self.rest();
+        let Some(length) = rest.find(delimiter) else {
+            return Err(self.error(format!("expected {:?} in {:?}", delimiter, rest)));
+        };
+        self.position += length + delimiter.len();
+
+        Ok(rest[..length].trim_end())
+    }
+
+    // The trimmed text up to `stop` or the end of the line, parsed as `T`.
+    // `stop` itself is left for the caller.
+    pub fn value<T>(&mut self, stop: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.skip_spaces();
+        let rest = self.rest();
+        let text = rest[..rest.find(stop).unwrap_or(rest.len())].trim_end();
+        let value = text
+            .parse()
+            .map_err(|err| self.error(format!("{:?}: {}", text, err)))?;
+        self.position += text.len();
+
+        Ok(value)
+    }
+
+    // One or more items with `separator` between them
+    pub fn list<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Parser<'a>) -> Result<T>,
+    {
+        let mut items = vec![item(self)?];
+        while self.optional(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    pub fn end(&mut self) -> Result<()> {
+        self.skip_spaces();
+        if !self.rest().is_empty() {
+            return Err(self.error(format!("unexpected {:?}", self.rest())));
+        }
+
+        Ok(())
+    }
+}
+
+// Reads "key: a, b, c" shapes, parsing every value as `T`
+pub fn key_list<T>(line: usize, text: &str) -> Result<(&str, Vec<T>)>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut parser = Parser::new(line, text);
+    let key = parser.until(":")?;
+    let values = parser.list(",", |parser| parser.value(","))?;
+    parser.end()?;
+
+    Ok((key, values))
+}
+
+// Reads "a -> b" shapes
+pub fn arrow(line: usize, text: &str) -> Result<(&str, &str)> {
+    let mut parser = Parser::new(line, text);
+    let from = parser.until("->")?;
+    let to = parser.rest().trim();
+    if to.is_empty() {
+        return Err(parser.error("expected something after \"->\""));
+    }
+
+    Ok((from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(ints::<i64>("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+        assert_eq!(ints::<i64>("3-5, x-1"), vec![3, 5, 1]);
+        assert_eq!(ints::<u64>("from -7 to 12"), vec![12]);
+        assert_eq!(ints::<u64>("3-5"), vec![3, 5]);
+        assert_eq!(ints::<u8>("1 300 2"), vec![1, 2]);
+        assert!(ints::<i32>("no numbers").is_empty());
+
+        assert_eq!(try_ints::<u8>(3, "1, 2").unwrap(), vec![1, 2]);
+        assert!(matches!(
+            try_ints::<u8>(3, "1, 300"),
+            Err(Error::Parse {
+                line: 3,
+                column: Some(4),
+                ..
+            })
+        ));
+        assert!(matches!(
+            try_ints::<u32>(1, "-3"),
+            Err(Error::Parse {
+                line: 1,
+                column: Some(1),
+                ..
+            })
+        ));
+        assert_eq!(try_ints::<i32>(1, "-3").unwrap(), vec![-3]);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "a\r\nb\r\n\r\n\r\nc\n  \nd\ne\n";
+        let blocks = blocks(input);
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[0].lines, vec!["a", "b"]);
+        assert_eq!(blocks[1].line, 5);
+        assert_eq!(blocks[1].lines, vec!["c"]);
+        assert_eq!(
+            blocks[2].numbered_lines().collect::<Vec<_>>(),
+            vec![(7, "d"), (8, "e")]
+        );
+    }
+
+    #[test]
+    fn test_parser() {
+        let mut parser = Parser::new(1, "AAA = (BBB, CCC)");
+        assert_eq!(parser.word().unwrap(), "AAA");
+        parser.literal("=").unwrap();
+        parser.literal("(").unwrap();
+        let pair = parser.list(",", |parser| parser.word()).unwrap();
+        parser.literal(")").unwrap();
+        parser.end().unwrap();
+        assert_eq!(pair, vec!["BBB", "CCC"]);
+
+        let mut parser = Parser::new(2, "move -3 from x");
+        parser.literal("move").unwrap();
+        assert_eq!(parser.number::<i32>().unwrap(), -3);
+        parser.literal("from").unwrap();
+        let err = parser.number::<i32>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 14: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_shapes() {
+        assert_eq!(
+            key_list::<u32>(1, "Starting items: 79, 98").unwrap(),
+            ("Starting items", vec![79, 98])
+        );
+        assert_eq!(
+            key_list::<String>(1, "ab: x,y").unwrap(),
+            ("ab", vec!["x".to_string(), "y".to_string()])
+        );
+        assert!(matches!(
+            key_list::<u32>(5, "items: 1, two"),
+            Err(Error::Parse {
+                line: 5,
+                column: Some(11),
+                ..
+            })
+        ));
+
+        assert_eq!(
+            arrow(1, "broadcaster -> a, b").unwrap(),
+            ("broadcaster", "a, b")
+        );
+        assert!(arrow(1, "a - b").is_err());
+        assert!(arrow(1, "a ->").is_err());
+    }
+}
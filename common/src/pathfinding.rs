@@ -0,0 +1,304 @@
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// What a search learned: the goal it stopped at, if any, and the cost of every
+// state it reached. Passing a goal predicate that never matches explores
+// everything reachable, which makes `distances` the full distance map.
+pub struct Search<S, C> {
+    pub goal: Option<S>,
+    pub distances: HashMap<S, C>,
+    parents: HashMap<S, S>,
+}
+
+impl<S: Clone + Hash + Eq, C: Copy> Search<S, C> {
+    fn new(start: S, zero: C) -> Search<S, C> {
+        Search {
+            goal: None,
+            distances: HashMap::from([(start, zero)]),
+            parents: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self) -> Option<C> {
+        self.goal.as_ref().and_then(|goal| self.distance_to(goal))
+    }
+
+    pub fn distance_to(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    // States from the start to the goal, both included
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+
+    pub fn path_to(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+// Breadth-first search for when every step costs one
+pub fn bfs<S, I, FS, FG>(start: S, mut successors: FS, mut is_goal: FG) -> Search<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state] + 1;
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = search.distances.entry(next.clone()) {
+                entry.insert(distance);
+                search.parents.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+// Orders the heap by priority alone, lowest first, so states need no ordering
+struct Queued<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: Ord> PartialEq for Queued<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Ord> Eq for Queued<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Queued<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Queued<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// Dijkstra's algorithm for non-negative step costs. `successors` yields each
+// next state with the cost of stepping to it.
+pub fn dijkstra<S, C, I, FS, FG>(start: S, successors: FS, is_goal: FG) -> Search<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+// A* search. The heuristic must never overestimate the remaining cost, or the
+// distance found may not be the shortest.
+pub fn astar<S, C, I, FS, FH, FG>(
+    start: S,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Search<S, C>
+where
+    S: Clone + Hash + Eq,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    FS: FnMut(&S) -> I,
+    FH: FnMut(&S) -> C,
+    FG: FnMut(&S) -> bool,
+{
+    let mut search = Search::new(start.clone(), C::default());
+    let mut heap = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        cost: C::default(),
+        state: start,
+    }]);
+
+    while let Some(Queued { cost, state, .. }) = heap.pop() {
+        // A cheaper route to this state was found after it was queued
+        if search.distances[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            let improved = match search.distances.get(&next) {
+                Some(&known) => next_cost < known,
+                None => true,
+            };
+            if improved {
+                search.distances.insert(next.clone(), next_cost);
+                search.parents.insert(next.clone(), state.clone());
+                heap.push(Queued {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    state: next,
+                });
+            }
+        }
+    }
+
+    search
+}
+
+// Counts the distinct paths from `start` to any goal state. The successors
+// must never lead back to a state already on the path, so there are finitely
+// many paths to count. The search is iterative so long paths cannot overflow
+// the stack.
+pub fn count_paths<S, I, FS, FG>(start: S, mut successors: FS, mut is_goal: FG) -> u64
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+    FS: FnMut(&S) -> I,
+    FG: FnMut(&S) -> bool,
+{
+    if is_goal(&start) {
+        return 1;
+    }
+
+    let mut counts: HashMap<S, u64> = HashMap::new();
+    // Each frame is a state, the successors it still has to visit and the
+    // paths counted through the ones already visited
+    let successors_of_start = successors(&start).into_iter();
+    let mut frames = vec![(start, successors_of_start, 0)];
+
+    while let Some((_, nexts, total)) = frames.last_mut() {
+        if let Some(next) = nexts.next() {
+            if is_goal(&next) {
+                *total += 1;
+            } else if let Some(&known) = counts.get(&next) {
+                *total += known;
+            } else {
+                let nexts = successors(&next).into_iter();
+                frames.push((next, nexts, 0));
+            }
+            continue;
+        }
+
+        let (state, _, total) = frames.pop().unwrap();
+        match frames.last_mut() {
+            Some((_, _, parent_total)) => *parent_total += total,
+            None => return total,
+        }
+        counts.insert(state, total);
+    }
+
+    unreachable!("the start frame returns its total when popped")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A line of states 0..=10 where each state steps to the next one
+    fn line(state: &i32) -> Vec<i32> {
+        if *state < 10 {
+            vec![state + 1]
+        } else {
+            vec![]
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let search = bfs(0, line, |&state| state == 4);
+        assert_eq!(search.distance(), Some(4));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3, 4]));
+
+        let everything = bfs(0, line, |_| false);
+        assert_eq!(everything.goal, None);
+        assert_eq!(everything.distances.len(), 11);
+        assert_eq!(everything.distance_to(&10), Some(10));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // Going straight to 3 costs 10, while going through 1 and 2 costs 3
+        let successors = |state: &i32| match state {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 1)],
+            2 => vec![(3, 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra(0, successors, |&state| state == 3);
+        assert_eq!(search.distance(), Some(3));
+        assert_eq!(search.path(), Some(vec![0, 1, 2, 3]));
+
+        assert_eq!(
+            dijkstra(0, successors, |&state| state == 4).distance(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && (x, y) != (2, 1))
+                .map(|next| (next, 1))
+                .collect::<Vec<_>>()
+        };
+        let goal = (4, 4);
+        let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+        let search = astar((0, 0), successors, heuristic, |&state| state == goal);
+        assert_eq!(search.distance(), Some(8));
+        assert_eq!(search.path().unwrap().len(), 9);
+    }
+
+    #[test]
+    fn test_count_paths() {
+        // Every state splits in two and both halves meet again at the next
+        let diamonds = |&state: &i32| match state % 3 {
+            0 if state < 9 => vec![state + 1, state + 2],
+            1 => vec![state + 2],
+            2 => vec![state + 1],
+            _ => vec![],
+        };
+
+        assert_eq!(count_paths(0, diamonds, |&state| state == 9), 8);
+        assert_eq!(count_paths(0, diamonds, |&state| state == 10), 0);
+    }
+
+    #[test]
+    fn test_count_paths_along_a_long_chain() {
+        let successors = |&state: &u32| (state < 1_000_000).then_some(state + 1);
+
+        assert_eq!(count_paths(0, successors, |&state| state == 1_000_000), 1);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::{Coordinate, Coordinate3D, Error, Result};
+use std::fmt;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+
+// A point or offset in any number of dimensions, for puzzles that outgrow
+// `Coordinate` and `Coordinate3D`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Point<N> = Point([0; N]);
+
+    pub fn new(components: [i64; N]) -> Point<N> {
+        Point(components)
+    }
+
+    // Reads comma separated components such as "2,-3,5"
+    pub fn parse(line: usize, text: &str) -> Result<Point<N>> {
+        let mut components = [0; N];
+        let mut values = text.split(',');
+
+        for component in components.iter_mut() {
+            let value = values.next().ok_or_else(|| {
+                Error::parse(line, format!("expected {} components in {:?}", N, text))
+            })?;
+            *component = value
+                .trim()
+                .parse()
+                .map_err(|err| Error::parse(line, format!("{:?}: {}", value, err)))?;
+        }
+        if values.next().is_some() {
+            return Err(Error::parse(
+                line,
+                format!("expected {} components in {:?}", N, text),
+            ));
+        }
+
+        Ok(Point(components))
+    }
+
+    pub fn manhattan_distance(&self, other: &Point<N>) -> i64 {
+        (*self - *other).0.iter().map(|d| d.abs()).sum()
+    }
+
+    // The number of king moves apart, where diagonal steps count as one
+    pub fn chebyshev_distance(&self, other: &Point<N>) -> i64 {
+        (*self - *other)
+            .0
+            .iter()
+            .map(|d| d.abs())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn euclidean_distance(&self, other: &Point<N>) -> f64 {
+        let squared: i64 = (*self - *other).0.iter().map(|d| d * d).sum();
+        (squared as f64).sqrt()
+    }
+
+    // Every point one step away along at most `axes` axes at once. In 3D, one
+    // axis gives the 6 sharing a face, two add the 12 sharing an edge and
+    // three add the 8 sharing a corner.
+    pub fn neighbors(&self, axes: usize) -> Vec<Point<N>> {
+        let mut neighbors = Vec::new();
+        let mut offset = [-1; N];
+
+        // Counts through every offset in {-1, 0, 1}^N like an odometer
+        loop {
+            let changed = offset.iter().filter(|&&d| d != 0).count();
+            if (1..=axes).contains(&changed) {
+                neighbors.push(*self + Point(offset));
+            }
+
+            let Some(axis) = offset.iter().rposition(|&d| d < 1) else {
+                break;
+            };
+            offset[axis] += 1;
+            offset[axis + 1..].fill(-1);
+        }
+
+        neighbors
+    }
+
+    pub fn face_neighbors(&self) -> Vec<Point<N>> {
+        self.neighbors(1)
+    }
+
+    // Face neighbors and the neighbors sharing only an edge
+    pub fn edge_neighbors(&self) -> Vec<Point<N>> {
+        self.neighbors(2)
+    }
+
+    // Every point touching this one, corners included
+    pub fn all_neighbors(&self) -> Vec<Point<N>> {
+        self.neighbors(N)
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Point<N> {
+        Point::ORIGIN
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = i64;
+
+    fn index(&self, axis: usize) -> &i64 {
+        &self.0[axis]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, axis: usize) -> &mut i64 {
+        &mut self.0[axis]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|i| self.0[i] + other.0[i]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|i| self.0[i] - other.0[i]))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, factor: i64) -> Point<N> {
+        Point(self.0.map(|component| component * factor))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Point<N> {
+        Point(self.0.map(|component| -component))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        *self = *self - other;
+    }
+}
+
+impl From<Coordinate> for Point<2> {
+    fn from(coordinate: Coordinate) -> Point<2> {
+        Point([coordinate.x.into(), coordinate.y.into()])
+    }
+}
+
+impl From<Coordinate3D> for Point<3> {
+    fn from(coordinate: Coordinate3D) -> Point<3> {
+        Point([
+            coordinate.x.into(),
+            coordinate.y.into(),
+            coordinate.z.into(),
+        ])
+    }
+}
+
+impl<const N: usize> fmt::Display for Point<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let components: Vec<String> = self.0.iter().map(i64::to_string).collect();
+        write!(f, "({})", components.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new([1, 2, 3]);
+        let b = Point::new([4, -5, 6]);
+
+        assert_eq!(a + b, Point::new([5, -3, 9]));
+        assert_eq!(a - b, Point::new([-3, 7, -3]));
+        assert_eq!(a * 2, Point::new([2, 4, 6]));
+        assert_eq!(-a, Point::new([-1, -2, -3]));
+        assert_eq!(a[2], 3);
+
+        let mut c = a;
+        c += b;
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point::new([0, 0]);
+        let b = Point::new([3, -4]);
+
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.euclidean_distance(&b), 5.0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let origin = Point::<3>::ORIGIN;
+        assert_eq!(origin.face_neighbors().len(), 6);
+        assert_eq!(origin.edge_neighbors().len(), 18);
+        assert_eq!(origin.all_neighbors().len(), 26);
+        assert!(origin
+            .all_neighbors()
+            .iter()
+            .all(|neighbor| origin.chebyshev_distance(neighbor) == 1));
+
+        assert_eq!(Point::<2>::ORIGIN.all_neighbors().len(), 8);
+        assert_eq!(Point::<4>::ORIGIN.all_neighbors().len(), 80);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Point::<3>::parse(1, "2,-3, 5").unwrap(),
+            Point::new([2, -3, 5])
+        );
+        assert!(matches!(
+            Point::<3>::parse(4, "2,3"),
+            Err(Error::Parse { line: 4, .. })
+        ));
+        assert!(Point::<2>::parse(1, "2,3,4").is_err());
+        assert!(Point::<2>::parse(1, "2,x").is_err());
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Point::from(Coordinate::new(1, -2)), Point::new([1, -2]));
+        assert_eq!(
+            Point::from(Coordinate3D::new(1, 2, 3)),
+            Point::new([1, 2, 3])
+        );
+    }
+}
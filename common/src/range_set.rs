@@ -0,0 +1,337 @@
+use std::fmt;
+use std::ops::{Bound, Range, RangeBounds};
+
+// A set of integers kept as sorted, disjoint half-open ranges. Overlapping or
+// touching ranges are merged on insert, so equal sets always compare equal.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+// Converts any range expression, such as `3..=5` or `..10`, to a half-open one.
+// A half-open range cannot reach past i64::MAX, so an end of `..=i64::MAX` is
+// treated as unbounded rather than overflowing.
+fn half_open(range: impl RangeBounds<i64>) -> Range<i64> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => i64::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => i64::MAX,
+    };
+
+    start..end
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    // The merged ranges in ascending order
+    pub fn ranges(&self) -> &[Range<i64>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // The number of integers covered
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end.abs_diff(range.start))
+            .sum()
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    // Whether every value of `range` is in the set
+    pub fn contains_range(&self, range: impl RangeBounds<i64>) -> bool {
+        let range = half_open(range);
+        if range.is_empty() {
+            return true;
+        }
+
+        let index = self.ranges.partition_point(|r| r.end <= range.start);
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start <= range.start && range.end <= r.end)
+    }
+
+    pub fn insert(&mut self, range: impl RangeBounds<i64>) {
+        let Range { mut start, mut end } = half_open(range);
+        if start >= end {
+            return;
+        }
+
+        // Every range from `first` up to `last` overlaps or touches the new one
+        let first = self.ranges.partition_point(|r| r.end < start);
+        let last = self.ranges.partition_point(|r| r.start <= end);
+        if first < last {
+            start = start.min(self.ranges[first].start);
+            end = end.max(self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, std::iter::once(start..end));
+    }
+
+    pub fn remove(&mut self, range: impl RangeBounds<i64>) {
+        let Range { start, end } = half_open(range);
+        if start >= end {
+            return;
+        }
+
+        let first = self.ranges.partition_point(|r| r.end <= start);
+        let last = self.ranges.partition_point(|r| r.start < end);
+        if first >= last {
+            return;
+        }
+
+        // Only the ends of the outermost overlapping ranges survive
+        let mut kept = Vec::new();
+        if self.ranges[first].start < start {
+            kept.push(self.ranges[first].start..start);
+        }
+        if self.ranges[last - 1].end > end {
+            kept.push(end..self.ranges[last - 1].end);
+        }
+
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Whichever range ends first cannot overlap anything further on
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+
+        difference
+    }
+
+    pub fn is_disjoint(&self, other: &RangeSet) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn is_subset(&self, other: &RangeSet) -> bool {
+        self.ranges
+            .iter()
+            .all(|range| other.contains_range(range.clone()))
+    }
+
+    pub fn is_superset(&self, other: &RangeSet) -> bool {
+        other.is_subset(self)
+    }
+}
+
+impl<R: RangeBounds<i64>> From<R> for RangeSet {
+    fn from(range: R) -> RangeSet {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<R: RangeBounds<i64>> FromIterator<R> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> RangeSet {
+        let mut set = RangeSet::new();
+        set.extend(ranges);
+        set
+    }
+}
+
+impl<R: RangeBounds<i64>> Extend<R> for RangeSet {
+    fn extend<I: IntoIterator<Item = R>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.insert(range);
+        }
+    }
+}
+
+impl fmt::Display for RangeSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges: Vec<String> = self
+            .ranges
+            .iter()
+            .map(|range| format!("{}..={}", range.start, range.end - 1))
+            .collect();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+// Shifts the values in each source range by that range's offset and leaves
+// every other value alone, like the almanac maps of 2023 day 5. Source ranges
+// are expected not to overlap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    rules: Vec<(Range<i64>, i64)>,
+}
+
+impl RangeMap {
+    pub fn new() -> RangeMap {
+        RangeMap::default()
+    }
+
+    pub fn insert(&mut self, source: impl RangeBounds<i64>, offset: i64) {
+        let source = half_open(source);
+        let index = self.rules.partition_point(|(r, _)| r.start < source.start);
+        self.rules.insert(index, (source, offset));
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        let index = self.rules.partition_point(|(r, _)| r.end <= value);
+        match self.rules.get(index) {
+            Some((source, offset)) if source.start <= value => value + offset,
+            _ => value,
+        }
+    }
+
+    // Maps every value in the set at once, splitting ranges that straddle the
+    // edge of a source range
+    pub fn apply(&self, set: &RangeSet) -> RangeSet {
+        let mut mapped = RangeSet::new();
+        let mut unmapped = set.clone();
+
+        for (source, offset) in &self.rules {
+            let covered = set.intersection(&RangeSet::from(source.clone()));
+            for range in covered.ranges() {
+                mapped.insert(range.start + offset..range.end + offset);
+            }
+            unmapped.remove(source.clone());
+        }
+
+        mapped.union(&unmapped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_merges() {
+        let set = RangeSet::from_iter([3..=5, 10..=14, 16..=20, 12..=18]);
+        assert_eq!(set.ranges(), &[3..6, 10..21]);
+        assert_eq!(set.len(), 14);
+
+        // Touching ranges merge too
+        let set = RangeSet::from_iter([1..3, 3..5, 7..9]);
+        assert_eq!(set.ranges(), &[1..5, 7..9]);
+        assert_eq!(set.to_string(), "{1..=4, 7..=8}");
+    }
+
+    #[test]
+    fn test_insert_up_to_the_largest_value() {
+        let set = RangeSet::from_iter([i64::MAX - 1..=i64::MAX, 0..=i64::MAX]);
+        assert_eq!(set, RangeSet::from(0..));
+        assert_eq!(set.max(), Some(i64::MAX - 1));
+    }
+
+    #[test]
+    fn test_contains() {
+        let set = RangeSet::from_iter([3..=5, 10..=14]);
+        assert!(!set.contains(2));
+        assert!(set.contains(3));
+        assert!(set.contains(5));
+        assert!(!set.contains(6));
+        assert!(set.contains(14));
+        assert!(!set.contains(15));
+
+        assert!(set.contains_range(11..=13));
+        assert!(!set.contains_range(4..=10));
+        assert_eq!((set.min(), set.max()), (Some(3), Some(14)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = RangeSet::from_iter([0..10, 20..30]);
+        let b = RangeSet::from(5..25);
+
+        assert_eq!(a.union(&b), RangeSet::from(0..30));
+        assert_eq!(a.intersection(&b), RangeSet::from_iter([5..10, 20..25]));
+        assert_eq!(a.difference(&b), RangeSet::from_iter([0..5, 25..30]));
+        assert_eq!(b.difference(&a), RangeSet::from(10..20));
+
+        assert!(RangeSet::from(2..4).is_subset(&a));
+        assert!(a.is_superset(&RangeSet::from(22..28)));
+        assert!(!a.is_subset(&b));
+        assert!(a.is_disjoint(&RangeSet::from(10..20)));
+    }
+
+    #[test]
+    fn test_remove_splits() {
+        let mut set = RangeSet::from(0..10);
+        set.remove(3..=5);
+        assert_eq!(set.ranges(), &[0..3, 6..10]);
+
+        set.remove(..1);
+        set.remove(8..);
+        assert_eq!(set.ranges(), &[1..3, 6..8]);
+    }
+
+    #[test]
+    fn test_range_map() {
+        // seed-to-soil from the 2023 day 5 example
+        let mut map = RangeMap::new();
+        map.insert(98..100, -48);
+        map.insert(50..98, 2);
+
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(10), 10);
+
+        let seeds = RangeSet::from_iter([79..93, 40..52, 97..102]);
+        assert_eq!(
+            map.apply(&seeds),
+            RangeSet::from_iter([81..95, 40..50, 52..54, 99..100, 50..52, 100..102])
+        );
+    }
+}
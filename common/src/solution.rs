@@ -0,0 +1,99 @@
+use crate::{Answer, Result};
+use clap::{Args, Command};
+use std::any::Any;
+use std::collections::BTreeMap;
+
+// A day's puzzle. The implementing type doubles as the day's options, so a
+// day that needs extra parameters declares them as clap fields on itself.
+// Solutions and their inputs are shared between threads when days run in
+// parallel.
+pub trait Solution: Args + Send + Sync + 'static {
+    type Input: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer>;
+}
+
+// Type-erased view of a configured `Solution` so the registry can hold every
+// day behind the same pointer.
+pub trait Runnable: Send + Sync {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>>;
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer>;
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer>;
+}
+
+impl<S: Solution> Runnable for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any + Send + Sync>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part_one(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::part_one(self, downcast_input::<S>(input))
+    }
+
+    fn part_two(&self, input: &dyn Any) -> Result<Answer> {
+        Solution::part_two(self, downcast_input::<S>(input))
+    }
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("input was parsed by a different solution")
+}
+
+type Configure = fn(&[String]) -> Result<Box<dyn Runnable>, clap::Error>;
+
+fn configure<S: Solution>(options: &[String]) -> Result<Box<dyn Runnable>, clap::Error> {
+    let matches = S::augment_args(Command::new("options").no_binary_name(true))
+        .try_get_matches_from(options)?;
+
+    Ok(Box::new(S::from_arg_matches(&matches)?))
+}
+
+#[derive(Default)]
+pub struct Registry {
+    solutions: BTreeMap<(u16, u8), Configure>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    pub fn register<S: Solution>(&mut self, year: u16, day: u8) {
+        self.solutions.insert((year, day), configure::<S>);
+    }
+
+    pub fn solution(
+        &self,
+        year: u16,
+        day: u8,
+        options: &[String],
+    ) -> Option<Result<Box<dyn Runnable>, clap::Error>> {
+        self.solutions
+            .get(&(year, day))
+            .map(|configure| configure(options))
+    }
+
+    // Years with at least one registered day in ascending order
+    pub fn years(&self) -> Vec<u16> {
+        let mut years: Vec<u16> = self.solutions.keys().map(|&(year, _)| year).collect();
+        years.dedup();
+
+        years
+    }
+
+    // Registered days for a year in ascending order
+    pub fn days(&self, year: u16) -> Vec<u8> {
+        self.solutions
+            .range((year, u8::MIN)..=(year, u8::MAX))
+            .map(|(&(_, day), _)| day)
+            .collect()
+    }
+}